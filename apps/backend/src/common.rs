@@ -7,7 +7,7 @@ use axum::{
     Extension,
     extract::DefaultBodyLimit,
     http::{Method, header},
-    routing::{Router, get, post},
+    routing::{Router, get, post, put},
 };
 use background_models::{
     ApplicationJob, HpApplicationJob, LpApplicationJob, MpApplicationJob, SingleApplicationJob,
@@ -44,8 +44,11 @@ use miscellaneous_tracking_resolver::{
     MiscellaneousTrackingMutationResolver, MiscellaneousTrackingQueryResolver,
 };
 use router_resolver::{
-    config_handler, download_logs_handler, graphql_playground_handler, integration_webhook_handler,
-    upload_file_handler,
+    config_handler, download_logs_handler, file_storage_download_handler,
+    file_storage_upload_handler, graphql_playground_handler, integration_webhook_handler,
+    koreader_sync_auth_handler, koreader_sync_create_user_handler,
+    koreader_sync_get_progress_handler, koreader_sync_update_progress_handler,
+    listenbrainz_submit_listens_handler, listenbrainz_validate_token_handler, upload_file_handler,
 };
 use sea_orm::DatabaseConnection;
 use statistics_resolver::StatisticsQueryResolver;
@@ -101,7 +104,7 @@ pub async fn create_app_dependencies(
         .map(|f| f.parse().unwrap())
         .collect_vec();
     let cors = TowerCorsLayer::new()
-        .allow_methods([Method::GET, Method::POST])
+        .allow_methods([Method::GET, Method::POST, Method::PUT])
         .allow_headers([header::ACCEPT, header::CONTENT_TYPE])
         .allow_origin(cors_origins)
         .allow_credentials(true);
@@ -111,6 +114,32 @@ pub async fn create_app_dependencies(
         post(integration_webhook_handler),
     );
 
+    let file_storage_routes = Router::new()
+        .route("/download/{token}", get(file_storage_download_handler))
+        .route("/upload/{token}", put(file_storage_upload_handler));
+
+    let koreader_sync_routes = Router::new()
+        .route("/users/create", post(koreader_sync_create_user_handler))
+        .route("/users/auth", get(koreader_sync_auth_handler))
+        .route(
+            "/syncs/progress",
+            put(koreader_sync_update_progress_handler),
+        )
+        .route(
+            "/syncs/progress/{document}",
+            get(koreader_sync_get_progress_handler),
+        );
+
+    let listenbrainz_routes = Router::new()
+        .route(
+            "/1/submit-listens",
+            post(listenbrainz_submit_listens_handler),
+        )
+        .route(
+            "/1/validate-token",
+            get(listenbrainz_validate_token_handler),
+        );
+
     let mut gql = post(graphql_handler);
     if config.server.graphql_playground_enabled {
         gql = gql.get(graphql_playground_handler);
@@ -118,6 +147,9 @@ pub async fn create_app_dependencies(
 
     let app_router = Router::new()
         .nest("/webhooks", webhook_routes)
+        .nest("/files", file_storage_routes)
+        .nest("/koreader-sync/{integration_slug}", koreader_sync_routes)
+        .nest("/listenbrainz", listenbrainz_routes)
         .route("/config", get(config_handler))
         .route("/graphql", gql)
         .route("/upload", post(upload_file_handler))
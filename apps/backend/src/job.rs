@@ -11,11 +11,13 @@ use common_utils::ryot_log;
 use dependent_analytics_utils::recalculate_user_activities_and_summary;
 use dependent_collection_utils::{add_entities_to_collection, remove_entities_from_collection};
 use dependent_notification_utils::{
-    update_metadata_and_notify_users, update_metadata_group_and_notify_users,
-    update_person_and_notify_users,
+    send_queued_notifications, update_metadata_and_notify_users,
+    update_metadata_group_and_notify_users, update_person_and_notify_users,
 };
 use enum_models::EntityLot;
-use exporter_service::export_operations::perform_export;
+use exporter_service::{
+    export_operations::perform_export, scheduled_exports::deploy_scheduled_export_jobs,
+};
 use fitness_service::{
     deploy_update_exercise_library_job, process_users_scheduled_for_workout_revision,
     revise_user_workouts, update_github_exercises,
@@ -41,6 +43,7 @@ pub async fn run_infrequent_cron_jobs(
 ) -> Result<(), BoxDynError> {
     ryot_log!(debug, "Running job at {:#?}", tick.get_timestamp());
     perform_background_jobs(&ss).await.trace_ok();
+    deploy_scheduled_export_jobs(&ss).await.trace_ok();
     Ok(())
 }
 
@@ -55,6 +58,7 @@ pub async fn run_frequent_cron_jobs(
         .trace_ok();
     invalidate_import_jobs(&ss).await.trace_ok();
     cleanup_user_and_metadata_association(&ss).await.trace_ok();
+    send_queued_notifications(&ss).await.trace_ok();
     Ok(())
 }
 
@@ -98,7 +102,15 @@ pub async fn perform_mp_application_job(
         MpApplicationJob::SyncIntegrationsData => sync_integrations_data(&ss).await,
         MpApplicationJob::UpdateGithubExercises => update_github_exercises(&ss).await,
         MpApplicationJob::PerformBackgroundTasks => perform_background_jobs(&ss).await,
-        MpApplicationJob::PerformExport(user_id) => perform_export(&ss, user_id).await,
+        MpApplicationJob::PerformExport(user_id) => {
+            perform_export(&ss, user_id, Default::default(), false).await
+        }
+        MpApplicationJob::PerformExportWithInput(user_id, input) => {
+            perform_export(&ss, user_id, input, false).await
+        }
+        MpApplicationJob::PerformScheduledExport(user_id, input) => {
+            perform_export(&ss, user_id, input, true).await
+        }
         MpApplicationJob::UpdateExerciseLibrary => deploy_update_exercise_library_job(&ss).await,
         MpApplicationJob::ReviseUserWorkouts(user_id) => revise_user_workouts(&ss, user_id).await,
         MpApplicationJob::UpdateMediaTranslations(input) => {
@@ -1,6 +1,7 @@
 use std::{
     env,
     fs::{self, create_dir_all},
+    net::SocketAddr,
     path::PathBuf,
     str::FromStr,
     sync::{Arc, Mutex},
@@ -240,7 +241,10 @@ async fn main() -> Result<()> {
             .run()
     };
 
-    let http = axum::serve(listener, app_router.into_make_service());
+    let http = axum::serve(
+        listener,
+        app_router.into_make_service_with_connect_info::<SocketAddr>(),
+    );
 
     if disable_background_jobs {
         let _ = join!(http);
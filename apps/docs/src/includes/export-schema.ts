@@ -161,7 +161,7 @@ export interface UserMeasurement {
 }
 
 /** The different types of media that can be stored. */
-export type MediaLot = 'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'comic_book' | 'visual_novel';
+export type MediaLot = 'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'board_game' | 'comic_book' | 'visual_novel';
 
 export type SeenState = 'dropped' | 'on_a_hold' | 'completed' | 'in_progress';
 
@@ -169,6 +169,10 @@ export type SeenState = 'dropped' | 'on_a_hold' | 'completed' | 'in_progress';
 export interface ImportOrExportMetadataItemSeen {
 	/** If for an anime, the episode which was seen. */
 	anime_episode_number: number | null;
+	/** If for a board game, the number of players in the play. */
+	board_game_player_count: number | null;
+	/** If for a board game, the winner of the play. */
+	board_game_winner: string | null;
 	/** The timestamp when finished watching. */
 	ended_on: string | null;
 	/** If for a manga, the chapter which was seen. */
@@ -198,7 +202,7 @@ export interface ImportOrExportMetadataItemSeen {
 }
 
 /** The different sources (or providers) from which data can be obtained from. */
-export type MediaSource = 'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music';
+export type MediaSource = 'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'kitsu' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music' | 'board_game_geek' | 'comic_vine' | 'podcast_index' | 'rss';
 
 /** Details about a specific media item that needs to be imported or exported. */
 export interface ImportOrExportMetadataItem {
@@ -210,7 +214,7 @@ export interface ImportOrExportMetadataItem {
 	 * The type of media.
 	 *
 	 * @default 'book'
-	 * @type {'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'comic_book' | 'visual_novel'}
+	 * @type {'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'board_game' | 'comic_book' | 'visual_novel'}
 	 */
 	lot: MediaLot;
 	/** The review history for the user. */
@@ -221,7 +225,7 @@ export interface ImportOrExportMetadataItem {
 	 * The source of media.
 	 *
 	 * @default 'custom'
-	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music'}
+	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'kitsu' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music' | 'board_game_geek' | 'comic_vine' | 'podcast_index' | 'rss'}
 	 */
 	source: MediaSource;
 	/** An string to help identify it in the original source. */
@@ -238,7 +242,7 @@ export interface ImportOrExportMetadataGroupItem {
 	 * The type of media.
 	 *
 	 * @default 'book'
-	 * @type {'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'comic_book' | 'visual_novel'}
+	 * @type {'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' | 'podcast' | 'audio_book' | 'video_game' | 'board_game' | 'comic_book' | 'visual_novel'}
 	 */
 	lot: MediaLot;
 	/** The review history for the user. */
@@ -247,7 +251,7 @@ export interface ImportOrExportMetadataGroupItem {
 	 * The source of media.
 	 *
 	 * @default 'custom'
-	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music'}
+	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'kitsu' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music' | 'board_game_geek' | 'comic_vine' | 'podcast_index' | 'rss'}
 	 */
 	source: MediaSource;
 	/** Name of the group. */
@@ -276,7 +280,7 @@ export interface ImportOrExportPersonItem {
 	 * The source of data.
 	 *
 	 * @default 'custom'
-	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music'}
+	 * @type {'igdb' | 'tmdb' | 'tvdb' | 'vndb' | 'kitsu' | 'custom' | 'metron' | 'itunes' | 'anilist' | 'audible' | 'spotify' | 'music_brainz' | 'giant_bomb' | 'hardcover' | 'myanimelist' | 'listennotes' | 'google_books' | 'openlibrary' | 'manga_updates' | 'youtube_music' | 'board_game_geek' | 'comic_vine' | 'podcast_index' | 'rss'}
 	 */
 	source: MediaSource;
 	/** The source specific data. */
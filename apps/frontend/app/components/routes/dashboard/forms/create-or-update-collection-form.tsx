@@ -17,6 +17,7 @@ import {
 } from "@mantine/core";
 import { notifications } from "@mantine/notifications";
 import {
+	CollectionCollaboratorRole,
 	type CollectionExtraInformation,
 	CollectionExtraInformationLot,
 	CreateOrUpdateCollectionDocument,
@@ -57,6 +58,7 @@ export const CreateOrUpdateCollectionModal = (props: {
 		name: string;
 		isHidden: boolean;
 		description: string;
+		viewers: string[];
 		collaborators: string[];
 		informationTemplate: CollectionExtraInformation[];
 	}>({
@@ -65,9 +67,12 @@ export const CreateOrUpdateCollectionModal = (props: {
 			name: toUpdateCollection?.name || "",
 			description: toUpdateCollection?.description || "",
 			informationTemplate: toUpdateCollection?.informationTemplate || [],
-			collaborators: (toUpdateCollection?.collaborators || []).map(
-				(c) => c.collaborator.id,
-			),
+			collaborators: (toUpdateCollection?.collaborators || [])
+				.filter((c) => c.role !== CollectionCollaboratorRole.Viewer)
+				.map((c) => c.collaborator.id),
+			viewers: (toUpdateCollection?.collaborators || [])
+				.filter((c) => c.role === CollectionCollaboratorRole.Viewer)
+				.map((c) => c.collaborator.id),
 			isHidden: Boolean(
 				toUpdateCollection?.collaborators?.find(
 					(c) => c.collaborator.id === userDetails.id,
@@ -96,6 +101,7 @@ export const CreateOrUpdateCollectionModal = (props: {
 					name: values.name,
 					updateId: toUpdateCollection?.id,
 					description: values.description,
+					viewers: values.viewers,
 					collaborators: values.collaborators,
 					extraInformation: { isHidden: values.isHidden },
 					informationTemplate:
@@ -168,11 +174,30 @@ export const CreateOrUpdateCollectionModal = (props: {
 						data={usersList?.map((u) => ({
 							value: u.id,
 							label: u.name,
-							disabled: u.id === userDetails.id,
+							disabled:
+								u.id === userDetails.id || form.values.viewers.includes(u.id),
 						}))}
 						{...form.getInputProps("collaborators")}
 					/>
 				</Tooltip>
+				<Tooltip
+					label={PRO_REQUIRED_MESSAGE}
+					disabled={coreDetails.isServerKeyValidated}
+				>
+					<MultiSelect
+						searchable
+						disabled={!coreDetails.isServerKeyValidated}
+						description="Add users who can see this collection but not change it"
+						data={usersList?.map((u) => ({
+							value: u.id,
+							label: u.name,
+							disabled:
+								u.id === userDetails.id ||
+								form.values.collaborators.includes(u.id),
+						}))}
+						{...form.getInputProps("viewers")}
+					/>
+				</Tooltip>
 				<Input.Wrapper
 					labelProps={{ w: "100%" }}
 					description="Associate extra information when adding an entity to this collection"
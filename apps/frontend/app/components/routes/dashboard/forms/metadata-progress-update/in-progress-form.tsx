@@ -11,6 +11,7 @@ import { MediaLot } from "@ryot/generated/graphql/backend/graphql";
 import { isNumber } from "@ryot/ts-utils";
 import {
 	IconBook,
+	IconBooks,
 	IconBrandPagekit,
 	IconClock,
 	IconDeviceTv,
@@ -53,6 +54,7 @@ export const MetadataInProgressUpdateForm = (
 		metadataDetails.movieSpecifics?.runtime ||
 		metadataDetails.mangaSpecifics?.chapters ||
 		metadataDetails.animeSpecifics?.episodes ||
+		metadataDetails.comicBookSpecifics?.issueCount ||
 		metadataDetails.audioBookSpecifics?.runtime ||
 		metadataDetails.visualNovelSpecifics?.length;
 
@@ -66,6 +68,10 @@ export const MetadataInProgressUpdateForm = (
 			<IconBrandPagekit size={24} key="element" />,
 			"Chapters",
 		])
+		.with(MediaLot.ComicBook, () => [
+			<IconBooks size={24} key="element" />,
+			"Issues",
+		])
 		.with(MediaLot.Movie, MediaLot.VisualNovel, MediaLot.AudioBook, () => [
 			<IconClock size={24} key="element" />,
 			"Minutes",
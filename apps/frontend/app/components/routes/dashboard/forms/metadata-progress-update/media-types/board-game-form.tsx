@@ -0,0 +1,51 @@
+import { Group, NumberInput, TextInput } from "@mantine/core";
+import { MediaLot } from "@ryot/generated/graphql/backend/graphql";
+import { useMetadataDetails } from "~/lib/shared/hooks";
+import { useMetadataProgressUpdate } from "~/lib/state/media";
+import type { MediaFormProps } from "../utils/form-types";
+
+export const BoardGameForm = (props: MediaFormProps) => {
+	const { metadataToUpdate, updateMetadataToUpdate } =
+		useMetadataProgressUpdate();
+	const [{ data: metadataDetails }] = useMetadataDetails(props.metadataId);
+	if (
+		!metadataDetails ||
+		metadataDetails.lot !== MediaLot.BoardGame ||
+		!metadataToUpdate
+	)
+		return null;
+
+	const { minPlayers, maxPlayers } = metadataDetails.boardGameSpecifics || {};
+
+	return (
+		<Group wrap="nowrap">
+			<NumberInput
+				size="xs"
+				flex={1}
+				hideControls
+				label="Players"
+				min={minPlayers || 1}
+				max={maxPlayers || undefined}
+				value={metadataToUpdate.boardGamePlayerCount?.toString()}
+				onChange={(e) => {
+					updateMetadataToUpdate({
+						...metadataToUpdate,
+						boardGamePlayerCount: e === "" ? undefined : Number(e),
+					});
+				}}
+			/>
+			<TextInput
+				size="xs"
+				flex={1}
+				label="Winner"
+				value={metadataToUpdate.boardGameWinner || ""}
+				onChange={(e) => {
+					updateMetadataToUpdate({
+						...metadataToUpdate,
+						boardGameWinner: e.currentTarget.value || undefined,
+					});
+				}}
+			/>
+		</Group>
+	);
+};
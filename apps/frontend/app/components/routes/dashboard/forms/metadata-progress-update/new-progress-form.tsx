@@ -19,6 +19,7 @@ import {
 } from "~/lib/state/onboarding-tour";
 import { WatchTimes } from "../../types";
 import { AnimeForm } from "./media-types/anime-form";
+import { BoardGameForm } from "./media-types/board-game-form";
 import { MangaForm } from "./media-types/manga-form";
 import { PodcastForm } from "./media-types/podcast-form";
 import { ShowForm } from "./media-types/show-form";
@@ -120,6 +121,8 @@ export const MetadataNewProgressUpdateForm = (
 					mangaChapterNumber: metadataToUpdate.mangaChapterNumber,
 					animeEpisodeNumber: metadataToUpdate.animeEpisodeNumber,
 					podcastEpisodeNumber: metadataToUpdate.podcastEpisodeNumber,
+					boardGameWinner: metadataToUpdate.boardGameWinner,
+					boardGamePlayerCount: metadataToUpdate.boardGamePlayerCount,
 				};
 				const updates: MetadataProgressUpdateInput[] = [];
 
@@ -168,6 +171,7 @@ export const MetadataNewProgressUpdateForm = (
 				<AnimeForm metadataId={props.metadataId} />
 				<MangaForm metadataId={props.metadataId} />
 				<PodcastForm metadataId={props.metadataId} />
+				<BoardGameForm metadataId={props.metadataId} />
 				<WatchTimeSelect
 					value={form.values.watchTime}
 					onChange={handleWatchTimeChange}
@@ -14,6 +14,7 @@ import {
 	IconDeviceDesktop,
 	IconDeviceTv,
 	IconDeviceTvOld,
+	IconDice,
 	IconHeadphones,
 	IconMicrophone,
 	IconMusic,
@@ -67,6 +68,14 @@ export const getLot = (lot: unknown) => {
 			"comic_books",
 			() => MediaLot.ComicBook,
 		)
+		.with(
+			"boardgames",
+			"boardgame",
+			"board_game",
+			"board game",
+			"board_games",
+			() => MediaLot.BoardGame,
+		)
 		.otherwise(() => undefined);
 };
 
@@ -86,6 +95,7 @@ export const getVerb = (verb: Verb, lot: MediaLot) =>
 					MediaLot.AudioBook,
 					MediaLot.Music,
 					MediaLot.VideoGame,
+					MediaLot.BoardGame,
 					MediaLot.Podcast,
 					() => "play",
 				)
@@ -106,6 +116,7 @@ export const getMetadataIcon = (lot: MediaLot) =>
 		.with(MediaLot.AudioBook, () => IconHeadphones)
 		.with(MediaLot.ComicBook, () => IconVocabulary)
 		.with(MediaLot.VideoGame, () => IconBrandAppleArcade)
+		.with(MediaLot.BoardGame, () => IconDice)
 		.exhaustive();
 
 export const getSetColor = (l: SetLot) =>
@@ -194,6 +205,7 @@ export const MediaColors: EntityColor = {
 	COMIC_BOOK: "grape",
 	AUDIO_BOOK: "orange",
 	VISUAL_NOVEL: "pink",
+	BOARD_GAME: "teal.8",
 	USER_MEASUREMENT: "indigo",
 };
 
@@ -95,5 +95,13 @@ export const getProviderSourceImage = (source: MediaSource) =>
 		.with(MediaSource.Spotify, () => "spotify.svg")
 		.with(MediaSource.MusicBrainz, () => "musicbrainz.svg")
 		.with(MediaSource.Metron, () => "metron.svg")
-		.with(MediaSource.Custom, () => undefined)
+		.with(
+			MediaSource.Custom,
+			MediaSource.BoardGameGeek,
+			MediaSource.Kitsu,
+			MediaSource.ComicVine,
+			MediaSource.PodcastIndex,
+			MediaSource.Rss,
+			() => undefined,
+		)
 		.exhaustive();
@@ -19,6 +19,8 @@ export type UpdateProgressData = {
 	animeEpisodeNumber?: number;
 	mangaChapterNumber?: string;
 	podcastEpisodeNumber?: number;
+	boardGameWinner?: string;
+	boardGamePlayerCount?: number;
 	providersConsumedOn?: string[];
 	showAllEpisodesBefore?: boolean;
 	animeAllEpisodesBefore?: boolean;
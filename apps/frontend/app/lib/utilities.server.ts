@@ -83,6 +83,15 @@ export const serverGqlService = new AuthenticatedGraphQLClient(
 	{ headers: { Connection: "keep-alive" } },
 );
 
+/**
+ * The backend only sees the address of the frontend server, so the address of the client
+ * is forwarded for requests which are rate limited by it.
+ */
+export const getForwardedForHeaders = (request: Request) => {
+	const forwardedFor = request.headers.get("x-forwarded-for");
+	return forwardedFor ? { "X-Forwarded-For": forwardedFor } : undefined;
+};
+
 export const getCookieValue = (request: Request, cookieName: string) =>
 	parse(request.headers.get("cookie") || "")[cookieName];
 
@@ -192,6 +201,16 @@ export const twoFactorSessionStorage = createCookieSessionStorage({
 	},
 });
 
+/**
+ * Set while a logged in user is linking their OIDC identity, so that the OIDC callback
+ * links it instead of logging in.
+ */
+export const oidcLinkCookie = createCookie("OidcLink", {
+	path: "/",
+	sameSite: "lax",
+	maxAge: 60 * 10,
+});
+
 export const colorSchemeCookie = createCookie("ColorScheme", {
 	maxAge: 60 * 60 * 24 * 365,
 });
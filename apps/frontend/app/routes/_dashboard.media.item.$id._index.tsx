@@ -258,6 +258,9 @@ export default function Page() {
 	const averageRatingSuffix = getRatingUnitSuffix(
 		userPreferences.general.reviewScale,
 	);
+	const providerLogo = metadataDetails.data
+		? getProviderSourceImage(metadataDetails.data.source)
+		: undefined;
 
 	const [tab, setTab] = useState<string | null>(
 		loaderData.query.defaultTab || "overview",
@@ -320,6 +323,7 @@ export default function Page() {
 		variant: EntityTranslationVariant.Title,
 		metadataGroupId: firstGroupAssociated?.id,
 	});
+	const boardGameSpecifics = metadataDetails.data?.boardGameSpecifics;
 	const additionalMetadataDetails = [
 		userPreferences.featuresEnabled.media.groups && firstGroupAssociated && (
 			<Link
@@ -357,6 +361,23 @@ export default function Page() {
 			`${metadataDetails.data.mangaSpecifics.volumes} volumes`,
 		metadataDetails.data?.comicBookSpecifics?.pageCount &&
 			`${metadataDetails.data.comicBookSpecifics.pageCount} pages`,
+		metadataDetails.data?.comicBookSpecifics?.issueCount &&
+			`${metadataDetails.data.comicBookSpecifics.issueCount} issues`,
+		boardGameSpecifics?.minPlayers &&
+			`${boardGameSpecifics.minPlayers}${
+				boardGameSpecifics.maxPlayers &&
+				boardGameSpecifics.maxPlayers !== boardGameSpecifics.minPlayers
+					? `-${boardGameSpecifics.maxPlayers}`
+					: ""
+			} players`,
+		boardGameSpecifics?.playingTime &&
+			humanizeDuration(
+				dayjsLib
+					.duration(boardGameSpecifics.playingTime, "minute")
+					.asMilliseconds(),
+			),
+		boardGameSpecifics?.weight &&
+			`Weight ${Number(boardGameSpecifics.weight).toFixed(2)} / 5`,
 		metadataDetails.data?.movieSpecifics?.runtime &&
 			humanizeDuration(
 				dayjsLib
@@ -471,13 +492,15 @@ export default function Page() {
 											flexDirection: "column",
 										}}
 									>
-										<Image
-											h={24}
-											w={24}
-											alt="Logo"
-											fit="contain"
-											src={`/provider-logos/${getProviderSourceImage(metadataDetails.data.source)}`}
-										/>
+										{providerLogo ? (
+											<Image
+												h={24}
+												w={24}
+												alt="Logo"
+												fit="contain"
+												src={`/provider-logos/${providerLogo}`}
+											/>
+										) : null}
 										<Text fz="sm">
 											{Number(metadataDetails.data.providerRating).toFixed(1)}
 											{match(metadataDetails.data.source)
@@ -488,6 +511,7 @@ export default function Page() {
 													MediaSource.Anilist,
 													MediaSource.Listennotes,
 													MediaSource.Spotify,
+													MediaSource.Kitsu,
 													() => "%",
 												)
 												.with(
@@ -510,6 +534,10 @@ export default function Page() {
 													MediaSource.GiantBomb,
 													MediaSource.MusicBrainz,
 													MediaSource.Metron,
+													MediaSource.BoardGameGeek,
+													MediaSource.ComicVine,
+													MediaSource.PodcastIndex,
+													MediaSource.Rss,
 													() => undefined,
 												)
 												.exhaustive()}
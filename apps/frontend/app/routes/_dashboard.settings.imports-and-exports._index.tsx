@@ -10,6 +10,7 @@ import {
 	FileInput,
 	Group,
 	Indicator,
+	NumberInput,
 	Paper,
 	Progress,
 	Select,
@@ -26,7 +27,10 @@ import {
 	DeleteUserImportReportDocument,
 	DeployExportJobDocument,
 	DeployImportJobDocument,
+	ExportFormat,
 	ImportSource,
+	UpdateUserExportScheduleDocument,
+	UserExportScheduleFrequency,
 	UserExportsDocument,
 	type UserExportsQuery,
 	UserImportReportsDocument,
@@ -36,6 +40,8 @@ import {
 	getActionIntent,
 	kebabCase,
 	processSubmission,
+	zodCheckboxAsString,
+	zodIntAsString,
 } from "@ryot/ts-utils";
 import { IconDownload, IconEye, IconTrash } from "@tabler/icons-react";
 import { useMutation, useQuery } from "@tanstack/react-query";
@@ -54,6 +60,7 @@ import {
 	useCoreDetails,
 	useDeleteS3AssetMutation,
 	useNonHiddenUserCollections,
+	useUserDetails,
 } from "~/lib/shared/hooks";
 import { clientGqlService } from "~/lib/shared/react-query";
 import {
@@ -116,6 +123,9 @@ export const action = async ({ request }: Route.ActionArgs) => {
 					ImportSource.GenericJson,
 					ImportSource.Anilist,
 					ImportSource.Watcharr,
+					ImportSource.Letterboxd,
+					ImportSource.Simkl,
+					ImportSource.Opml,
 					async () => ({
 						path: processSubmission(formData, exportPathImportFormSchema),
 					}),
@@ -129,6 +139,9 @@ export const action = async ({ request }: Route.ActionArgs) => {
 				.with(ImportSource.Igdb, async () => ({
 					igdb: processSubmission(formData, igdbImportFormSchema),
 				}))
+				.with(ImportSource.Kitsu, async () => ({
+					kitsu: processSubmission(formData, usernameImportFormSchema),
+				}))
 				.exhaustive();
 			await serverGqlService.authenticatedRequest(
 				request,
@@ -143,9 +156,11 @@ export const action = async ({ request }: Route.ActionArgs) => {
 			});
 		})
 		.with("deployExport", async () => {
+			const input = processSubmission(formData, deployExportFormSchema);
 			await serverGqlService.authenticatedRequest(
 				request,
 				DeployExportJobDocument,
+				{ input },
 			);
 			return data({ status: "success" } as const, {
 				headers: await createToastHeaders({
@@ -154,9 +169,39 @@ export const action = async ({ request }: Route.ActionArgs) => {
 				}),
 			});
 		})
+		.with("updateExportSchedule", async () => {
+			const { frequency, ...schedule } = processSubmission(
+				formData,
+				exportScheduleFormSchema,
+			);
+			await serverGqlService.authenticatedRequest(
+				request,
+				UpdateUserExportScheduleDocument,
+				{ schedule: frequency ? { frequency, ...schedule } : null },
+			);
+			return data({ status: "success" } as const, {
+				headers: await createToastHeaders({
+					type: "success",
+					message: frequency
+						? "Export schedule updated"
+						: "Scheduled exports disabled",
+				}),
+			});
+		})
 		.run();
 };
 
+const deployExportFormSchema = z.object({
+	format: z.enum(ExportFormat).optional(),
+});
+
+const exportScheduleFormSchema = z.object({
+	format: z.enum(ExportFormat),
+	notifyOnCompletion: zodCheckboxAsString,
+	retentionCount: zodIntAsString.optional(),
+	frequency: z.enum(UserExportScheduleFrequency).optional(),
+});
+
 const traktImportFormSchema = z.object({
 	user: z.string().optional(),
 	list: z.object({ url: z.string(), collection: z.string() }).optional(),
@@ -220,7 +265,9 @@ export default function Page() {
 	const [openDrawerId, setOpenDrawerId] = useState<string | null>(null);
 	const [deployImportSource, setDeployImportSource] = useState<ImportSource>();
 
+	const userDetails = useUserDetails();
 	const fileUploadNotAllowed = !coreDetails.fileStorageEnabled;
+	const exportSchedule = userDetails.extraInformation?.exportSchedule;
 
 	const userImportsReportsQuery = useQuery({
 		enabled: inViewport,
@@ -450,6 +497,7 @@ export default function Page() {
 													</>
 												))
 												.with(
+													ImportSource.Simkl,
 													ImportSource.Anilist,
 													ImportSource.Watcharr,
 													ImportSource.GenericJson,
@@ -462,6 +510,29 @@ export default function Page() {
 														/>
 													),
 												)
+												.with(ImportSource.Kitsu, () => (
+													<TextInput
+														required
+														name="username"
+														label="The username of the Kitsu user to import"
+													/>
+												))
+												.with(ImportSource.Letterboxd, () => (
+													<FileInput
+														required
+														accept=".zip"
+														name="exportPath"
+														label="Letterboxd ZIP export file"
+													/>
+												))
+												.with(ImportSource.Opml, () => (
+													<FileInput
+														required
+														accept=".opml,.xml"
+														name="exportPath"
+														label="OPML export file"
+													/>
+												))
 												.with(ImportSource.Netflix, () => (
 													<>
 														<FileInput
@@ -749,6 +820,12 @@ export default function Page() {
 									name="dummy"
 									defaultValue="this is required because of the encType"
 								/>
+								<Select
+									name="format"
+									label="Format"
+									defaultValue={ExportFormat.Json}
+									data={convertEnumToSelectData(ExportFormat)}
+								/>
 								<Tooltip
 									label="Please enable file storage to use this feature"
 									disabled={!fileUploadNotAllowed}
@@ -767,6 +844,56 @@ export default function Page() {
 								</Tooltip>
 							</Form>
 							<Divider />
+							<Title order={3}>Scheduled exports</Title>
+							<Form
+								method="POST"
+								encType="multipart/form-data"
+								action={withQuery(".", { intent: "updateExportSchedule" })}
+							>
+								<Stack gap="xs">
+									<Select
+										clearable
+										name="frequency"
+										label="Frequency"
+										placeholder="Disabled"
+										defaultValue={exportSchedule?.frequency}
+										data={convertEnumToSelectData(UserExportScheduleFrequency)}
+									/>
+									<Select
+										required
+										name="format"
+										label="Format"
+										data={convertEnumToSelectData(ExportFormat)}
+										defaultValue={exportSchedule?.format || ExportFormat.Json}
+									/>
+									<NumberInput
+										min={1}
+										name="retentionCount"
+										label="Exports to keep"
+										description="Older scheduled exports are deleted, exports started by hand are kept"
+										defaultValue={exportSchedule?.retentionCount ?? undefined}
+									/>
+									<Checkbox
+										name="notifyOnCompletion"
+										label="Send a notification when a scheduled export completes"
+										defaultChecked={exportSchedule?.notifyOnCompletion}
+									/>
+									{exportSchedule?.lastTriggeredOn ? (
+										<Text size="xs" c="dimmed">
+											Last ran on{" "}
+											{dayjsLib(exportSchedule.lastTriggeredOn).format("LL")}
+										</Text>
+									) : null}
+									<Button
+										type="submit"
+										variant="light"
+										disabled={fileUploadNotAllowed}
+									>
+										Save schedule
+									</Button>
+								</Stack>
+							</Form>
+							<Divider />
 							<Title order={3}>Export history</Title>
 							{userExportsQuery.data ? (
 								userExportsQuery.data.length > 0 ? (
@@ -820,7 +947,8 @@ const DisplayExport = (props: ExportItemProps) => {
 						{dayjsLib(props.item.startedAt).format("MMM DD, YYYY [at] h:mm A")}
 					</Text>
 					<Text span size="xs" c="dimmed">
-						(Took {duration}, {filesize(props.item.size)})
+						({changeCase(props.item.format)}, took {duration},{" "}
+						{filesize(props.item.size)})
 					</Text>
 				</Stack>
 				<Group>
@@ -221,7 +221,16 @@ const DisplayIntegration = (props: {
 	] = useDisclosure(false);
 	const submit = useConfirmSubmit();
 
-	const integrationUrl = `${applicationBaseUrl}/_i/${props.integration.id}`;
+	const integrationUrl = match(props.integration.provider)
+		.with(
+			IntegrationProvider.KoreaderSync,
+			() => `${applicationBaseUrl}/backend/koreader-sync/${props.integration.id}`,
+		)
+		.with(
+			IntegrationProvider.ListenBrainz,
+			() => `${applicationBaseUrl}/backend/listenbrainz`,
+		)
+		.otherwise(() => `${applicationBaseUrl}/_i/${props.integration.id}`);
 
 	const integrationDisplayName = [
 		changeCase(props.integration.provider),
@@ -347,6 +356,13 @@ const DisplayIntegration = (props: {
 					{integrationUrlOpened ? (
 						<CopyableTextInput value={integrationUrl} />
 					) : null}
+					{integrationUrlOpened &&
+					props.integration.provider === IntegrationProvider.ListenBrainz ? (
+						<CopyableTextInput
+							value={props.integration.id}
+							description="Use this as the user token in your scrobbler"
+						/>
+					) : null}
 				</Stack>
 			</Paper>
 		</>
@@ -370,6 +386,7 @@ interface FieldConfig {
 	description?: string;
 	placeholder?: string;
 	options?: { value: string; label: string }[];
+	format?: (value: unknown) => string | undefined;
 	transform?: (value: string | undefined) => unknown;
 }
 
@@ -396,6 +413,23 @@ const tagIdsTransform = (val: string | undefined) =>
 const disabledSitesTransform = (val: string | undefined) =>
 	val ? val.split("\n").filter((line) => line.trim() !== "") : undefined;
 
+const koreaderSyncDocumentsTransform = (val: string | undefined) =>
+	val
+		? val
+				.split("\n")
+				.map((line) => line.split(",").map((part) => part.trim()))
+				.filter(([document, isbn]) => document && isbn)
+				.map(([document, isbn]) => ({ document, isbn }))
+		: undefined;
+
+const koreaderSyncDocumentsFormat = (val: unknown) =>
+	Array.isArray(val)
+		? (val as { document: string; isbn?: string | null }[])
+				.filter((d) => d.isbn)
+				.map((d) => `${d.document},${d.isbn}`)
+				.join("\n")
+		: undefined;
+
 const PROVIDER_CONFIGS: Record<IntegrationProvider, ProviderConfig> = {
 	[IntegrationProvider.Audiobookshelf]: {
 		capabilities: {
@@ -627,6 +661,39 @@ const PROVIDER_CONFIGS: Record<IntegrationProvider, ProviderConfig> = {
 	[IntegrationProvider.Kodi]: {
 		capabilities: { showUrl: true, progressAdjustment: true },
 	},
+	[IntegrationProvider.ListenBrainz]: {
+		capabilities: { showUrl: true },
+	},
+	[IntegrationProvider.KoreaderSync]: {
+		capabilities: { showUrl: true, progressAdjustment: true },
+		fields: [
+			{
+				type: "text",
+				label: "Username",
+				name: "koreaderSyncUsername",
+			},
+			{
+				type: "password",
+				label: "Password",
+				notRequired: true,
+				name: "koreaderSyncPassword",
+				description:
+					"Required when creating the integration. Leave empty to keep the current password.",
+			},
+			{
+				rows: 4,
+				type: "textarea",
+				notRequired: true,
+				label: "Documents",
+				name: "koreaderSyncDocuments",
+				format: koreaderSyncDocumentsFormat,
+				transform: koreaderSyncDocumentsTransform,
+				placeholder: "5b2d6e0f9c8a7d3e1f4a6b8c0d2e4f6a,9780141036144",
+				description:
+					"One document per line as the KOReader document hash and the ISBN of the book, separated by a comma",
+			},
+		],
+	},
 };
 
 const MINIMUM_PROGRESS = "2";
@@ -776,9 +843,11 @@ const ProviderField = (props: {
 					description={props.field.description}
 					placeholder={props.field.placeholder}
 					defaultValue={
-						Array.isArray(value)
-							? (value as string[]).join("\n")
-							: (value as string) || undefined
+						props.field.format
+							? props.field.format(value)
+							: Array.isArray(value)
+								? (value as string[]).join("\n")
+								: (value as string) || undefined
 					}
 				/>
 			);
@@ -14,6 +14,7 @@ import {
 	Stack,
 	Switch,
 	Text,
+	Textarea,
 	TextInput,
 	Title,
 	Tooltip,
@@ -136,7 +137,21 @@ const createSchema = z.object({
 	priority: z.number().optional(),
 	apiToken: z.string().optional(),
 	authHeader: z.string().optional(),
+	bodyTemplate: z.string().optional(),
 	lot: z.enum(NotificationPlatformLot),
+	headers: z
+		.string()
+		.optional()
+		.transform((val) =>
+			val
+				?.split("\n")
+				.map((line) => line.split(":"))
+				.filter(([name, ...value]) => name.trim() && value.length > 0)
+				.map(([name, ...value]) => ({
+					name: name.trim(),
+					value: value.join(":").trim(),
+				})),
+		),
 });
 
 const updateSchema = z.object({
@@ -164,7 +179,9 @@ export default function Page() {
 		baseUrl?: string;
 		apiToken?: string;
 		priority?: number;
+		headers?: string;
 		authHeader?: string;
+		bodyTemplate?: string;
 		lot: NotificationPlatformLot | "";
 	}>({
 		storageKeyPrefix: "CreateUserNotificationPlatform",
@@ -175,7 +192,9 @@ export default function Page() {
 			chatId: "",
 			baseUrl: "",
 			apiToken: "",
+			headers: "",
 			authHeader: "",
+			bodyTemplate: "",
 			priority: undefined,
 		},
 	});
@@ -229,6 +248,28 @@ export default function Page() {
 											<NumberInput label="Priority" name="priority" />
 										</>
 									))
+									.with(NotificationPlatformLot.Matrix, () => (
+										<>
+											<TextInput
+												required
+												name="baseUrl"
+												label="Homeserver Url"
+												placeholder="https://matrix.org"
+											/>
+											<TextInput
+												required
+												name="chatId"
+												label="Room ID"
+												placeholder="!roomid:matrix.org"
+											/>
+											<TextInput
+												required
+												name="apiToken"
+												label="Access Token"
+												description="Access token of the account that sends the messages, it must already be in the room"
+											/>
+										</>
+									))
 									.with(NotificationPlatformLot.Ntfy, () => (
 										<>
 											<TextInput label="Topic" required name="apiToken" />
@@ -279,6 +320,30 @@ export default function Page() {
 											<TextInput label="Chat ID" required name="chatId" />
 										</>
 									))
+									.with(NotificationPlatformLot.Webhook, () => (
+										<>
+											<TextInput label="Url" required name="baseUrl" />
+											<TextInput
+												name="apiToken"
+												label="Secret"
+												description="If set, the body is signed with HMAC-SHA256 and sent in the X-Ryot-Signature header"
+											/>
+											<Textarea
+												rows={3}
+												name="headers"
+												label="Headers"
+												placeholder="X-Api-Key: secret"
+												description="One header per line as name and value separated by a colon"
+											/>
+											<Textarea
+												rows={4}
+												name="bodyTemplate"
+												label="Body template"
+												placeholder='{"text": {{ message }}}'
+												description="MiniJinja template for the request body, every value is inserted as JSON. Leave empty to send the whole event."
+											/>
+										</>
+									))
 									.with(NotificationPlatformLot.Email, () => (
 										<>
 											<TextInput
@@ -474,6 +539,14 @@ const DisplayNotification = (props: {
 													() =>
 														"Media moved from the Completed to the Watchlist collection",
 												)
+												.with(
+													UserNotificationContentDiscriminants.ExportCompleted,
+													() => "A scheduled export completes",
+												)
+												.with(
+													UserNotificationContentDiscriminants.AccountLockedDueToFailedLogins,
+													() => "Account locked due to too many failed logins",
+												)
 												.exhaustive()}
 										/>
 									),
@@ -20,17 +20,19 @@ import { notifications } from "@mantine/notifications";
 import {
 	CompleteTwoFactorSetupDocument,
 	DisableTwoFactorDocument,
+	GetOidcRedirectUrlDocument,
 	GetPasswordChangeSessionDocument,
 	InitiateTwoFactorSetupDocument,
 	type InitiateTwoFactorSetupMutation,
 	RegenerateTwoFactorBackupCodesDocument,
+	UnlinkOidcIdentityDocument,
 	UpdateUserDocument,
 } from "@ryot/generated/graphql/backend/graphql";
 import { getActionIntent, processSubmission } from "@ryot/ts-utils";
 import { useMutation } from "@tanstack/react-query";
 import { QRCodeSVG } from "qrcode.react";
 import { useState } from "react";
-import { data, Form, useNavigate } from "react-router";
+import { data, Form, redirect, useNavigate } from "react-router";
 import { $path } from "safe-routes";
 import { match } from "ts-pattern";
 import { withQuery } from "ufo";
@@ -46,7 +48,11 @@ import {
 } from "~/lib/shared/hooks";
 import { clientGqlService } from "~/lib/shared/react-query";
 import { openConfirmationModal } from "~/lib/shared/ui-utils";
-import { createToastHeaders, serverGqlService } from "~/lib/utilities.server";
+import {
+	createToastHeaders,
+	oidcLinkCookie,
+	serverGqlService,
+} from "~/lib/utilities.server";
 import type { Route } from "./+types/_dashboard.settings.security";
 
 enum TwoFactorSetupStep {
@@ -76,6 +82,27 @@ export const action = async ({ request }: Route.ActionArgs) => {
 				}),
 			});
 		})
+		.with("linkOidcIdentity", async () => {
+			const { getOidcRedirectUrl } = await serverGqlService.request(
+				GetOidcRedirectUrlDocument,
+			);
+			return redirect(getOidcRedirectUrl, {
+				headers: { "set-cookie": await oidcLinkCookie.serialize(true) },
+			});
+		})
+		.with("unlinkOidcIdentity", async () => {
+			await serverGqlService.authenticatedRequest(
+				request,
+				UnlinkOidcIdentityDocument,
+				{},
+			);
+			return data({ status: "success" } as const, {
+				headers: await createToastHeaders({
+					type: "success",
+					message: "OIDC account unlinked successfully",
+				}),
+			});
+		})
 		.run();
 };
 
@@ -91,6 +118,7 @@ export default function Page() {
 			<Stack gap="xl">
 				<PasswordSection />
 				<Divider />
+				<OidcSection />
 				<TwoFactorAuthSection />
 			</Stack>
 		</Container>
@@ -174,7 +202,7 @@ const PasswordSection = () => {
 
 			<Divider />
 
-			{userDetails.oidcIssuerId ? (
+			{!userDetails.hasPassword ? (
 				<Alert color="blue" title="OIDC User">
 					Password change is not available since this user was created via OIDC.
 				</Alert>
@@ -199,6 +227,75 @@ const PasswordSection = () => {
 	);
 };
 
+const OidcSection = () => {
+	const submit = useConfirmSubmit();
+	const coreDetails = useCoreDetails();
+	const userDetails = useUserDetails();
+	const dashboardData = useDashboardLayoutData();
+	const isEditDisabled = dashboardData.isDemoInstance;
+	const invalidateUserDetails = useInvalidateUserDetails();
+
+	if (!coreDetails.oidcEnabled) return null;
+
+	return (
+		<Paper withBorder p="md">
+			<Stack>
+				<Box>
+					<Text size="lg" fw="bold">
+						OpenID Connect
+					</Text>
+					<Text size="sm" c="dimmed">
+						{userDetails.oidcIssuerId
+							? "Your account is linked to an OIDC account which you can use to login."
+							: "Link your OIDC account to login with it in addition to your password."}
+					</Text>
+				</Box>
+				{userDetails.oidcIssuerId ? (
+					<Form
+						method="POST"
+						action={withQuery(".", { intent: "unlinkOidcIdentity" })}
+					>
+						<Button
+							fullWidth
+							color="red"
+							type="submit"
+							variant="light"
+							disabled={isEditDisabled || !userDetails.hasPassword}
+							onClick={(e) => {
+								const form = e.currentTarget.form;
+								e.preventDefault();
+								openConfirmationModal(
+									"Are you sure you want to unlink your OIDC account? You will only be able to login using your password.",
+									async () => {
+										submit(form);
+										await invalidateUserDetails();
+									},
+								);
+							}}
+						>
+							Unlink OIDC Account
+						</Button>
+						{!userDetails.hasPassword && (
+							<Text size="xs" c="dimmed" mt="xs">
+								This account has no password to login with after unlinking
+							</Text>
+						)}
+					</Form>
+				) : (
+					<Form
+						method="POST"
+						action={withQuery(".", { intent: "linkOidcIdentity" })}
+					>
+						<Button fullWidth type="submit" disabled={isEditDisabled}>
+							Link OIDC Account
+						</Button>
+					</Form>
+				)}
+			</Stack>
+		</Paper>
+	);
+};
+
 const TwoFactorAuthSection = () => {
 	const navigate = useNavigate();
 	const userDetails = useUserDetails();
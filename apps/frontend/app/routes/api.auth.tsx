@@ -1,17 +1,21 @@
 import {
 	GetOidcTokenDocument,
+	LinkOidcIdentityDocument,
 	LoginUserDocument,
 	RegisterErrorVariant,
 	RegisterUserDocument,
 	UserByOidcIssuerIdDocument,
 } from "@ryot/generated/graphql/backend/graphql";
 import { parseSearchQuery } from "@ryot/ts-utils";
+import { ClientError } from "graphql-request";
 import { data, redirect } from "react-router";
 import { $path } from "safe-routes";
 import { z } from "zod";
 import {
+	getAuthorizationCookie,
 	getCookiesForApplication,
 	getCoreDetails,
+	oidcLinkCookie,
 	redirectWithToast,
 	serverGqlService,
 	twoFactorSessionStorage,
@@ -22,8 +26,31 @@ const searchParamsSchema = z.object({ code: z.string() });
 
 export type SearchParams = z.infer<typeof searchParamsSchema>;
 
+const linkOidcIdentity = async (request: Request, input: SearchParams) => {
+	const headers = new Headers({
+		"set-cookie": await oidcLinkCookie.serialize("", { maxAge: 0 }),
+	});
+	const toast = await serverGqlService
+		.request(LinkOidcIdentityDocument, input, {
+			Authorization: `Bearer ${getAuthorizationCookie(request)}`,
+		})
+		.then(() => ({
+			type: "success" as const,
+			message: "OIDC account linked successfully",
+		}))
+		.catch((e) => ({
+			type: "error" as const,
+			message:
+				(e instanceof ClientError && e.response.errors?.at(0)?.message) ||
+				"Failed to link OIDC account",
+		}));
+	return redirectWithToast($path("/settings/security"), toast, { headers });
+};
+
 export const loader = async ({ request }: Route.LoaderArgs) => {
 	const input = parseSearchQuery(request, searchParamsSchema);
+	if (await oidcLinkCookie.parse(request.headers.get("cookie")))
+		return linkOidcIdentity(request, input);
 	const { getOidcToken } = await serverGqlService.request(
 		GetOidcTokenDocument,
 		input,
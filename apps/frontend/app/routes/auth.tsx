@@ -14,6 +14,7 @@ import {
 	GetOidcRedirectUrlDocument,
 	LoginErrorVariant,
 	LoginUserDocument,
+	LoginUserViaProxyDocument,
 	RegisterErrorVariant,
 	RegisterUserDocument,
 } from "@ryot/generated/graphql/backend/graphql";
@@ -36,6 +37,7 @@ import {
 	createToastHeaders,
 	getCookiesForApplication,
 	getCoreDetails,
+	getForwardedForHeaders,
 	redirectWithToast,
 	serverGqlService,
 	twoFactorSessionStorage,
@@ -55,9 +57,37 @@ const getOidcRedirectUrl = () =>
 		.request(GetOidcRedirectUrlDocument)
 		.then(({ getOidcRedirectUrl }) => getOidcRedirectUrl);
 
+/**
+ * Requests from a forward authentication proxy carry the name of the user in a header,
+ * which is passed on to the backend so that it can check whether the proxy is trusted.
+ */
+const loginUserViaProxy = async (request: Request, proxyAuthHeader: string) => {
+	const username = request.headers.get(proxyAuthHeader);
+	if (!username) return null;
+	const result = await serverGqlService
+		.request(
+			LoginUserViaProxyDocument,
+			{},
+			{ ...getForwardedForHeaders(request), [proxyAuthHeader]: username },
+		)
+		.catch(() => null);
+	const response = result?.loginUserViaProxy;
+	return response?.__typename === "ApiKeyResponse" ? response.apiKey : null;
+};
+
 export const loader = async ({ request }: Route.LoaderArgs) => {
 	const query = parseSearchQuery(request, searchParamsSchema);
 	const [coreDetails] = await Promise.all([getCoreDetails()]);
+	if (coreDetails.proxyAuthHeader) {
+		const apiKey = await loginUserViaProxy(
+			request,
+			coreDetails.proxyAuthHeader,
+		);
+		if (apiKey) {
+			const headers = await getCookiesForApplication(apiKey);
+			return redirect($path("/"), { headers });
+		}
+	}
 	if (
 		coreDetails.oidcEnabled &&
 		coreDetails.localAuthDisabled &&
@@ -128,14 +158,18 @@ export const action = async ({ request }: Route.ActionArgs) => {
 		})
 		.with("login", async () => {
 			const submission = processSubmission(formData, loginSchema);
-			const { loginUser } = await serverGqlService.request(LoginUserDocument, {
-				input: {
-					password: {
-						password: submission.password,
-						username: submission.username,
+			const { loginUser } = await serverGqlService.request(
+				LoginUserDocument,
+				{
+					input: {
+						password: {
+							password: submission.password,
+							username: submission.username,
+						},
 					},
 				},
-			});
+				getForwardedForHeaders(request),
+			);
 			if (loginUser.__typename === "ApiKeyResponse") {
 				const headers = await getCookiesForApplication(loginUser.apiKey);
 				return redirect($path("/"), { headers });
@@ -163,6 +197,10 @@ export const action = async ({ request }: Route.ActionArgs) => {
 					LoginErrorVariant.IncorrectProviderChosen,
 					() => "The provider chosen was incorrect",
 				)
+				.with(
+					LoginErrorVariant.AccountLocked,
+					() => "Too many failed attempts. Please try again later.",
+				)
 				.exhaustive();
 			return data({} as const, {
 				headers: await createToastHeaders({ message, type: "error" }),
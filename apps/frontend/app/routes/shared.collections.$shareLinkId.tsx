@@ -0,0 +1,215 @@
+import {
+	Alert,
+	Anchor,
+	Button,
+	Card,
+	Container,
+	Group,
+	Image,
+	SimpleGrid,
+	Stack,
+	Text,
+	Title,
+} from "@mantine/core";
+import {
+	EntityLot,
+	MetadataDetailsDocument,
+	MetadataGroupDetailsDocument,
+	PersonDetailsDocument,
+	SharedCollectionContentsDocument,
+} from "@ryot/generated/graphql/backend/graphql";
+import {
+	changeCase,
+	parseParameters,
+	parseSearchQuery,
+	zodNumAsString,
+} from "@ryot/ts-utils";
+import { ClientError } from "graphql-request";
+import { Link, useLoaderData } from "react-router";
+import { $path } from "safe-routes";
+import { match } from "ts-pattern";
+import { z } from "zod";
+import { serverGqlService } from "~/lib/utilities.server";
+import type { Route } from "./+types/shared.collections.$shareLinkId";
+
+const searchParamsSchema = z.object({
+	page: zodNumAsString.optional(),
+	visitToken: z.string().optional(),
+});
+
+export type SearchParams = z.infer<typeof searchParamsSchema>;
+
+type SharedItem = {
+	id: string;
+	lot: EntityLot;
+	title?: string;
+	image?: string;
+	sourceUrl?: string | null;
+};
+
+const getSharedItem = async (id: string, lot: EntityLot): Promise<SharedItem> =>
+	match(lot)
+		.with(EntityLot.Metadata, async () => {
+			const { metadataDetails } = await serverGqlService.request(
+				MetadataDetailsDocument,
+				{ metadataId: id },
+			);
+			const { response } = metadataDetails;
+			return {
+				id,
+				lot,
+				title: response.title,
+				sourceUrl: response.sourceUrl,
+				image: response.assets.remoteImages.at(0),
+			};
+		})
+		.with(EntityLot.MetadataGroup, async () => {
+			const { metadataGroupDetails } = await serverGqlService.request(
+				MetadataGroupDetailsDocument,
+				{ metadataGroupId: id },
+			);
+			const { details } = metadataGroupDetails.response;
+			return {
+				id,
+				lot,
+				title: details.title,
+				sourceUrl: details.sourceUrl,
+				image: details.assets.remoteImages.at(0),
+			};
+		})
+		.with(EntityLot.Person, async () => {
+			const { personDetails } = await serverGqlService.request(
+				PersonDetailsDocument,
+				{ personId: id },
+			);
+			const { details } = personDetails.response;
+			return {
+				id,
+				lot,
+				title: details.name,
+				sourceUrl: details.sourceUrl,
+				image: details.assets.remoteImages.at(0),
+			};
+		})
+		.otherwise(async () => ({ id, lot }));
+
+export const loader = async ({ request, params }: Route.LoaderArgs) => {
+	const { shareLinkId } = parseParameters(
+		params,
+		z.object({ shareLinkId: z.string() }),
+	);
+	const query = parseSearchQuery(request, searchParamsSchema);
+	const page = query.page || 1;
+	const contents = await serverGqlService
+		.request(SharedCollectionContentsDocument, {
+			input: { shareLinkId, search: { page }, visitToken: query.visitToken },
+		})
+		.then((d) => d.sharedCollectionContents)
+		.catch((error) => {
+			if (!(error instanceof ClientError)) throw error;
+			return error.response.errors?.at(0)?.message || "Share link not found";
+		});
+	if (typeof contents === "string")
+		return {
+			page,
+			shareLinkId,
+			error: contents,
+			contents: null,
+			visitToken: null,
+		};
+	const items = await Promise.all(
+		contents.results.items.map((item) =>
+			getSharedItem(item.entityId, item.entityLot).catch(() => ({
+				id: item.entityId,
+				lot: item.entityLot,
+			})),
+		),
+	);
+	return {
+		page,
+		shareLinkId,
+		error: null,
+		visitToken: contents.visitToken,
+		contents: { ...contents, items },
+	};
+};
+
+export const meta = ({ data }: Route.MetaArgs) => [
+	{ title: `${data?.contents?.details.name || "Shared Collection"} | Ryot` },
+];
+
+export default function Page() {
+	const loaderData = useLoaderData<typeof loader>();
+	const { contents, page, shareLinkId, visitToken } = loaderData;
+
+	if (!contents)
+		return (
+			<Container size="sm">
+				<Alert color="red" mt="xl" title="This share link can not be opened">
+					{loaderData.error}
+				</Alert>
+			</Container>
+		);
+
+	const pagePath = (page: number) =>
+		$path(
+			"/shared/collections/:shareLinkId",
+			{ shareLinkId },
+			{ page, visitToken: visitToken ?? undefined },
+		);
+
+	return (
+		<Container>
+			<Stack my="xl">
+				<Stack gap={4}>
+					<Title>{contents.details.name}</Title>
+					<Text c="dimmed" size="sm">
+						Shared by {contents.user.name} • {contents.totalItems} items
+					</Text>
+					{contents.details.description ? (
+						<Text>{contents.details.description}</Text>
+					) : null}
+				</Stack>
+				<SimpleGrid cols={{ base: 2, sm: 3, md: 5 }}>
+					{contents.items.map((item) => (
+						<Card key={item.id} withBorder padding="xs">
+							{item.image ? (
+								<Card.Section>
+									<Image src={item.image} h={220} alt={item.title} />
+								</Card.Section>
+							) : null}
+							<Stack gap={2} mt="xs">
+								{item.sourceUrl ? (
+									<Anchor href={item.sourceUrl} target="_blank" lineClamp={2}>
+										{item.title}
+									</Anchor>
+								) : (
+									<Text lineClamp={2}>{item.title || item.id}</Text>
+								)}
+								<Text c="dimmed" size="xs">
+									{changeCase(item.lot)}
+								</Text>
+							</Stack>
+						</Card>
+					))}
+				</SimpleGrid>
+				<Group justify="center">
+					{page > 1 ? (
+						<Button component={Link} to={pagePath(page - 1)} variant="default">
+							Previous
+						</Button>
+					) : null}
+					{contents.results.details.nextPage ? (
+						<Button
+							component={Link}
+							variant="default"
+							to={pagePath(contents.results.details.nextPage)}
+						>
+							Next
+						</Button>
+					) : null}
+				</Group>
+			</Stack>
+		</Container>
+	);
+}
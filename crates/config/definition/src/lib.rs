@@ -198,6 +198,17 @@ pub struct ListenNotesConfig {
 #[config(rename_all = "snake_case", env_prefix = "PODCASTS_ITUNES_")]
 pub struct ITunesConfig {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "PODCASTS_PODCAST_INDEX_")]
+pub struct PodcastIndexConfig {
+    /// The API key for the Podcast Index API.
+    #[mask]
+    pub api_key: String,
+    /// The API secret for the Podcast Index API.
+    #[mask]
+    pub api_secret: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
 #[config(rename_all = "snake_case")]
 pub struct PodcastConfig {
@@ -208,6 +219,10 @@ pub struct PodcastConfig {
     #[setting(nested)]
     #[mask_nested]
     pub listennotes: ListenNotesConfig,
+    /// Settings related to Podcast Index.
+    #[setting(nested)]
+    #[mask_nested]
+    pub podcast_index: PodcastIndexConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
@@ -281,6 +296,14 @@ pub struct MetronConfig {
     pub password: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "COMIC_BOOK_COMIC_VINE_")]
+pub struct ComicVineConfig {
+    /// The API key for the Comic Vine API.
+    #[mask]
+    pub api_key: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
 #[config(rename_all = "snake_case")]
 pub struct ComicBookConfig {
@@ -288,11 +311,39 @@ pub struct ComicBookConfig {
     #[setting(nested)]
     #[mask_nested]
     pub metron: MetronConfig,
+    /// Settings related to Comic Vine.
+    #[setting(nested)]
+    #[mask_nested]
+    pub comic_vine: ComicVineConfig,
 }
 
 impl ComicBookConfig {
     pub fn is_enabled(&self) -> bool {
-        !self.metron.username.is_empty() && !self.metron.password.is_empty()
+        (!self.metron.username.is_empty() && !self.metron.password.is_empty())
+            || !self.comic_vine.api_key.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "BOARD_GAMES_BOARD_GAME_GEEK_")]
+pub struct BoardGameGeekConfig {
+    /// The access token for the BoardGameGeek XML API.
+    #[mask]
+    pub access_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case")]
+pub struct BoardGameConfig {
+    /// Settings related to BoardGameGeek.
+    #[setting(nested)]
+    #[mask_nested]
+    pub board_game_geek: BoardGameGeekConfig,
+}
+
+impl BoardGameConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.board_game_geek.access_token.is_empty()
     }
 }
 
@@ -300,9 +351,23 @@ impl ComicBookConfig {
 #[config(rename_all = "snake_case", env_prefix = "VISUAL_NOVEL_")]
 pub struct VisualNovelConfig {}
 
+derive_enum!(
+    #[derive(ConfigEnum, Default)]
+    pub enum FileStorageBackend {
+        #[default]
+        S3,
+        Local,
+    }
+);
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
 #[config(rename_all = "snake_case", env_prefix = "FILE_STORAGE_")]
 pub struct FileStorageConfig {
+    /// The backend to use for file storage.
+    pub backend: FileStorageBackend,
+    /// The directory in which files will be stored when using the local backend.
+    #[setting(default = "storage")]
+    pub local_directory: String,
     /// The URL for the S3 compatible file storage.
     pub s3_url: String,
     /// The region for the S3 compatible file storage.
@@ -322,15 +387,15 @@ pub struct FileStorageConfig {
 
 impl FileStorageConfig {
     pub fn is_enabled(&self) -> bool {
-        let mut enabled = false;
-        if !self.s3_url.is_empty()
-            && !self.s3_access_key_id.is_empty()
-            && !self.s3_bucket_name.is_empty()
-            && !self.s3_secret_access_key.is_empty()
-        {
-            enabled = true;
+        match self.backend {
+            FileStorageBackend::Local => !self.local_directory.is_empty(),
+            FileStorageBackend::S3 => {
+                !self.s3_url.is_empty()
+                    && !self.s3_access_key_id.is_empty()
+                    && !self.s3_bucket_name.is_empty()
+                    && !self.s3_secret_access_key.is_empty()
+            }
         }
-        enabled
     }
 }
 
@@ -407,6 +472,45 @@ pub struct OidcConfig {
     pub issuer_url: String,
     #[mask]
     pub client_secret: String,
+    /// The claim in the ID token which lists the groups the user is a member of.
+    #[setting(default = "groups")]
+    pub groups_claim: String,
+    /// Members of this group become admins. Users not in it lose admin access on
+    /// their next login.
+    pub admin_group: String,
+    /// Members of this group have their account disabled on their next login. Users not
+    /// in it are enabled again.
+    pub disabled_group: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "SERVER_LDAP_")]
+pub struct LdapConfig {
+    /// The URL of the LDAP server. Use `ldaps://` for LDAPS. **Required** to enable LDAP.
+    pub url: String,
+    /// Whether to upgrade plain `ldap://` connections using StartTLS.
+    #[setting(default = false)]
+    pub starttls: bool,
+    /// The DN used to bind as the user. `{username}` is replaced with the username.
+    /// For example: `uid={username},ou=people,dc=example,dc=org`.
+    pub bind_dn_template: String,
+    /// The DN under which the user entry is searched for after binding.
+    pub base_dn: String,
+    /// The filter the user entry must match to be allowed to login. `{username}` is
+    /// replaced with the username.
+    #[setting(default = "(uid={username})")]
+    pub search_filter: String,
+    /// The attribute of the user entry which lists the groups it is a member of.
+    #[setting(default = "memberOf")]
+    pub group_attribute: String,
+    /// The DN of the group whose members become admins in Ryot.
+    pub admin_group_dn: String,
+}
+
+impl LdapConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.url.is_empty() && !self.bind_dn_template.is_empty()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
@@ -427,6 +531,9 @@ pub struct ServerConfig {
     #[setting(nested)]
     #[mask_nested]
     pub oidc: OidcConfig,
+    /// The LDAP related settings.
+    #[setting(nested)]
+    pub ldap: LdapConfig,
     /// The mailer related settings.
     #[setting(nested)]
     #[mask_nested]
@@ -485,6 +592,25 @@ pub struct UsersConfig {
     /// The number of days till login authentication token is valid.
     #[setting(default = 90)]
     pub token_valid_for_days: i32,
+    /// The number of days after which entries in the audit log are deleted.
+    #[setting(default = 90)]
+    pub audit_log_retention_days: i64,
+    /// The number of failed login attempts after which the username or IP address is
+    /// locked out.
+    #[setting(default = 5)]
+    pub login_max_failed_attempts: u32,
+    /// The number of minutes a lockout lasts. Failed attempts are also forgotten after
+    /// this much time without any new ones.
+    #[setting(default = 15)]
+    pub login_lockout_duration_minutes: i64,
+    /// The header set by a reverse proxy (eg: `Remote-User`) which contains the username
+    /// of the authenticated user. Header authentication is disabled when this is empty.
+    pub trusted_proxy_header: String,
+    /// The CIDRs of the reverse proxies which are allowed to set the trusted header and the
+    /// `X-Forwarded-For` header with the address of the client. Loopback addresses belong to
+    /// the bundled proxy and are never trusted.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub trusted_proxy_cidrs: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
@@ -530,6 +656,10 @@ pub struct AppConfig {
     #[setting(nested)]
     #[mask_nested]
     pub comic_books: ComicBookConfig,
+    /// Settings related to board games.
+    #[setting(nested)]
+    #[mask_nested]
+    pub board_games: BoardGameConfig,
     /// Settings related to audio books.
     #[setting(nested)]
     pub audio_books: AudioBookConfig,
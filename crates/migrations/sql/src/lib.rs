@@ -30,6 +30,17 @@ mod m20251128_create_entity_translation;
 mod m20251218_is_v10_migration;
 mod m20260118_changes_for_issue_1672;
 mod m20260201_changes_for_issue_1044;
+mod m20261018_create_koreader_sync_progress;
+mod m20261019_changes_for_notification_quiet_hours;
+mod m20261020_create_user_api_key;
+mod m20261021_create_user_passkey;
+mod m20261022_create_user_audit_log;
+mod m20261023_create_collection_share_link;
+mod m20261024_changes_for_collection_collaborator_roles;
+mod m20261025_changes_for_board_games;
+mod m20261026_changes_for_export_completed_notification;
+mod m20261027_changes_for_account_locked_notification;
+mod m20261028_create_rss_feed;
 
 pub struct Migrator;
 
@@ -66,6 +77,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20251218_is_v10_migration::Migration),
             Box::new(m20260118_changes_for_issue_1672::Migration),
             Box::new(m20260201_changes_for_issue_1044::Migration),
+            Box::new(m20261018_create_koreader_sync_progress::Migration),
+            Box::new(m20261019_changes_for_notification_quiet_hours::Migration),
+            Box::new(m20261020_create_user_api_key::Migration),
+            Box::new(m20261021_create_user_passkey::Migration),
+            Box::new(m20261022_create_user_audit_log::Migration),
+            Box::new(m20261023_create_collection_share_link::Migration),
+            Box::new(m20261024_changes_for_collection_collaborator_roles::Migration),
+            Box::new(m20261025_changes_for_board_games::Migration),
+            Box::new(m20261026_changes_for_export_completed_notification::Migration),
+            Box::new(m20261027_changes_for_account_locked_notification::Migration),
+            Box::new(m20261028_create_rss_feed::Migration),
         ]
     }
 }
@@ -55,6 +55,7 @@ pub enum Metadata {
     CreatedByUserId,
     VideoGameSpecifics,
     ComicBookSpecifics,
+    BoardGameSpecifics,
     VisualNovelSpecifics,
     MusicSpecifics,
     WatchProviders,
@@ -111,6 +112,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Metadata::CreatedByUserId).text())
                     .col(ColumnDef::new(Metadata::Assets).json_binary().not_null())
                     .col(ColumnDef::new(Metadata::ComicBookSpecifics).json_binary())
+                    .col(ColumnDef::new(Metadata::BoardGameSpecifics).json_binary())
                     .foreign_key(
                         ForeignKey::create()
                             .name(METADATA_TO_USER_FOREIGN_KEY)
@@ -31,6 +31,7 @@ pub enum Seen {
     PodcastExtraInformation,
     AnimeExtraInformation,
     MangaExtraInformation,
+    BoardGameExtraInformation,
     ProvidersConsumedOn,
     ManualTimeSpent,
 }
@@ -67,6 +68,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Seen::PodcastExtraInformation).json_binary())
                     .col(ColumnDef::new(Seen::AnimeExtraInformation).json_binary())
                     .col(ColumnDef::new(Seen::MangaExtraInformation).json_binary())
+                    .col(ColumnDef::new(Seen::BoardGameExtraInformation).json_binary())
                     .col(
                         ColumnDef::new(Seen::LastUpdatedOn)
                             .timestamp_with_time_zone()
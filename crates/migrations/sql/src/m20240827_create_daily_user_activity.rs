@@ -51,6 +51,8 @@ pub enum DailyUserActivity {
     VideoGameDuration,
     ComicBookCount,
     ComicBookPages,
+    BoardGameCount,
+    BoardGameDuration,
     VisualNovelCount,
     VisualNovelDuration,
     WorkoutPersonalBests,
@@ -163,6 +165,8 @@ impl MigrationTrait for Migration {
                     .col(integer_not_null(DailyUserActivity::TotalCollectionCount))
                     .col(integer_not_null(DailyUserActivity::ComicBookCount))
                     .col(integer_not_null(DailyUserActivity::ComicBookPages))
+                    .col(integer_not_null(DailyUserActivity::BoardGameCount))
+                    .col(integer_not_null(DailyUserActivity::BoardGameDuration))
                     .foreign_key(
                         ForeignKey::create()
                             .name("daily_user_activity_to_user_foreign_key")
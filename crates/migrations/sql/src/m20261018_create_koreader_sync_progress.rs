@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20240607_create_integration::Integration;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum KoreaderSyncProgress {
+    Table,
+    Id,
+    Device,
+    DeviceId,
+    Document,
+    Progress,
+    UpdatedOn,
+    Percentage,
+    IntegrationId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(KoreaderSyncProgress::Table)
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::Id)
+                            .uuid()
+                            .not_null()
+                            .default(PgFunc::gen_random_uuid())
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::IntegrationId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::Document)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::Progress)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::Percentage)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::Device)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::DeviceId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderSyncProgress::UpdatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("koreader_sync_progress_to_integration_foreign_key")
+                            .from(
+                                KoreaderSyncProgress::Table,
+                                KoreaderSyncProgress::IntegrationId,
+                            )
+                            .to(Integration::Table, Integration::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .unique()
+                    .name("koreader_sync_progress_integration_document_idx")
+                    .table(KoreaderSyncProgress::Table)
+                    .col(KoreaderSyncProgress::IntegrationId)
+                    .col(KoreaderSyncProgress::Document)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
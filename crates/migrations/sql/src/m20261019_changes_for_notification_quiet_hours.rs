@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20240712_create_notification_platform::NotificationPlatform;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum QueuedNotification {
+    Id,
+    Table,
+    Content,
+    CreatedOn,
+    NotificationPlatformId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+ALTER TABLE notification_platform ADD COLUMN IF NOT EXISTS "quiet_hours" JSONB;
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(QueuedNotification::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(QueuedNotification::Id)
+                            .uuid()
+                            .not_null()
+                            .default(PgFunc::gen_random_uuid())
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(QueuedNotification::NotificationPlatformId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(QueuedNotification::Content)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(QueuedNotification::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("queued_notification_to_notification_platform_foreign_key")
+                            .from(
+                                QueuedNotification::Table,
+                                QueuedNotification::NotificationPlatformId,
+                            )
+                            .to(NotificationPlatform::Table, NotificationPlatform::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230404_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum UserApiKey {
+    Id,
+    Name,
+    Table,
+    UserId,
+    Scopes,
+    CreatedOn,
+    IsRevoked,
+    TokenHash,
+    LastUsedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserApiKey::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserApiKey::Id)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserApiKey::Name).text().not_null())
+                    .col(ColumnDef::new(UserApiKey::UserId).text().not_null())
+                    .col(
+                        ColumnDef::new(UserApiKey::Scopes)
+                            .array(ColumnType::Text)
+                            .not_null()
+                            .default("{}"),
+                    )
+                    .col(
+                        ColumnDef::new(UserApiKey::TokenHash)
+                            .text()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserApiKey::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(UserApiKey::LastUsedOn).timestamp_with_time_zone())
+                    .col(ColumnDef::new(UserApiKey::IsRevoked).boolean())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("user_api_key_to_user_foreign_key")
+                            .from(UserApiKey::Table, UserApiKey::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("user_api_key__user_id_idx")
+                    .table(UserApiKey::Table)
+                    .col(UserApiKey::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230404_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum UserPasskey {
+    Id,
+    Name,
+    Table,
+    UserId,
+    CreatedOn,
+    Credential,
+    LastUsedOn,
+    CredentialId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserPasskey::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserPasskey::Id)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserPasskey::Name).text().not_null())
+                    .col(ColumnDef::new(UserPasskey::UserId).text().not_null())
+                    .col(
+                        ColumnDef::new(UserPasskey::CredentialId)
+                            .text()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserPasskey::Credential)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserPasskey::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(UserPasskey::LastUsedOn).timestamp_with_time_zone())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("user_passkey_to_user_foreign_key")
+                            .from(UserPasskey::Table, UserPasskey::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("user_passkey__user_id_idx")
+                    .table(UserPasskey::Table)
+                    .col(UserPasskey::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
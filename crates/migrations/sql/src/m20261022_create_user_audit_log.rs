@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230404_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum UserAuditLog {
+    Id,
+    Lot,
+    Table,
+    UserId,
+    CreatedOn,
+    Information,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserAuditLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserAuditLog::Id)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserAuditLog::Lot).text().not_null())
+                    .col(ColumnDef::new(UserAuditLog::UserId).text())
+                    .col(
+                        ColumnDef::new(UserAuditLog::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(UserAuditLog::Information)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("user_audit_log_to_user_foreign_key")
+                            .from(UserAuditLog::Table, UserAuditLog::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("user_audit_log__user_id_idx")
+                    .table(UserAuditLog::Table)
+                    .col(UserAuditLog::UserId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("user_audit_log__created_on_idx")
+                    .table(UserAuditLog::Table)
+                    .col(UserAuditLog::CreatedOn)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+use super::{m20230404_create_user::User, m20230504_create_collection::Collection};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum CollectionShareLink {
+    Id,
+    Table,
+    UserId,
+    CreatedOn,
+    ExpiresOn,
+    IsRevoked,
+    TimesUsed,
+    MaximumUses,
+    CollectionId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CollectionShareLink::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CollectionShareLink::Id)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionShareLink::UserId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionShareLink::CollectionId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CollectionShareLink::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(CollectionShareLink::ExpiresOn).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(CollectionShareLink::TimesUsed)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(CollectionShareLink::MaximumUses).integer())
+                    .col(ColumnDef::new(CollectionShareLink::IsRevoked).boolean())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("collection_share_link_to_user_foreign_key")
+                            .from(CollectionShareLink::Table, CollectionShareLink::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("collection_share_link_to_collection_foreign_key")
+                            .from(
+                                CollectionShareLink::Table,
+                                CollectionShareLink::CollectionId,
+                            )
+                            .to(Collection::Table, Collection::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("collection_share_link__collection_id_idx")
+                    .table(CollectionShareLink::Table)
+                    .col(CollectionShareLink::CollectionId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use indoc::indoc;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+static COLLECTION_ENTITY_MEMBERSHIP_VIEW_REPLACEMENT_SQL: &str = indoc! { r#"
+    CREATE OR REPLACE VIEW collection_entity_membership AS
+    SELECT
+        ute."user_id",
+        cte."entity_id",
+        cte."entity_lot",
+        c."name" AS "collection_name",
+        cte."id" AS "collection_to_entity_id",
+        cte."rank" AS "collection_to_entity_rank",
+        cte."collection_id" AS "origin_collection_id",
+        cte."created_on" AS "collection_to_entity_created_on",
+        cte."information" AS "collection_to_entity_information",
+        cte."last_updated_on" AS "collection_to_entity_last_updated_on",
+        cte."added_by_user_id" AS "collection_to_entity_added_by_user_id"
+    FROM
+        "collection_to_entity" cte
+    JOIN
+        "collection" c ON cte."collection_id" = c."id"
+    JOIN
+        "user_to_entity" ute ON cte."collection_id" = ute."collection_id"
+    JOIN
+        "user" u ON ute."user_id" = u."id"
+    WHERE
+        (u."is_disabled" IS NULL OR u."is_disabled" = false)
+"# };
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            r#"
+ALTER TABLE user_to_entity ADD COLUMN IF NOT EXISTS "collection_collaborator_role" TEXT;
+UPDATE user_to_entity SET "collection_collaborator_role" = 'editor'
+WHERE "collection_id" IS NOT NULL AND "collection_collaborator_role" IS NULL;
+
+ALTER TABLE collection_to_entity ADD COLUMN IF NOT EXISTS "added_by_user_id" TEXT
+REFERENCES "user"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(COLLECTION_ENTITY_MEMBERSHIP_VIEW_REPLACEMENT_SQL)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
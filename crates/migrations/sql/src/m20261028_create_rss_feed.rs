@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Feed URLs can contain private tokens, so they are kept here instead of being used
+/// as the (publicly visible) identifier of the metadata.
+#[derive(Iden)]
+pub enum RssFeed {
+    Id,
+    Url,
+    Table,
+    CreatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RssFeed::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RssFeed::Id).text().not_null().primary_key())
+                    .col(ColumnDef::new(RssFeed::Url).text().not_null())
+                    .col(
+                        ColumnDef::new(RssFeed::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use common_models::{ChangeCollectionToEntitiesInput, EntityWithLot};
+use common_models::{ChangeCollectionToEntitiesInput, DeployExportJobInput, EntityWithLot};
 use enum_models::{EntityLot, EntityTranslationVariant};
 use media_models::{
     DeployImportJobInput, MetadataProgressUpdateInput, PodcastTranslationExtraInformation,
@@ -30,7 +30,10 @@ pub struct UpdateMediaTranslationJobInput {
 #[derive(Debug, Deserialize, Serialize, Display, Clone)]
 pub enum MpApplicationJob {
     SyncIntegrationsData,
+    /// Only carries the user id, kept so that exports queued by older versions still run.
     PerformExport(String),
+    PerformExportWithInput(String, DeployExportJobInput),
+    PerformScheduledExport(String, DeployExportJobInput),
     UpdateExerciseLibrary,
     UpdateGithubExercises,
     PerformBackgroundTasks,
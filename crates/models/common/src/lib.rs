@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use async_graphql::{Enum, InputObject, SimpleObject};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 use enum_meta::{Meta, meta};
 use enum_models::{EntityLot, FilterPresetContextType, MediaLot, MediaSource};
 use schematic::{ConfigEnum, Schematic};
@@ -20,6 +20,44 @@ pub struct StringIdObject {
     pub id: String,
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct NotificationWebhookHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// Non-urgent notifications sent between the start and end time (in the server's timezone)
+/// are queued and delivered once the quiet hours are over.
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    PartialEq,
+    Serialize,
+    InputObject,
+    Deserialize,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "NotificationPlatformQuietHoursInput")]
+pub struct NotificationPlatformQuietHours {
+    pub is_enabled: bool,
+    pub end_time: NaiveTime,
+    pub start_time: NaiveTime,
+}
+
+impl NotificationPlatformQuietHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if !self.is_enabled {
+            return false;
+        }
+        match self.start_time <= self.end_time {
+            true => self.start_time <= time && time < self.end_time,
+            false => time >= self.start_time || time < self.end_time,
+        }
+    }
+}
+
 #[derive(
     Eq,
     Debug,
@@ -260,6 +298,7 @@ pub enum BackendError {
     SessionExpired,
     AdminOnlyAction,
     MutationNotAllowed,
+    ApiKeyScopeNotAllowed,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, InputObject)]
@@ -327,13 +366,62 @@ pub struct CreateFilterPresetInput {
     pub context_information: Option<serde_json::Value>,
 }
 
+#[derive(
+    Eq, Copy, Hash, Enum, Clone, Debug, Display, EnumIter, PartialEq, Serialize, Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ExportItem {
+    People,
+    Workouts,
+    Metadata,
+    Exercises,
+    Collections,
+    Measurements,
+    MetadataGroups,
+    WorkoutTemplates,
+}
+
+#[derive(
+    Eq, Copy, Hash, Enum, Clone, Debug, Default, Display, PartialEq, Serialize, Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// A single JSON document containing all the selected items.
+    #[default]
+    Json,
+    /// A zip archive containing one CSV file per entity type.
+    Csv,
+    /// A CSV file of watched movies that can be imported into Letterboxd.
+    Letterboxd,
+    /// An OPML file of monitored and in progress podcasts for podcast apps.
+    Opml,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, InputObject, Clone, Serialize, Deserialize)]
+pub struct DeployExportJobInput {
+    /// The format of the export. Defaults to JSON.
+    pub format: Option<ExportFormat>,
+    /// The items to include in the export. Defaults to all items.
+    pub items: Option<Vec<ExportItem>>,
+    /// Only include history, reviews, workouts and measurements within this range.
+    pub date_range: Option<ApplicationDateRange>,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct ExportJob {
     pub size: i64,
     pub url: String,
     pub key: String,
+    pub format: ExportFormat,
+    pub items: Vec<ExportItem>,
     pub ended_at: DateTimeUtc,
     pub started_at: DateTimeUtc,
+    /// Whether the export was created by the user's export schedule.
+    pub is_scheduled: bool,
+    pub date_range: Option<ApplicationDateRange>,
 }
 
 #[skip_serializing_none]
@@ -39,6 +39,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::collection_to_entity::Entity")]
     CollectionToEntity,
+    #[sea_orm(has_many = "super::collection_share_link::Entity")]
+    CollectionShareLink,
     #[sea_orm(has_many = "super::review::Entity")]
     Review,
     #[sea_orm(
@@ -59,6 +61,12 @@ impl Related<super::collection_to_entity::Entity> for Entity {
     }
 }
 
+impl Related<super::collection_share_link::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CollectionShareLink.def()
+    }
+}
+
 impl Related<super::review::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Review.def()
@@ -20,6 +20,7 @@ pub struct Model {
     pub collection_to_entity_created_on: DateTimeUtc,
     pub collection_to_entity_last_updated_on: DateTimeUtc,
     pub collection_to_entity_information: Option<serde_json::Value>,
+    pub collection_to_entity_added_by_user_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -24,6 +24,7 @@ pub struct Model {
     pub metadata_group_id: Option<String>,
     pub workout_template_id: Option<String>,
     pub information: Option<serde_json::Value>,
+    pub added_by_user_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
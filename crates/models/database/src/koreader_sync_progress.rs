@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "koreader_sync_progress")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub device: String,
+    pub document: String,
+    pub progress: String,
+    pub device_id: String,
+    pub percentage: Decimal,
+    pub integration_id: String,
+    pub updated_on: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::integration::Entity",
+        from = "Column::IntegrationId",
+        to = "super::integration::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Integration,
+}
+
+impl Related<super::integration::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Integration.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {}
@@ -7,6 +7,7 @@ pub mod application_cache;
 pub mod calendar_event;
 pub mod collection;
 pub mod collection_entity_membership;
+pub mod collection_share_link;
 pub mod collection_to_entity;
 pub mod daily_user_activity;
 pub mod entity_translation;
@@ -16,6 +17,7 @@ pub mod functions;
 pub mod genre;
 pub mod import_report;
 pub mod integration;
+pub mod koreader_sync_progress;
 pub mod metadata;
 pub mod metadata_group;
 pub mod metadata_group_to_person;
@@ -25,10 +27,15 @@ pub mod metadata_to_metadata_group;
 pub mod metadata_to_person;
 pub mod notification_platform;
 pub mod person;
+pub mod queued_notification;
 pub mod review;
+pub mod rss_feed;
 pub mod seen;
 pub mod user;
+pub mod user_api_key;
+pub mod user_audit_log;
 pub mod user_measurement;
+pub mod user_passkey;
 pub mod user_to_entity;
 pub mod workout;
 pub mod workout_template;
@@ -5,9 +5,10 @@ use chrono::NaiveDate;
 use common_models::EntityAssets;
 use enum_models::{MediaLot, MediaSource};
 use media_models::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, ComicBookSpecifics, MangaSpecifics,
-    MetadataExternalIdentifiers, MetadataFreeCreator, MovieSpecifics, MusicSpecifics,
-    PodcastSpecifics, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics, WatchProvider,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicBookSpecifics,
+    MangaSpecifics, MetadataExternalIdentifiers, MetadataFreeCreator, MovieSpecifics,
+    MusicSpecifics, PodcastSpecifics, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
+    WatchProvider,
 };
 use nanoid::nanoid;
 use rust_decimal::Decimal;
@@ -50,6 +51,7 @@ pub struct Model {
     pub free_creators: Option<Vec<MetadataFreeCreator>>,
     pub audio_book_specifics: Option<AudioBookSpecifics>,
     pub video_game_specifics: Option<VideoGameSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub comic_book_specifics: Option<ComicBookSpecifics>,
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
     #[sea_orm(column_type = "JsonBinary")]
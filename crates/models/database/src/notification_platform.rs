@@ -2,6 +2,7 @@
 
 use async_graphql::SimpleObject;
 use async_trait::async_trait;
+use common_models::NotificationPlatformQuietHours;
 use enum_models::{NotificationPlatformLot, UserNotificationContentDiscriminants};
 use nanoid::nanoid;
 use sea_orm::{ActiveValue, entity::prelude::*};
@@ -22,6 +23,7 @@ pub struct Model {
     #[graphql(skip)]
     pub platform_specifics: NotificationPlatformSpecifics,
     pub configured_events: Vec<UserNotificationContentDiscriminants>,
+    pub quiet_hours: Option<NotificationPlatformQuietHours>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -34,6 +36,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     User,
+    #[sea_orm(has_many = "super::queued_notification::Entity")]
+    QueuedNotification,
+}
+
+impl Related<super::queued_notification::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QueuedNotification.def()
+    }
 }
 
 impl Related<super::user::Entity> for Entity {
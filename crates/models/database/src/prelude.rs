@@ -5,6 +5,7 @@ pub use super::application_cache::Entity as ApplicationCache;
 pub use super::calendar_event::Entity as CalendarEvent;
 pub use super::collection::Entity as Collection;
 pub use super::collection_entity_membership::Entity as CollectionEntityMembership;
+pub use super::collection_share_link::Entity as CollectionShareLink;
 pub use super::collection_to_entity::Entity as CollectionToEntity;
 pub use super::daily_user_activity::Entity as DailyUserActivity;
 pub use super::entity_translation::Entity as EntityTranslation;
@@ -13,6 +14,7 @@ pub use super::filter_preset::Entity as FilterPreset;
 pub use super::genre::Entity as Genre;
 pub use super::import_report::Entity as ImportReport;
 pub use super::integration::Entity as Integration;
+pub use super::koreader_sync_progress::Entity as KoreaderSyncProgress;
 pub use super::metadata::Entity as Metadata;
 pub use super::metadata_group::Entity as MetadataGroup;
 pub use super::metadata_group_to_person::Entity as MetadataGroupToPerson;
@@ -22,10 +24,15 @@ pub use super::metadata_to_metadata_group::Entity as MetadataToMetadataGroup;
 pub use super::metadata_to_person::Entity as MetadataToPerson;
 pub use super::notification_platform::Entity as NotificationPlatform;
 pub use super::person::Entity as Person;
+pub use super::queued_notification::Entity as QueuedNotification;
 pub use super::review::Entity as Review;
+pub use super::rss_feed::Entity as RssFeed;
 pub use super::seen::Entity as Seen;
 pub use super::user::Entity as User;
+pub use super::user_api_key::Entity as UserApiKey;
+pub use super::user_audit_log::Entity as UserAuditLog;
 pub use super::user_measurement::Entity as UserMeasurement;
+pub use super::user_passkey::Entity as UserPasskey;
 pub use super::user_to_entity::Entity as UserToEntity;
 pub use super::workout::Entity as Workout;
 pub use super::workout_template::Entity as WorkoutTemplate;
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use enum_models::UserNotificationContent;
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "queued_notification")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub created_on: DateTimeUtc,
+    pub notification_platform_id: String,
+    pub content: UserNotificationContent,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::notification_platform::Entity",
+        from = "Column::NotificationPlatformId",
+        to = "super::notification_platform::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    NotificationPlatform,
+}
+
+impl Related<super::notification_platform::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationPlatform.def()
+    }
+}
+
+#[async_trait]
+impl ActiveModelBehavior for ActiveModel {}
@@ -5,8 +5,8 @@ use async_trait::async_trait;
 use educe::Educe;
 use enum_models::SeenState;
 use media_models::{
-    SeenAnimeExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraInformation,
-    SeenShowExtraInformation,
+    SeenAnimeExtraInformation, SeenBoardGameExtraInformation, SeenMangaExtraInformation,
+    SeenPodcastExtraInformation, SeenShowExtraInformation,
 };
 use nanoid::nanoid;
 use rust_decimal::Decimal;
@@ -42,6 +42,7 @@ pub struct Model {
     pub anime_extra_information: Option<SeenAnimeExtraInformation>,
     pub manga_extra_information: Option<SeenMangaExtraInformation>,
     pub podcast_extra_information: Option<SeenPodcastExtraInformation>,
+    pub board_game_extra_information: Option<SeenBoardGameExtraInformation>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,6 +30,8 @@ pub enum Relation {
     AccessLink,
     #[sea_orm(has_many = "super::collection::Entity")]
     Collection,
+    #[sea_orm(has_many = "super::collection_share_link::Entity")]
+    CollectionShareLink,
     #[sea_orm(has_many = "super::daily_user_activity::Entity")]
     DailyUserActivity,
     #[sea_orm(has_many = "super::exercise::Entity")]
@@ -50,8 +52,14 @@ pub enum Relation {
     Review,
     #[sea_orm(has_many = "super::seen::Entity")]
     Seen,
+    #[sea_orm(has_many = "super::user_api_key::Entity")]
+    UserApiKey,
+    #[sea_orm(has_many = "super::user_audit_log::Entity")]
+    UserAuditLog,
     #[sea_orm(has_many = "super::user_measurement::Entity")]
     UserMeasurement,
+    #[sea_orm(has_many = "super::user_passkey::Entity")]
+    UserPasskey,
     #[sea_orm(has_many = "super::user_to_entity::Entity")]
     UserToEntity,
     #[sea_orm(has_many = "super::workout::Entity")]
@@ -72,6 +80,12 @@ impl Related<super::collection::Entity> for Entity {
     }
 }
 
+impl Related<super::collection_share_link::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CollectionShareLink.def()
+    }
+}
+
 impl Related<super::daily_user_activity::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::DailyUserActivity.def()
@@ -120,12 +134,30 @@ impl Related<super::seen::Entity> for Entity {
     }
 }
 
+impl Related<super::user_api_key::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserApiKey.def()
+    }
+}
+
+impl Related<super::user_audit_log::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserAuditLog.def()
+    }
+}
+
 impl Related<super::user_measurement::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserMeasurement.def()
     }
 }
 
+impl Related<super::user_passkey::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserPasskey.def()
+    }
+}
+
 impl Related<super::user_to_entity::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserToEntity.def()
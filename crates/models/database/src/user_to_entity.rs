@@ -2,7 +2,7 @@
 
 use async_graphql::SimpleObject;
 use common_models::UserToCollectionExtraInformation;
-use enum_models::{EntityLot, UserToMediaReason};
+use enum_models::{CollectionCollaboratorRole, EntityLot, UserToMediaReason};
 use fitness_models::UserToExerciseExtraInformation;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -32,6 +32,7 @@ pub struct Model {
     pub media_reason: Option<Vec<UserToMediaReason>>,
     pub exercise_extra_information: Option<UserToExerciseExtraInformation>,
     pub collection_extra_information: Option<UserToCollectionExtraInformation>,
+    pub collection_collaborator_role: Option<CollectionCollaboratorRole>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -35,6 +35,8 @@ pub struct DailyUserActivityItem {
     pub total_video_game_duration: i64,
     pub comic_book_count: i64,
     pub total_comic_book_pages: i64,
+    pub board_game_count: i64,
+    pub total_board_game_duration: i64,
     pub visual_novel_count: i64,
     pub total_visual_novel_duration: i64,
     pub total_workout_personal_bests: i64,
@@ -8,11 +8,11 @@ use common_models::{
 use enum_models::{EntityLot, EntityTranslationVariant};
 use fitness_models::{UserExercisesListInput, UserMeasurementsListInput};
 use media_models::{
-    GenreDetailsInput, GraphqlMetadataDetails, MetadataLookupResponse,
+    GenreDetailsInput, GraphqlMetadataDetails, LoginAttemptSubject, MetadataLookupResponse,
     MetadataProgressUpdateCacheInput, PodcastTranslationExtraInformation,
     ShowTranslationExtraInformation, TmdbMetadataLookupResult,
 };
-use sea_orm::FromJsonQueryResult;
+use sea_orm::{FromJsonQueryResult, prelude::DateTimeUtc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::{Display, EnumDiscriminants};
@@ -44,11 +44,54 @@ pub struct EmptyCacheValue {
 pub struct UserTwoFactorSetupCacheValue {
     pub secret: String,
 }
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq)]
+pub struct UserPasskeyRegistrationCacheValue {
+    pub state: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq)]
+pub struct UserPasskeyAuthenticationCacheValue {
+    pub state: String,
+    /// Set when the passkey is being used as the second factor for a known user.
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq)]
+pub struct UserLoginFailedAttemptsCacheValue {
+    pub failed_attempts: u32,
+    /// No login attempts are allowed until this time.
+    pub blocked_until: DateTimeUtc,
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserSessionValue {
     pub user_id: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
     pub access_link_id: Option<String>,
+    pub created_on: Option<DateTimeUtc>,
+    pub last_seen_on: Option<DateTimeUtc>,
+}
+
+/// Details about the client that a session is being created or used from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SessionClientDetails {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStorageTokenValue {
+    pub key: String,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionShareLinkVisitCacheValue {
+    pub share_link_id: String,
 }
 
 #[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -92,9 +135,14 @@ pub enum ApplicationCacheKey {
     PersonDetails(String),
     MetadataDetails(String),
     LogDownloadToken(String),
+    CollectionShareLinkVisit(String),
     MetadataGroupDetails(String),
+    FileStorageUploadToken(String),
+    FileStorageDownloadToken(String),
     UserPasswordChangeSession(String),
     CollectionRecommendations(String),
+    UserPasskeyAuthentication(String),
+    UserLoginFailedAttempts(LoginAttemptSubject),
     MetadataLookup(MetadataLookupCacheInput),
     TmdbMultiSearch(MetadataLookupCacheInput),
     UserTwoFactorSetup(UserLevelCacheKey<()>),
@@ -102,6 +150,7 @@ pub enum ApplicationCacheKey {
     UserPersonDetails(UserLevelCacheKey<String>),
     UserTwoFactorRateLimit(UserLevelCacheKey<()>),
     UserWorkoutDetails(UserLevelCacheKey<String>),
+    UserPasskeyRegistration(UserLevelCacheKey<()>),
     UserAnalyticsParameters(UserLevelCacheKey<()>),
     UserMetadataDetails(UserLevelCacheKey<String>),
     GenreDetails(UserLevelCacheKey<GenreDetailsInput>),
@@ -146,7 +195,10 @@ pub enum ApplicationCacheValue {
     CoreDetails(Box<CoreDetails>),
     UserSession(UserSessionValue),
     LogDownloadToken(EmptyCacheValue),
+    CollectionShareLinkVisit(CollectionShareLinkVisitCacheValue),
     PeopleSearch(PeopleSearchResponse),
+    FileStorageUploadToken(FileStorageTokenValue),
+    FileStorageDownloadToken(FileStorageTokenValue),
     SpotifyAccessToken(SpotifyAccessToken),
     MetadataLookup(MetadataLookupResponse),
     MetadataSearch(MetadataSearchResponse),
@@ -178,6 +230,9 @@ pub enum ApplicationCacheValue {
     UserCollectionContents(Box<CollectionContentsResponse>),
     UserMetadataGroupDetails(Box<UserMetadataGroupDetails>),
     UserPasswordChangeSession(UserPasswordChangeSessionValue),
+    UserPasskeyRegistration(UserPasskeyRegistrationCacheValue),
+    UserPasskeyAuthentication(UserPasskeyAuthenticationCacheValue),
+    UserLoginFailedAttempts(UserLoginFailedAttemptsCacheValue),
     YoutubeMusicSongListened(YoutubeMusicSongListenedResponse),
     UserMetadataRecommendationsSet(ApplicationRecommendations),
     UserWorkoutTemplateDetails(Box<UserWorkoutTemplateDetails>),
@@ -84,6 +84,7 @@ pub struct CoreDetails {
     pub website_url: String,
     pub signup_allowed: bool,
     pub is_demo_instance: bool,
+    pub proxy_auth_header: Option<String>,
     pub disable_telemetry: bool,
     pub max_file_size_mb: usize,
     pub repository_link: String,
@@ -1,6 +1,6 @@
 use async_graphql::{InputObject, InputType, OutputType, SimpleObject};
 use common_models::{ApplicationDateRange, EntityWithLot, SearchDetails, SearchInput};
-use database_models::{collection, metadata_group};
+use database_models::{collection, metadata_group, user_audit_log};
 use enum_models::MediaLot;
 use media_models::{
     CollectionContentsFilter, CollectionContentsSortBy, GenreListItem, GraphqlMetadataDetails,
@@ -19,6 +19,7 @@ use crate::{
 #[derive(PartialEq, Eq, Default, Serialize, Deserialize, Debug, SimpleObject, Clone)]
 #[graphql(concrete(name = "IdResults", params(String)))]
 #[graphql(concrete(params(EntityWithLot), name = "MediaCollectionContentsResults"))]
+#[graphql(concrete(name = "UserAuditLogResults", params(user_audit_log::Model)))]
 pub struct SearchResults<T: OutputType> {
     pub items: Vec<T>,
     pub details: SearchDetails,
@@ -52,6 +53,16 @@ pub struct CollectionContents {
     pub results: SearchResults<EntityWithLot>,
 }
 
+/// The contents of a collection opened through a share link.
+#[derive(Debug, SimpleObject)]
+pub struct SharedCollectionContentsResponse {
+    /// Send this back as `visit_token` while browsing the collection so that the requests
+    /// do not count as new uses of the share link.
+    pub visit_token: String,
+    #[graphql(flatten)]
+    pub contents: CollectionContents,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct MetadataGroupDetails {
     pub contents: Vec<String>,
@@ -78,6 +89,23 @@ pub struct CollectionContentsInput {
     pub sort: Option<SortInput<CollectionContentsSortBy>>,
 }
 
+/// Input parameters for retrieving the contents of a collection through a share link.
+/// Used by the `shared_collection_contents` query which does not require authentication.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject)]
+pub struct SharedCollectionContentsInput {
+    /// The identifier of the share link that was sent to the viewer.
+    pub share_link_id: String,
+    /// The token returned when the share link was opened. Requests without a valid token
+    /// count as a new use of the share link.
+    pub visit_token: Option<String>,
+    /// Optional pagination and text search parameters.
+    pub search: Option<SearchInput>,
+    /// Optional filters to narrow down collection contents by entity type, date range, or entity-specific criteria.
+    pub filter: Option<CollectionContentsFilter>,
+    /// Optional sorting configuration specifying how to order the collection contents.
+    pub sort: Option<SortInput<CollectionContentsSortBy>>,
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject)]
 pub struct CollectionRecommendationsInput {
     pub collection_id: String,
@@ -32,6 +32,8 @@ pub struct CollectionToEntityDetails {
     pub creator_user_id: String,
     pub last_updated_on: DateTimeUtc,
     pub information: Option<serde_json::Value>,
+    /// The user who added this entity to the collection. This is ignored during importing.
+    pub added_by_user_id: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject, Clone)]
@@ -86,6 +88,8 @@ pub struct UserDetails {
     pub is_disabled: Option<bool>,
     pub preferences: UserPreferences,
     pub oidc_issuer_id: Option<String>,
+    /// Whether the user can login with a password.
+    pub has_password: bool,
     pub access_link_id: Option<String>,
     pub extra_information: Option<UserExtraInformation>,
     pub times_two_factor_backup_codes_used: Option<usize>,
@@ -62,6 +62,8 @@ pub enum IntegrationProvider {
     YoutubeMusic,
     JellyfinPush,
     JellyfinSink,
+    KoreaderSync,
+    ListenBrainz,
     Audiobookshelf,
     RyotBrowserExtension,
 }
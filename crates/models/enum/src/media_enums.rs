@@ -38,6 +38,7 @@ pub enum MediaLot {
     Podcast,
     AudioBook,
     VideoGame,
+    BoardGame,
     ComicBook,
     VisualNovel,
 }
@@ -45,7 +46,8 @@ pub enum MediaLot {
 meta! {
     MediaLot, Vec<MediaSource>;
 
-    ComicBook, vec![MediaSource::Metron];
+    BoardGame, vec![MediaSource::BoardGameGeek];
+    ComicBook, vec![MediaSource::Metron, MediaSource::ComicVine];
     VisualNovel, vec![MediaSource::Vndb];
     AudioBook, vec![MediaSource::Audible];
     Show, vec![MediaSource::Tmdb, MediaSource::Tvdb];
@@ -54,10 +56,13 @@ meta! {
     Anime, vec![
         MediaSource::Anilist,
         MediaSource::Myanimelist,
+        MediaSource::Kitsu,
     ];
     Podcast, vec![
         MediaSource::Itunes,
         MediaSource::Listennotes,
+        MediaSource::PodcastIndex,
+        MediaSource::Rss,
     ];
     Music, vec![
         MediaSource::Spotify,
@@ -73,6 +78,7 @@ meta! {
         MediaSource::Anilist,
         MediaSource::Myanimelist,
         MediaSource::MangaUpdates,
+        MediaSource::Kitsu,
     ];
 }
 
@@ -103,6 +109,7 @@ pub enum MediaSource {
     Tmdb,
     Tvdb,
     Vndb,
+    Kitsu,
     #[default]
     Custom,
     Metron,
@@ -119,6 +126,10 @@ pub enum MediaSource {
     Openlibrary,
     MangaUpdates,
     YoutubeMusic,
+    BoardGameGeek,
+    ComicVine,
+    PodcastIndex,
+    Rss,
 }
 
 meta! {
@@ -126,6 +137,7 @@ meta! {
 
     Tvdb, None;
     Vndb, None;
+    Kitsu, None;
     Custom, None;
     Itunes, None;
     Anilist, None;
@@ -143,6 +155,10 @@ meta! {
     MusicBrainz, Some(MediaLot::Music);
     YoutubeMusic, Some(MediaLot::Music);
     GiantBomb, Some(MediaLot::VideoGame);
+    BoardGameGeek, Some(MediaLot::BoardGame);
+    ComicVine, Some(MediaLot::ComicBook);
+    PodcastIndex, None;
+    Rss, None;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
@@ -1,6 +1,6 @@
 use async_graphql::Enum;
 use rust_decimal::Decimal;
-use sea_orm::{DeriveActiveEnum, EnumIter, prelude::Date};
+use sea_orm::{DeriveActiveEnum, EnumIter, FromJsonQueryResult, prelude::Date};
 use sea_orm_migration::prelude::StringLen;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumDiscriminants};
@@ -32,13 +32,17 @@ pub enum NotificationPlatformLot {
     Apprise,
     Discord,
     PushOver,
+    Matrix,
     Telegram,
     PushSafer,
     PushBullet,
     Email,
+    Webhook,
 }
 
-#[derive(Debug, Clone, Display, PartialEq, Serialize, Deserialize, EnumDiscriminants)]
+#[derive(
+    Debug, Clone, Display, PartialEq, Serialize, Deserialize, EnumDiscriminants, FromJsonQueryResult,
+)]
 #[strum_discriminants(
     derive(Enum, EnumIter, Serialize, Deserialize, DeriveActiveEnum),
     sea_orm(
@@ -134,4 +138,24 @@ pub enum UserNotificationContent {
         entity_title: String,
         entity_lot: EntityLot,
     },
+    ExportCompleted {
+        format: String,
+    },
+    AccountLockedDueToFailedLogins {
+        failed_attempts: u32,
+        locked_for_minutes: i64,
+        ip_address: Option<String>,
+    },
+}
+
+impl UserNotificationContent {
+    /// Urgent notifications are delivered even during a platform's quiet hours.
+    pub fn is_urgent(&self) -> bool {
+        matches!(
+            self,
+            Self::IntegrationDisabledDueToTooManyErrors { .. }
+                | Self::NotificationFromReminderCollection { .. }
+                | Self::AccountLockedDueToFailedLogins { .. }
+        )
+    }
 }
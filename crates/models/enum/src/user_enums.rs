@@ -111,6 +111,32 @@ pub enum Visibility {
     Private,
 }
 
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    ConfigEnum,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+pub enum CollectionCollaboratorRole {
+    #[default]
+    Editor,
+    Viewer,
+}
+
 #[derive(
     Eq,
     Enum,
@@ -134,7 +160,10 @@ pub enum ImportSource {
     Imdb,
     Plex,
     Hevy,
+    Opml,
+    Simkl,
     Trakt,
+    Kitsu,
     Movary,
     Anilist,
     Grouvee,
@@ -145,6 +174,7 @@ pub enum ImportSource {
     StrongApp,
     Goodreads,
     Hardcover,
+    Letterboxd,
     Storygraph,
     Myanimelist,
     GenericJson,
@@ -184,3 +214,68 @@ pub enum UserToMediaReason {
     Collection,
     Monitoring,
 }
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    Serialize,
+    PartialEq,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+pub enum UserApiKeyScope {
+    /// Run queries, except those about the account and its security.
+    ReadMedia,
+    /// Update progress, reviews and collections.
+    WriteProgress,
+    /// Create and edit workouts, templates and measurements.
+    FitnessWrite,
+    /// Deploy imports and exports.
+    ImportExport,
+    /// Everything a regular session can do.
+    Admin,
+}
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    Serialize,
+    PartialEq,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+pub enum UserAuditEventLot {
+    Login,
+    LoginFailed,
+    UserDeleted,
+    TwoFactorEnabled,
+    AccessLinkUsed,
+    TwoFactorDisabled,
+    AccessLinkCreated,
+    AccessLinkRevoked,
+    IntegrationCreated,
+    IntegrationUpdated,
+    IntegrationDeleted,
+    ImpersonationLinkGenerated,
+    TwoFactorBackupCodesRegenerated,
+}
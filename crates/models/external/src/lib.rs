@@ -2,7 +2,7 @@ use common_models::StringIdObject;
 use rust_decimal::Decimal;
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
-use serde_with::{TimestampSeconds, formats::Flexible, serde_as};
+use serde_with::{TimestampSeconds, formats::Flexible, serde_as, skip_serializing_none};
 use strum::Display;
 
 pub mod audiobookshelf {
@@ -118,3 +118,61 @@ pub mod plex {
         pub media_container: T,
     }
 }
+
+pub mod koreader_sync {
+    use super::*;
+
+    #[serde_as]
+    #[skip_serializing_none]
+    #[derive(Debug, Serialize, Deserialize, Clone)]
+    pub struct DocumentProgress {
+        pub document: String,
+        pub progress: String,
+        pub percentage: f64,
+        pub device: String,
+        pub device_id: String,
+        #[serde_as(as = "Option<TimestampSeconds<i64, Flexible>>")]
+        pub timestamp: Option<DateTimeUtc>,
+    }
+}
+
+pub mod listenbrainz {
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ListenType {
+        Single,
+        Import,
+        PlayingNow,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+    pub struct AdditionalInfo {
+        pub recording_mbid: Option<String>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone)]
+    pub struct TrackMetadata {
+        pub artist_name: String,
+        pub track_name: String,
+        pub release_name: Option<String>,
+        #[serde(default)]
+        pub additional_info: AdditionalInfo,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, Clone)]
+    pub struct Listen {
+        #[serde_as(as = "Option<TimestampSeconds<i64, Flexible>>")]
+        #[serde(default)]
+        pub listened_at: Option<DateTimeUtc>,
+        pub track_metadata: TrackMetadata,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone)]
+    pub struct SubmitListens {
+        pub listen_type: ListenType,
+        pub payload: Vec<Listen>,
+    }
+}
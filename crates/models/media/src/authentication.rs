@@ -1,7 +1,7 @@
 use async_graphql::{Enum, InputObject, OneofObject, SimpleObject, Union};
 use common_models::StringIdObject;
-use enum_models::UserLot;
-use sea_orm::prelude::DateTimeUtc;
+use enum_models::{UserApiKeyScope, UserLot};
+use sea_orm::prelude::{DateTimeUtc, Uuid};
 use serde::{Deserialize, Serialize};
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
@@ -77,12 +77,20 @@ pub enum UserResetResult {
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
 pub enum LoginErrorVariant {
+    AccountLocked,
     AccountDisabled,
     CredentialsMismatch,
     UsernameDoesNotExist,
     IncorrectProviderChosen,
 }
 
+/// What failed login attempts are tracked against.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, OneofObject)]
+pub enum LoginAttemptSubject {
+    Username(String),
+    IpAddress(String),
+}
+
 #[derive(Debug, SimpleObject)]
 pub struct LoginError {
     pub error: LoginErrorVariant,
@@ -116,6 +124,30 @@ pub struct CreateAccessLinkInput {
     pub is_mutation_allowed: Option<bool>,
 }
 
+#[derive(Debug, SimpleObject)]
+pub struct UserSession {
+    pub id: Uuid,
+    pub is_current: bool,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub access_link_id: Option<String>,
+    pub created_on: Option<DateTimeUtc>,
+    pub last_seen_on: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct CreateUserApiKeyInput {
+    pub name: String,
+    pub scopes: Vec<UserApiKeyScope>,
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct CreateUserApiKeyResponse {
+    pub id: String,
+    /// The raw key. It is not stored and can not be retrieved again.
+    pub api_key: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, OneofObject, Clone)]
 pub enum ProcessAccessLinkInput {
     Id(String),
@@ -195,6 +227,27 @@ pub enum VerifyTwoFactorResult {
     Error(VerifyTwoFactorError),
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct FinishPasskeyRegistrationInput {
+    pub name: String,
+    /// The `PublicKeyCredential` returned by `navigator.credentials.create()`.
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct StartPasskeyAuthenticationResponse {
+    pub challenge_id: String,
+    /// Options to be passed to `navigator.credentials.get()`.
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct FinishPasskeyAuthenticationInput {
+    pub challenge_id: String,
+    /// The `PublicKeyCredential` returned by `navigator.credentials.get()`.
+    pub credential: serde_json::Value,
+}
+
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct GetPasswordChangeSessionInput {
     pub user_id: String,
@@ -213,6 +266,8 @@ pub struct SetPasswordViaSessionInput {
     #[graphql(secret)]
     pub password: String,
     pub session_id: String,
+    /// Log the user out everywhere else once the password has been changed.
+    pub revoke_other_sessions: Option<bool>,
 }
 
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
@@ -4,11 +4,11 @@ use common_models::{
     UserToCollectionExtraInformation,
 };
 use enum_models::{
-    EntityLot, ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic,
-    ExerciseMuscle, MediaLot, MediaSource,
+    CollectionCollaboratorRole, EntityLot, ExerciseEquipment, ExerciseForce, ExerciseLevel,
+    ExerciseLot, ExerciseMechanic, ExerciseMuscle, MediaLot, MediaSource,
 };
 use schematic::Schematic;
-use sea_orm::{FromJsonQueryResult, FromQueryResult};
+use sea_orm::{FromJsonQueryResult, FromQueryResult, prelude::DateTimeUtc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -20,10 +20,19 @@ pub struct CreateOrUpdateCollectionInput {
     pub update_id: Option<String>,
     pub description: Option<String>,
     pub collaborators: Option<Vec<String>>,
+    /// Users who can see the collection but not change its contents.
+    pub viewers: Option<Vec<String>>,
     pub extra_information: Option<UserToCollectionExtraInformation>,
     pub information_template: Option<Vec<CollectionExtraInformation>>,
 }
 
+#[derive(Debug, InputObject, Clone, Serialize)]
+pub struct CreateCollectionShareLinkInput {
+    pub collection_id: String,
+    pub maximum_uses: Option<i32>,
+    pub expires_on: Option<DateTimeUtc>,
+}
+
 #[derive(Debug, Serialize, Hash, Deserialize, Enum, Clone, PartialEq, Eq, Copy, Default)]
 pub enum CollectionContentsSortBy {
     /// Sort by the rank assigned to the item in the collection. Applicable to all entity types.
@@ -121,6 +130,7 @@ pub struct CollectionContentsFilter {
 #[serde(rename_all = "snake_case")]
 pub struct CollectionItemCollaboratorInformation {
     pub collaborator: StringIdAndNamedObject,
+    pub role: Option<CollectionCollaboratorRole>,
     pub extra_information: Option<UserToCollectionExtraInformation>,
 }
 
@@ -38,6 +38,8 @@ pub struct MetadataProgressUpdateCommonInput {
     pub manual_time_spent: Option<Decimal>,
     pub podcast_episode_number: Option<i32>,
     pub manga_chapter_number: Option<Decimal>,
+    pub board_game_winner: Option<String>,
+    pub board_game_player_count: Option<i32>,
     pub providers_consumed_on: Option<Vec<String>>,
 }
 
@@ -36,6 +36,10 @@ pub struct ImportOrExportMetadataItemSeen {
     pub podcast_episode_number: Option<i32>,
     /// If for a manga, the chapter which was seen.
     pub manga_chapter_number: Option<Decimal>,
+    /// If for a board game, the number of players in the play.
+    pub board_game_player_count: Option<i32>,
+    /// If for a board game, the winner of the play.
+    pub board_game_winner: Option<String>,
     /// The providers this item was consumed on.
     pub providers_consumed_on: Option<Vec<String>>,
 }
@@ -142,6 +146,12 @@ pub struct DeployMalImportInput {
     pub manga_path: Option<String>,
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployKitsuImportInput {
+    /// The username (profile slug) of the Kitsu user whose library should be imported.
+    pub username: String,
+}
+
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct DeployStrongAppImportInput {
     pub data_export_path: Option<String>,
@@ -189,6 +199,7 @@ pub struct DeployImportJobInput {
     pub mal: Option<DeployMalImportInput>,
     pub path: Option<DeployPathImportInput>,
     pub igdb: Option<DeployIgdbImportInput>,
+    pub kitsu: Option<DeployKitsuImportInput>,
     pub trakt: Option<DeployTraktImportInput>,
     pub movary: Option<DeployMovaryImportInput>,
     pub netflix: Option<DeployNetflixImportInput>,
@@ -1,4 +1,5 @@
 use async_graphql::{InputObject, SimpleObject, Union};
+use common_models::{NotificationPlatformQuietHours, NotificationWebhookHeader};
 use enum_models::{
     IntegrationProvider, MediaLot, NotificationPlatformLot, UserNotificationContentDiscriminants,
 };
@@ -54,6 +55,31 @@ pub struct IntegrationExtraSettings {
     pub disable_on_continuous_errors: bool,
 }
 
+/// Maps a document synced by KOReader to a book. Either the ISBN or the metadata
+/// ID needs to be set.
+#[skip_serializing_none]
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    InputObject,
+    Clone,
+    SimpleObject,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    Schematic,
+)]
+#[graphql(input_name = "KoreaderSyncDocumentInput")]
+#[serde(rename_all = "snake_case")]
+pub struct KoreaderSyncDocument {
+    /// The hash KOReader uses to identify the document.
+    pub document: String,
+    pub isbn: Option<String>,
+    pub metadata_id: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(
     Debug,
@@ -108,6 +134,13 @@ pub struct IntegrationProviderSpecifics {
     pub youtube_music_auth_cookie: Option<String>,
 
     pub ryot_browser_extension_disabled_sites: Option<Vec<String>>,
+
+    pub koreader_sync_username: Option<String>,
+    /// Only accepted as input, the password is hashed before the integration is saved.
+    pub koreader_sync_password: Option<String>,
+    #[graphql(skip)]
+    pub koreader_sync_password_hash: Option<String>,
+    pub koreader_sync_documents: Option<Vec<KoreaderSyncDocument>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
@@ -134,6 +167,10 @@ pub struct CreateUserNotificationPlatformInput {
     #[graphql(secret)]
     pub auth_header: Option<String>,
     pub lot: NotificationPlatformLot,
+    /// MiniJinja template for the request body of the webhook platform. Interpolated values
+    /// are inserted as JSON, eg: `{"text": {{ message }}}`.
+    pub body_template: Option<String>,
+    pub headers: Option<Vec<NotificationWebhookHeader>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
@@ -141,4 +178,7 @@ pub struct UpdateUserNotificationPlatformInput {
     pub notification_id: String,
     pub is_disabled: Option<bool>,
     pub configured_events: Option<Vec<UserNotificationContentDiscriminants>>,
+    pub quiet_hours: Option<NotificationPlatformQuietHours>,
+    /// Remove the quiet hours of the platform. Takes precedence over `quiet_hours`.
+    pub clear_quiet_hours: Option<bool>,
 }
@@ -77,6 +77,7 @@ pub struct MovieSpecifics {
 #[graphql(input_name = "PodcastSpecificsInput")]
 pub struct PodcastSpecifics {
     pub total_episodes: usize,
+    pub feed_url: Option<String>,
     pub episodes: Vec<PodcastEpisode>,
 }
 
@@ -335,6 +336,29 @@ pub struct MusicSpecifics {
 #[graphql(input_name = "ComicBookSpecificsInput")]
 pub struct ComicBookSpecifics {
     pub page_count: Option<i32>,
+    /// The number of issues collected in this book, used to track progress on trade paperbacks.
+    pub issue_count: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "BoardGameSpecificsInput")]
+pub struct BoardGameSpecifics {
+    pub min_players: Option<i32>,
+    pub max_players: Option<i32>,
+    pub playing_time: Option<i32>,
+    pub weight: Option<Decimal>,
 }
 
 #[skip_serializing_none]
@@ -11,9 +11,9 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, ComicBookSpecifics, MangaSpecifics,
-    MovieSpecifics, MusicSpecifics, PodcastSpecifics, ShowSpecifics, VideoGameSpecifics,
-    VisualNovelSpecifics,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicBookSpecifics,
+    MangaSpecifics, MovieSpecifics, MusicSpecifics, PodcastSpecifics, ShowSpecifics,
+    VideoGameSpecifics, VisualNovelSpecifics,
 };
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
@@ -128,6 +128,7 @@ pub struct MetadataDetails {
     pub podcast_specifics: Option<PodcastSpecifics>,
     pub audio_book_specifics: Option<AudioBookSpecifics>,
     pub video_game_specifics: Option<VideoGameSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub comic_book_specifics: Option<ComicBookSpecifics>,
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
     pub external_identifiers: Option<MetadataExternalIdentifiers>,
@@ -236,6 +237,7 @@ pub struct CreateCustomMetadataInput {
     pub podcast_specifics: Option<PodcastSpecifics>,
     pub audio_book_specifics: Option<AudioBookSpecifics>,
     pub video_game_specifics: Option<VideoGameSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub comic_book_specifics: Option<ComicBookSpecifics>,
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
 }
@@ -304,6 +306,7 @@ pub struct GraphqlMetadataDetails {
     pub anime_specifics: Option<AnimeSpecifics>,
     pub podcast_specifics: Option<PodcastSpecifics>,
     pub creators: Vec<MetadataCreatorsGroupedByRole>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub comic_book_specifics: Option<ComicBookSpecifics>,
     pub audio_book_specifics: Option<AudioBookSpecifics>,
     pub video_game_specifics: Option<VideoGameSpecifics>,
@@ -32,6 +32,14 @@ pub struct IntegrationTriggerResult {
     pub finished_at: DateTimeUtc,
 }
 
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Clone, SimpleObject, FromJsonQueryResult,
+)]
+pub struct SeenBoardGameExtraInformation {
+    pub player_count: Option<i32>,
+    pub winner: Option<String>,
+}
+
 #[derive(
     Debug, PartialEq, Eq, Serialize, Deserialize, Clone, SimpleObject, FromJsonQueryResult,
 )]
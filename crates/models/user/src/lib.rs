@@ -1,8 +1,12 @@
 use async_graphql::{Enum, InputObject, SimpleObject};
+use common_models::{ExportFormat, ExportItem, NotificationWebhookHeader, SearchInput};
 use educe::Educe;
-use enum_models::{MediaLot, MediaSource, UserLot};
+use enum_models::{MediaLot, MediaSource, UserAuditEventLot, UserLot};
 use fitness_models::{SetRestTimersSettings, UserUnitSystem};
-use sea_orm::{FromJsonQueryResult, Iterable, prelude::DateTimeUtc};
+use sea_orm::{
+    FromJsonQueryResult, Iterable,
+    prelude::{Date, DateTimeUtc},
+};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::EnumString;
@@ -496,6 +500,11 @@ pub enum NotificationPlatformSpecifics {
         chat_id: String,
         bot_token: String,
     },
+    Matrix {
+        room_id: String,
+        base_url: String,
+        access_token: String,
+    },
     PushOver {
         key: String,
         device: Option<String>,
@@ -512,6 +521,34 @@ pub enum NotificationPlatformSpecifics {
         priority: Option<i32>,
         auth_header: Option<String>,
     },
+    Webhook {
+        url: String,
+        secret: Option<String>,
+        body_template: Option<String>,
+        headers: Option<Vec<NotificationWebhookHeader>>,
+    },
+}
+
+#[derive(Eq, Copy, Enum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserExportScheduleFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[skip_serializing_none]
+#[derive(Eq, Clone, Debug, PartialEq, Serialize, Deserialize, InputObject, SimpleObject)]
+#[graphql(input_name = "UserExportScheduleInput")]
+pub struct UserExportSchedule {
+    pub format: ExportFormat,
+    pub notify_on_completion: bool,
+    pub items: Option<Vec<ExportItem>>,
+    /// The number of most recent exports to keep. Older ones are deleted.
+    pub retention_count: Option<usize>,
+    pub frequency: UserExportScheduleFrequency,
+    #[graphql(skip_input)]
+    pub last_triggered_on: Option<Date>,
 }
 
 #[derive(
@@ -529,6 +566,10 @@ pub enum NotificationPlatformSpecifics {
 pub struct UserExtraInformation {
     pub is_onboarding_tour_completed: bool,
     pub scheduled_for_workout_revision: bool,
+    pub export_schedule: Option<UserExportSchedule>,
+    /// The LDAP username this account was provisioned for.
+    #[graphql(skip_input)]
+    pub ldap_username: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -552,3 +593,26 @@ pub struct UpdateUserInput {
     pub admin_access_token: Option<String>,
     pub is_onboarding_tour_completed: Option<bool>,
 }
+
+#[skip_serializing_none]
+#[derive(
+    Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, SimpleObject, FromJsonQueryResult,
+)]
+pub struct UserAuditLogInformation {
+    /// The access link, integration or user that the event is about.
+    pub entity_id: Option<String>,
+    /// The username sent with a login attempt or of a deleted user.
+    pub username: Option<String>,
+    /// Why the login attempt failed.
+    pub reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Default, InputObject)]
+pub struct UserAuditLogsInput {
+    pub search: Option<SearchInput>,
+    pub lot: Option<UserAuditEventLot>,
+    /// Get the events of all users. Only allowed for admins.
+    pub all_users: Option<bool>,
+}
@@ -0,0 +1,390 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page, get_base_http_client};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{MetadataSearchSourceSpecifics, PersonDetails, SearchResults};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    BoardGameSpecifics, CommitMetadataGroupInput, MetadataDetails, MetadataSearchItem,
+    PartialMetadataPerson, PartialMetadataWithoutId, UniqueMediaIdentifier,
+};
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderValue},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, de::DeserializeOwned};
+use traits::MediaProvider;
+
+#[cfg(test)]
+mod tests;
+
+static URL: &str = "https://boardgamegeek.com/xmlapi2";
+static GEEK_ITEMS_URL: &str = "https://api.geekdo.com/api/geekitems";
+
+#[derive(Debug, Clone)]
+pub struct BoardGameGeekService {
+    client: Client,
+}
+
+impl BoardGameGeekService {
+    pub async fn new(config: &config_definition::BoardGameGeekConfig) -> Result<Self> {
+        let client = get_base_http_client(Some(vec![(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", config.access_token))?,
+        )]));
+        Ok(Self { client })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Value {
+    #[serde(rename = "@value")]
+    value: String,
+}
+
+impl Value {
+    fn parse<T: std::str::FromStr>(&self) -> Option<T> {
+        self.value.parse().ok()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Name {
+    #[serde(rename = "@type")]
+    lot: String,
+    #[serde(rename = "@value")]
+    value: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Link {
+    #[serde(rename = "@type")]
+    lot: String,
+    #[serde(rename = "@id")]
+    id: String,
+    #[serde(rename = "@value")]
+    value: String,
+    #[serde(rename = "@inbound")]
+    inbound: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Ratings {
+    averageweight: Option<Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Statistics {
+    ratings: Option<Ratings>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ThingItem {
+    #[serde(rename = "@id")]
+    id: String,
+    image: Option<String>,
+    description: Option<String>,
+    minplayers: Option<Value>,
+    maxplayers: Option<Value>,
+    playingtime: Option<Value>,
+    yearpublished: Option<Value>,
+    statistics: Option<Statistics>,
+    #[serde(default, rename = "name")]
+    names: Vec<Name>,
+    #[serde(default, rename = "link")]
+    links: Vec<Link>,
+}
+
+impl ThingItem {
+    fn title(&self) -> String {
+        self.names
+            .iter()
+            .find(|n| n.lot == "primary")
+            .or_else(|| self.names.first())
+            .map(|n| n.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn links_of(&self, lot: &str) -> impl Iterator<Item = &Link> {
+        self.links.iter().filter(move |l| l.lot == lot)
+    }
+
+    fn to_partial(&self) -> PartialMetadataWithoutId {
+        PartialMetadataWithoutId {
+            title: self.title(),
+            image: self.image.clone(),
+            identifier: self.id.clone(),
+            lot: MediaLot::BoardGame,
+            source: MediaSource::BoardGameGeek,
+            publish_year: self.yearpublished.as_ref().and_then(|y| y.parse()),
+        }
+    }
+
+    fn into_details(self) -> MetadataDetails {
+        let title = self.title();
+
+        let people = self
+            .links_of("boardgamedesigner")
+            .map(|l| PartialMetadataPerson {
+                name: l.value.clone(),
+                identifier: l.id.clone(),
+                role: "Designer".to_owned(),
+                source: MediaSource::BoardGameGeek,
+                ..Default::default()
+            })
+            .collect_vec();
+
+        let genres = self
+            .links_of("boardgamecategory")
+            .map(|l| l.value.clone())
+            .collect_vec();
+
+        // Expansions point back to their base game with an inbound link, so the base game
+        // acts as the group which contains itself and all of its expansions.
+        let base_game = self
+            .links_of("boardgameexpansion")
+            .find(|l| l.inbound.as_deref() == Some("true"));
+        let expansions = self
+            .links_of("boardgameexpansion")
+            .filter(|l| l.inbound.is_none())
+            .collect_vec();
+        let group = match (base_game, expansions.is_empty()) {
+            (Some(base), _) => Some((base.id.clone(), base.value.clone())),
+            (None, false) => Some((self.id.clone(), title.clone())),
+            (None, true) => None,
+        };
+        let groups = group
+            .map(|(identifier, name)| CommitMetadataGroupInput {
+                name,
+                unique: UniqueMediaIdentifier {
+                    identifier,
+                    lot: MediaLot::BoardGame,
+                    source: MediaSource::BoardGameGeek,
+                },
+                ..Default::default()
+            })
+            .into_iter()
+            .collect_vec();
+
+        let weight = self
+            .statistics
+            .as_ref()
+            .and_then(|s| s.ratings.as_ref())
+            .and_then(|r| r.averageweight.as_ref())
+            .and_then(|w| w.parse::<Decimal>())
+            .filter(|w| !w.is_zero())
+            .map(|w| w.round_dp(2));
+
+        MetadataDetails {
+            title,
+            people,
+            genres,
+            groups,
+            description: self.description,
+            publish_year: self.yearpublished.as_ref().and_then(|y| y.parse()),
+            source_url: Some(format!("https://boardgamegeek.com/boardgame/{}", self.id)),
+            assets: EntityAssets {
+                remote_images: self.image.into_iter().collect(),
+                ..Default::default()
+            },
+            board_game_specifics: Some(BoardGameSpecifics {
+                weight,
+                min_players: positive(self.minplayers.and_then(|v| v.parse())),
+                max_players: positive(self.maxplayers.and_then(|v| v.parse())),
+                playing_time: positive(self.playingtime.and_then(|v| v.parse())),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ThingResponse {
+    #[serde(default, rename = "item")]
+    items: Vec<ThingItem>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SearchItem {
+    #[serde(rename = "@id")]
+    id: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct SearchResponse {
+    #[serde(default, rename = "item")]
+    items: Vec<SearchItem>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GeekItem {
+    name: String,
+    imageurl: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GeekItemResponse {
+    item: GeekItem,
+}
+
+fn positive(value: Option<i32>) -> Option<i32> {
+    value.filter(|v| *v > 0)
+}
+
+fn parse_xml<T: DeserializeOwned>(text: &str) -> Result<T> {
+    Ok(serde_xml_rs::from_str(text)?)
+}
+
+impl BoardGameGeekService {
+    async fn fetch_xml<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        let text = self
+            .client
+            .get(format!("{URL}/{path}"))
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_xml(&text)
+    }
+
+    async fn things(&self, ids: &[String], stats: bool) -> Result<Vec<ThingItem>> {
+        let mut items = vec![];
+        // The API does not allow more than 20 ids in a single request.
+        for chunk in ids.chunks(20) {
+            let ids = chunk.join(",");
+            let stats = if stats { "1" } else { "0" };
+            let data: ThingResponse = self
+                .fetch_xml("thing", &[("id", &ids), ("stats", stats)])
+                .await?;
+            items.extend(data.items);
+        }
+        Ok(items)
+    }
+
+    async fn thing(&self, identifier: &str) -> Result<ThingItem> {
+        self.things(&[identifier.to_owned()], true)
+            .await?
+            .pop()
+            .ok_or_else(|| anyhow!("Board game with id {identifier} not found"))
+    }
+
+    async fn partials(&self, ids: &[String]) -> Result<Vec<PartialMetadataWithoutId>> {
+        let items = self.things(ids, false).await?;
+        Ok(items.iter().map(|i| i.to_partial()).collect())
+    }
+}
+
+#[async_trait]
+impl MediaProvider for BoardGameGeekService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let data: SearchResponse = self
+            .fetch_xml("search", &[("query", query), ("type", "boardgame")])
+            .await?;
+        let total_items = data.items.len() as u64;
+        let ids = data
+            .items
+            .into_iter()
+            .map(|i| i.id)
+            .unique()
+            .skip(((page - 1) * PAGE_SIZE) as usize)
+            .take(PAGE_SIZE as usize)
+            .collect_vec();
+        let items = self
+            .partials(&ids)
+            .await?
+            .into_iter()
+            .map(|p| MetadataSearchItem {
+                image: p.image,
+                title: p.title,
+                identifier: p.identifier,
+                publish_year: p.publish_year,
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let data = self.thing(identifier).await?;
+        Ok(data.into_details())
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let data: GeekItemResponse = self
+            .client
+            .get(GEEK_ITEMS_URL)
+            .query(&[("objectid", identifier), ("objecttype", "person")])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(PersonDetails {
+            name: data.item.name,
+            description: data.item.description,
+            source_url: Some(format!(
+                "https://boardgamegeek.com/boardgamedesigner/{identifier}"
+            )),
+            assets: EntityAssets {
+                remote_images: data.item.imageurl.into_iter().collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let data = self.thing(identifier).await?;
+        let ids = std::iter::once(data.id.clone())
+            .chain(
+                data.links_of("boardgameexpansion")
+                    .filter(|l| l.inbound.is_none())
+                    .map(|l| l.id.clone()),
+            )
+            .collect_vec();
+        let members = self.partials(&ids).await?;
+        let group = MetadataGroupWithoutId {
+            title: data.title(),
+            lot: MediaLot::BoardGame,
+            parts: members.len() as i32,
+            description: data.description,
+            identifier: data.id.clone(),
+            source: MediaSource::BoardGameGeek,
+            source_url: Some(format!("https://boardgamegeek.com/boardgame/{identifier}")),
+            assets: EntityAssets {
+                remote_images: data.image.into_iter().collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Ok((group, members))
+    }
+}
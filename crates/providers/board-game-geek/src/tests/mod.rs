@@ -0,0 +1,67 @@
+use rstest::rstest;
+
+use super::*;
+
+fn parse_thing(fixture: &str) -> ThingItem {
+    let mut response: ThingResponse = parse_xml(fixture).unwrap();
+    response.items.remove(0)
+}
+
+#[test]
+fn test_base_game_details() {
+    let details = parse_thing(include_str!("fixtures/base-game.xml")).into_details();
+    assert_eq!(details.title, "CATAN");
+    assert_eq!(details.publish_year, Some(1995));
+    assert_eq!(details.genres, vec!["Economic", "Negotiation"]);
+    assert_eq!(
+        details.source_url.as_deref(),
+        Some("https://boardgamegeek.com/boardgame/13")
+    );
+    assert_eq!(
+        details.assets.remote_images,
+        vec!["https://cf.geekdo-images.com/catan.jpg"]
+    );
+
+    let people = details
+        .people
+        .iter()
+        .map(|p| (p.identifier.as_str(), p.name.as_str(), p.role.as_str()))
+        .collect_vec();
+    assert_eq!(people, vec![("11", "Klaus Teuber", "Designer")]);
+
+    let specifics = details.board_game_specifics.unwrap();
+    assert_eq!(specifics.min_players, Some(3));
+    assert_eq!(specifics.max_players, Some(4));
+    assert_eq!(specifics.playing_time, Some(120));
+    assert_eq!(specifics.weight, Some(Decimal::new(229, 2)));
+}
+
+#[test]
+fn test_expansion_details() {
+    let details = parse_thing(include_str!("fixtures/expansion.xml")).into_details();
+    let specifics = details.board_game_specifics.unwrap();
+    assert_eq!(specifics.playing_time, None);
+    assert_eq!(specifics.weight, None);
+    assert!(details.genres.is_empty());
+}
+
+#[rstest]
+#[case(include_str!("fixtures/base-game.xml"), Some(("13", "CATAN")))]
+#[case(include_str!("fixtures/expansion.xml"), Some(("13", "CATAN")))]
+#[case(include_str!("fixtures/search.xml"), None)]
+fn test_group_detection(#[case] fixture: &str, #[case] expected: Option<(&str, &str)>) {
+    let details = parse_thing(fixture).into_details();
+    let groups = details
+        .groups
+        .iter()
+        .map(|g| (g.unique.identifier.as_str(), g.name.as_str()))
+        .collect_vec();
+    assert_eq!(groups, expected.into_iter().collect_vec());
+}
+
+#[test]
+fn test_search_response() {
+    let response: SearchResponse = parse_xml(include_str!("fixtures/search.xml")).unwrap();
+    let ids = response.items.into_iter().map(|i| i.id).collect_vec();
+    assert_eq!(ids, vec!["13", "27710"]);
+}
@@ -0,0 +1,448 @@
+use std::sync::OnceLock;
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page, get_base_http_client};
+use convert_case::{Case, Casing};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{MetadataSearchSourceSpecifics, PersonDetails, SearchResults};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    ComicBookSpecifics, CommitMetadataGroupInput, MetadataDetails, MetadataGroupSearchItem,
+    MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
+    UniqueMediaIdentifier,
+};
+use regex::Regex;
+use reqwest::Client;
+use serde::{Deserialize, de::DeserializeOwned};
+use traits::MediaProvider;
+
+#[cfg(test)]
+mod tests;
+
+static URL: &str = "https://comicvine.gamespot.com/api";
+static ISSUE_PREFIX: &str = "4000";
+static PERSON_PREFIX: &str = "4040";
+static VOLUME_PREFIX: &str = "4050";
+static ISSUE_RANGE_RE: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct ComicVineService {
+    client: Client,
+    api_key: String,
+}
+
+impl ComicVineService {
+    pub async fn new(config: &config_definition::ComicVineConfig) -> Result<Self> {
+        let client = get_base_http_client(None);
+        Ok(Self {
+            client,
+            api_key: config.api_key.clone(),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Response<T> {
+    error: String,
+    status_code: i32,
+    results: Option<T>,
+    number_of_total_results: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct Image {
+    super_url: Option<String>,
+    original_url: Option<String>,
+}
+
+impl Image {
+    fn best(self) -> Option<String> {
+        self.super_url.or(self.original_url)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct NamedResource {
+    id: i64,
+    name: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct PersonCredit {
+    id: i64,
+    name: String,
+    role: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Issue {
+    id: i64,
+    deck: Option<String>,
+    name: Option<String>,
+    image: Option<Image>,
+    cover_date: Option<String>,
+    description: Option<String>,
+    issue_number: Option<String>,
+    volume: Option<NamedResource>,
+    site_detail_url: Option<String>,
+    person_credits: Option<Vec<PersonCredit>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Volume {
+    id: i64,
+    name: String,
+    deck: Option<String>,
+    image: Option<Image>,
+    description: Option<String>,
+    count_of_issues: Option<usize>,
+    site_detail_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Person {
+    id: i64,
+    name: String,
+    deck: Option<String>,
+    image: Option<Image>,
+    gender: Option<i32>,
+    website: Option<String>,
+    hometown: Option<String>,
+    description: Option<String>,
+    site_detail_url: Option<String>,
+    birth: Option<serde_json::Value>,
+    death: Option<serde_json::Value>,
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Dates of people are returned either as strings or as objects with a `date` key.
+fn parse_person_date(value: Option<serde_json::Value>) -> Option<NaiveDate> {
+    match value? {
+        serde_json::Value::String(s) => parse_date(&s),
+        serde_json::Value::Object(o) => o.get("date")?.as_str().and_then(parse_date),
+        _ => None,
+    }
+}
+
+fn issue_title(issue: &Issue) -> String {
+    let volume_name = issue
+        .volume
+        .as_ref()
+        .and_then(|v| v.name.clone())
+        .or_else(|| issue.name.clone())
+        .unwrap_or_default();
+    match &issue.issue_number {
+        None => volume_name,
+        Some(n) => format!("{} #{}", volume_name, n),
+    }
+}
+
+/// Trade paperbacks mention the issues they collect in their description, for example
+/// "Collects Batman #1-7 and Detective Comics #27" or "Collects issues #1–#12".
+fn collected_issue_count(description: &str) -> Option<i32> {
+    let lower = description.to_lowercase();
+    let start = lower.find("collect")?;
+    let sentence = lower[start..].split(['.', '<']).next()?;
+    let range_re =
+        ISSUE_RANGE_RE.get_or_init(|| Regex::new(r"#(\d+)(?:\s*[-–—]\s*#?(\d+))?").unwrap());
+    let count = range_re
+        .captures_iter(sentence)
+        .filter_map(|caps| {
+            let from = caps.get(1)?.as_str().parse::<i32>().ok()?;
+            let to = caps
+                .get(2)
+                .and_then(|m| m.as_str().parse::<i32>().ok())
+                .unwrap_or(from);
+            (to >= from).then_some(to - from + 1)
+        })
+        .sum::<i32>();
+    (count > 0).then_some(count)
+}
+
+fn issue_to_partial(issue: Issue) -> PartialMetadataWithoutId {
+    PartialMetadataWithoutId {
+        title: issue_title(&issue),
+        lot: MediaLot::ComicBook,
+        source: MediaSource::ComicVine,
+        identifier: issue.id.to_string(),
+        image: issue.image.and_then(|i| i.best()),
+        publish_year: issue
+            .cover_date
+            .and_then(|d| parse_date(&d))
+            .map(|d| d.year()),
+    }
+}
+
+impl ComicVineService {
+    async fn request<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<(T, u64)> {
+        let data: Response<T> = self
+            .client
+            .get(format!("{URL}/{path}/"))
+            .query(&[("api_key", self.api_key.as_str()), ("format", "json")])
+            .query(query)
+            .send()
+            .await?
+            .json()
+            .await?;
+        let Some(results) = data.results.filter(|_| data.status_code == 1) else {
+            bail!("Comic Vine returned an error: {}", data.error);
+        };
+        Ok((results, data.number_of_total_results.unwrap_or_default()))
+    }
+
+    async fn search<T: DeserializeOwned>(
+        &self,
+        resources: &str,
+        query: &str,
+        page: u64,
+    ) -> Result<(Vec<T>, u64)> {
+        self.request(
+            "search",
+            &[
+                ("query", query.to_owned()),
+                ("page", page.to_string()),
+                ("resources", resources.to_owned()),
+                ("limit", PAGE_SIZE.to_string()),
+            ],
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl MediaProvider for ComicVineService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (issues, total_items) = self.search::<Issue>("issue", query, page).await?;
+        let items = issues
+            .into_iter()
+            .map(issue_to_partial)
+            .map(|i| MetadataSearchItem {
+                image: i.image,
+                title: i.title,
+                identifier: i.identifier,
+                publish_year: i.publish_year,
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let (data, _) = self
+            .request::<Issue>(&format!("issue/{ISSUE_PREFIX}-{identifier}"), &[])
+            .await?;
+        let title = issue_title(&data);
+
+        let people = data
+            .person_credits
+            .unwrap_or_default()
+            .into_iter()
+            .map(|credit| PartialMetadataPerson {
+                name: credit.name,
+                source: MediaSource::ComicVine,
+                identifier: credit.id.to_string(),
+                role: credit
+                    .role
+                    .map(|r| r.to_case(Case::Title))
+                    .unwrap_or_else(|| "Creator".to_owned()),
+                ..Default::default()
+            })
+            .collect_vec();
+
+        let groups = data
+            .volume
+            .map(|volume| CommitMetadataGroupInput {
+                name: volume.name.unwrap_or_default(),
+                unique: UniqueMediaIdentifier {
+                    lot: MediaLot::ComicBook,
+                    source: MediaSource::ComicVine,
+                    identifier: volume.id.to_string(),
+                },
+                ..Default::default()
+            })
+            .into_iter()
+            .collect_vec();
+
+        let publish_date = data.cover_date.as_ref().and_then(|d| parse_date(d));
+        let mut issue_count = data.description.as_deref().and_then(collected_issue_count);
+        if issue_count.is_none()
+            && let Some(volume) = &data.volume
+        {
+            issue_count = self
+                .request::<Volume>(&format!("volume/{VOLUME_PREFIX}-{}", volume.id), &[])
+                .await
+                .ok()
+                .and_then(|(v, _)| v.count_of_issues)
+                .map(|c| c as i32);
+        }
+
+        Ok(MetadataDetails {
+            title,
+            people,
+            groups,
+            publish_date,
+            source_url: data.site_detail_url,
+            description: data.description.or(data.deck),
+            publish_year: publish_date.map(|d| d.year()),
+            assets: EntityAssets {
+                remote_images: data.image.and_then(|i| i.best()).into_iter().collect(),
+                ..Default::default()
+            },
+            comic_book_specifics: Some(ComicBookSpecifics {
+                issue_count,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn people_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        let (people, total_items) = self.search::<Person>("person", query, page).await?;
+        let items = people
+            .into_iter()
+            .map(|p| PeopleSearchItem {
+                name: p.name,
+                identifier: p.id.to_string(),
+                image: p.image.and_then(|i| i.best()),
+                birth_year: parse_person_date(p.birth).map(|d| d.year()),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let (data, _) = self
+            .request::<Person>(&format!("person/{PERSON_PREFIX}-{identifier}"), &[])
+            .await?;
+        let gender = data.gender.and_then(|g| match g {
+            1 => Some("Male".to_owned()),
+            2 => Some("Female".to_owned()),
+            _ => None,
+        });
+        Ok(PersonDetails {
+            gender,
+            name: data.name,
+            place: data.hometown,
+            website: data.website,
+            source_url: data.site_detail_url,
+            description: data.description.or(data.deck),
+            birth_date: parse_person_date(data.birth),
+            death_date: parse_person_date(data.death),
+            assets: EntityAssets {
+                remote_images: data.image.and_then(|i| i.best()).into_iter().collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataGroupSearchItem>> {
+        let (volumes, total_items) = self.search::<Volume>("volume", query, page).await?;
+        let items = volumes
+            .into_iter()
+            .map(|v| MetadataGroupSearchItem {
+                name: v.name,
+                parts: v.count_of_issues,
+                identifier: v.id.to_string(),
+                image: v.image.and_then(|i| i.best()),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let (volume, _) = self
+            .request::<Volume>(&format!("volume/{VOLUME_PREFIX}-{identifier}"), &[])
+            .await?;
+
+        let page_size = 100;
+        let mut members = vec![];
+        loop {
+            let (issues, total) = self
+                .request::<Vec<Issue>>(
+                    "issues",
+                    &[
+                        ("filter", format!("volume:{identifier}")),
+                        ("sort", "cover_date:asc".to_owned()),
+                        ("limit", page_size.to_string()),
+                        ("offset", members.len().to_string()),
+                    ],
+                )
+                .await?;
+            let fetched = issues.len();
+            members.extend(issues.into_iter().map(issue_to_partial));
+            if fetched < page_size || members.len() as u64 >= total {
+                break;
+            }
+        }
+
+        let group = MetadataGroupWithoutId {
+            title: volume.name,
+            lot: MediaLot::ComicBook,
+            source: MediaSource::ComicVine,
+            identifier: volume.id.to_string(),
+            source_url: volume.site_detail_url,
+            description: volume.description.or(volume.deck),
+            parts: volume.count_of_issues.unwrap_or(members.len()) as i32,
+            assets: EntityAssets {
+                remote_images: volume.image.and_then(|i| i.best()).into_iter().collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok((group, members))
+    }
+}
@@ -0,0 +1,14 @@
+use rstest::rstest;
+
+use super::*;
+
+#[rstest]
+#[case("Collects issues #1-6.", Some(6))]
+#[case("Collects issues #1–#12 of the series.", Some(12))]
+#[case("Collects Batman #1-7 and Detective Comics #27", Some(8))]
+#[case("<p>Collects Saga #54.</p>", Some(1))]
+#[case("The first appearance of Spawn in issue #1.", None)]
+#[case("Collects the complete first season.", None)]
+fn test_collected_issue_count(#[case] description: &str, #[case] expected: Option<i32>) {
+    assert_eq!(collected_issue_count(description), expected);
+}
@@ -51,6 +51,37 @@ impl ITunesService {
     pub fn get_default_language(&self) -> String {
         "en_us".to_owned()
     }
+
+    /// The API can not be queried by feed URL, so the podcasts matching the title are
+    /// searched and the one with the same feed is picked.
+    pub async fn find_by_feed_url(&self, title: &str, feed_url: &str) -> Result<Option<String>> {
+        let rsp = self
+            .client
+            .get(format!("{URL}/search"))
+            .query(&[
+                ("term", title),
+                ("media", "podcast"),
+                ("entity", "podcast"),
+                ("lang", &self.get_default_language()),
+            ])
+            .send()
+            .await?;
+        let search: SearchResponse = rsp.json().await?;
+        let feed_url = normalize_feed_url(feed_url);
+        Ok(search
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .find(|i| i.feed_url.as_deref().map(normalize_feed_url) == Some(feed_url.clone()))
+            .map(|i| i.collection_id.to_string()))
+    }
+}
+
+fn normalize_feed_url(url: &str) -> String {
+    let url = url.trim().trim_end_matches('/');
+    url.split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .to_lowercase()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -69,6 +100,7 @@ struct ITunesItem {
     genres: Option<Vec<Genre>>,
     track_count: Option<usize>,
     track_name: Option<String>,
+    feed_url: Option<String>,
     description: Option<String>,
     artist_name: Option<String>,
     track_time_millis: Option<i32>,
@@ -125,6 +157,7 @@ impl MediaProvider for ITunesService {
             })
             .collect();
         let total_episodes = ht.track_count.unwrap();
+        let feed_url = ht.feed_url.clone();
         let details = get_search_response(ht);
         let rsp = self
             .client
@@ -224,6 +257,7 @@ impl MediaProvider for ITunesService {
                 details.title, identifier
             )),
             podcast_specifics: Some(PodcastSpecifics {
+                feed_url,
                 total_episodes: episodes.len(),
                 episodes,
             }),
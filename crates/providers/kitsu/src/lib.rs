@@ -0,0 +1,449 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{
+    PAGE_SIZE, compute_next_page, convert_date_to_year, convert_string_to_date,
+    get_base_http_client,
+};
+use convert_case::{Case, Casing};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataPersonRelated, MetadataSearchSourceSpecifics, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    AnimeSpecifics, CommitMetadataGroupInput, MangaSpecifics, MetadataDetails, MetadataSearchItem,
+    PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
+};
+use reqwest::{
+    Client,
+    header::{ACCEPT, CONTENT_TYPE, HeaderValue},
+};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use traits::MediaProvider;
+
+mod models;
+
+use models::{Document, JSON_API_CONTENT_TYPE, Resource, URL};
+
+#[derive(Debug, Clone)]
+pub struct KitsuService {
+    client: Client,
+}
+
+impl KitsuService {
+    pub async fn new() -> Result<Self> {
+        let content_type = HeaderValue::from_static(JSON_API_CONTENT_TYPE);
+        let client = get_base_http_client(Some(vec![
+            (ACCEPT, content_type.clone()),
+            (CONTENT_TYPE, content_type),
+        ]));
+        Ok(Self { client })
+    }
+
+    async fn document<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<Document<T>> {
+        let document = self
+            .client
+            .get(format!("{URL}/{path}"))
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(document)
+    }
+}
+
+fn page_query(page: u64) -> [(&'static str, String); 2] {
+    [
+        ("page[limit]", PAGE_SIZE.to_string()),
+        (
+            "page[offset]",
+            (page.saturating_sub(1) * PAGE_SIZE).to_string(),
+        ),
+    ]
+}
+
+fn media_title(resource: &Resource) -> String {
+    let attributes = &resource.attributes;
+    attributes
+        .canonical_title
+        .clone()
+        .or_else(|| attributes.title.clone())
+        .unwrap_or_default()
+}
+
+fn media_image(resource: &Resource) -> Option<String> {
+    resource
+        .attributes
+        .poster_image
+        .as_ref()
+        .and_then(|i| i.best())
+}
+
+fn media_publish_year(resource: &Resource) -> Option<i32> {
+    resource
+        .attributes
+        .start_date
+        .as_ref()
+        .and_then(|d| convert_date_to_year(d))
+}
+
+fn to_partial_metadata(resource: &Resource) -> Option<PartialMetadataWithoutId> {
+    Some(PartialMetadataWithoutId {
+        lot: resource.media_lot()?,
+        title: media_title(resource),
+        image: media_image(resource),
+        source: MediaSource::Kitsu,
+        identifier: resource.id.clone(),
+        publish_year: media_publish_year(resource),
+    })
+}
+
+async fn search(
+    service: &KitsuService,
+    media_type: &str,
+    query: &str,
+    page: u64,
+) -> Result<SearchResults<MetadataSearchItem>> {
+    let mut params = vec![("filter[text]", query.to_owned())];
+    params.extend(page_query(page));
+    let search = service
+        .document::<Vec<Resource>>(media_type, &params)
+        .await?;
+    let total_items = search.meta.count.unwrap_or_default();
+    let items = search
+        .data
+        .iter()
+        .map(|d| MetadataSearchItem {
+            title: media_title(d),
+            image: media_image(d),
+            identifier: d.id.clone(),
+            publish_year: media_publish_year(d),
+        })
+        .collect();
+    Ok(SearchResults {
+        items,
+        details: SearchDetails {
+            total_items,
+            next_page: compute_next_page(page, total_items),
+        },
+    })
+}
+
+async fn details(service: &KitsuService, media_type: &str, id: &str) -> Result<MetadataDetails> {
+    let include = "categories,staff.person,installments.franchise,mediaRelationships.destination";
+    let document = service
+        .document::<Resource>(
+            &format!("{media_type}/{id}"),
+            &[("include", include.to_owned())],
+        )
+        .await?;
+    let data = &document.data;
+    let lot = data
+        .media_lot()
+        .ok_or_else(|| anyhow!("Unsupported Kitsu media type {}", data.lot))?;
+
+    let genres = data
+        .related("categories")
+        .into_iter()
+        .filter_map(|c| document.find_included(c))
+        .filter_map(|c| c.attributes.title.clone())
+        .collect_vec();
+
+    let people = data
+        .related("staff")
+        .into_iter()
+        .filter_map(|s| document.find_included(s))
+        .filter_map(|staff| {
+            let person = staff
+                .related("person")
+                .into_iter()
+                .find_map(|p| document.find_included(p))?;
+            Some(PartialMetadataPerson {
+                source: MediaSource::Kitsu,
+                identifier: person.id.clone(),
+                name: person.attributes.name.clone().unwrap_or_default(),
+                role: staff
+                    .attributes
+                    .role
+                    .clone()
+                    .unwrap_or_else(|| "Staff".to_owned()),
+                ..Default::default()
+            })
+        })
+        .collect_vec();
+
+    let groups = data
+        .related("installments")
+        .into_iter()
+        .filter_map(|i| document.find_included(i))
+        .flat_map(|installment| installment.related("franchise"))
+        .filter_map(|f| document.find_included(f))
+        .unique_by(|franchise| franchise.id.clone())
+        .map(|franchise| CommitMetadataGroupInput {
+            name: media_title(franchise),
+            unique: UniqueMediaIdentifier {
+                lot,
+                source: MediaSource::Kitsu,
+                identifier: franchise.id.clone(),
+            },
+            ..Default::default()
+        })
+        .collect_vec();
+
+    let suggestions = data
+        .related("mediaRelationships")
+        .into_iter()
+        .filter_map(|r| document.find_included(r))
+        .flat_map(|relationship| relationship.related("destination"))
+        .filter_map(|d| document.find_included(d))
+        .filter_map(to_partial_metadata)
+        .unique_by(|m| (m.lot, m.identifier.clone()))
+        .collect_vec();
+
+    let attributes = &data.attributes;
+    let (anime_specifics, manga_specifics) = match lot {
+        MediaLot::Anime => (
+            Some(AnimeSpecifics {
+                episodes: attributes.episode_count,
+                ..Default::default()
+            }),
+            None,
+        ),
+        _ => (
+            None,
+            Some(MangaSpecifics {
+                volumes: attributes.volume_count,
+                chapters: attributes.chapter_count.map(Decimal::from),
+                ..Default::default()
+            }),
+        ),
+    };
+
+    Ok(MetadataDetails {
+        genres,
+        groups,
+        people,
+        suggestions,
+        anime_specifics,
+        manga_specifics,
+        title: media_title(data),
+        is_nsfw: attributes.nsfw,
+        publish_year: media_publish_year(data),
+        description: attributes
+            .synopsis
+            .clone()
+            .or_else(|| attributes.description.clone()),
+        provider_rating: attributes
+            .average_rating
+            .as_ref()
+            .and_then(|r| r.parse().ok()),
+        publish_date: attributes
+            .start_date
+            .as_ref()
+            .and_then(|d| convert_string_to_date(d)),
+        production_status: attributes.status.as_ref().map(|s| s.to_case(Case::Title)),
+        source_url: Some(format!("https://kitsu.app/{media_type}/{id}")),
+        assets: EntityAssets {
+            remote_images: media_image(data).into_iter().collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+async fn franchise_details(
+    service: &KitsuService,
+    lot: MediaLot,
+    id: &str,
+) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+    let document = service
+        .document::<Resource>(
+            &format!("franchises/{id}"),
+            &[("include", "installments.media".to_owned())],
+        )
+        .await?;
+    let data = &document.data;
+    let items = data
+        .related("installments")
+        .into_iter()
+        .filter_map(|i| document.find_included(i))
+        .flat_map(|installment| installment.related("media"))
+        .filter_map(|m| document.find_included(m))
+        .filter_map(to_partial_metadata)
+        .filter(|m| m.lot == lot)
+        .collect_vec();
+    let group = MetadataGroupWithoutId {
+        lot,
+        parts: items.len() as i32,
+        title: media_title(data),
+        identifier: data.id.clone(),
+        source: MediaSource::Kitsu,
+        ..Default::default()
+    };
+    Ok((group, items))
+}
+
+#[derive(Debug, Clone)]
+pub struct KitsuAnimeService(KitsuService);
+
+impl KitsuAnimeService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(KitsuService::new().await?))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for KitsuAnimeService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        search(&self.0, "anime", query, page).await
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        details(&self.0, "anime", identifier).await
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        franchise_details(&self.0, MediaLot::Anime, identifier).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KitsuMangaService(KitsuService);
+
+impl KitsuMangaService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(KitsuService::new().await?))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for KitsuMangaService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        search(&self.0, "manga", query, page).await
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        details(&self.0, "manga", identifier).await
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        franchise_details(&self.0, MediaLot::Manga, identifier).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NonMediaKitsuService(KitsuService);
+
+impl NonMediaKitsuService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(KitsuService::new().await?))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for NonMediaKitsuService {
+    async fn people_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        let mut params = vec![("filter[name]", query.to_owned())];
+        params.extend(page_query(page));
+        let search = self.0.document::<Vec<Resource>>("people", &params).await?;
+        let total_items = search.meta.count.unwrap_or_default();
+        let items = search
+            .data
+            .into_iter()
+            .map(|p| PeopleSearchItem {
+                identifier: p.id,
+                name: p.attributes.name.unwrap_or_default(),
+                image: p.attributes.image.and_then(|i| i.best()),
+                ..Default::default()
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let document = self
+            .0
+            .document::<Resource>(
+                &format!("people/{identifier}"),
+                &[("include", "staff.media".to_owned())],
+            )
+            .await?;
+        let data = &document.data;
+        let related_metadata = data
+            .related("staff")
+            .into_iter()
+            .filter_map(|s| document.find_included(s))
+            .flat_map(|staff| {
+                staff
+                    .related("media")
+                    .into_iter()
+                    .filter_map(|m| document.find_included(m))
+                    .filter_map(to_partial_metadata)
+                    .map(|metadata| MetadataPersonRelated {
+                        metadata,
+                        role: staff
+                            .attributes
+                            .role
+                            .clone()
+                            .unwrap_or_else(|| "Staff".to_owned()),
+                        ..Default::default()
+                    })
+            })
+            .collect_vec();
+        let attributes = &document.data.attributes;
+        Ok(PersonDetails {
+            related_metadata,
+            name: attributes.name.clone().unwrap_or_default(),
+            description: attributes.description.clone(),
+            source_url: Some(format!("https://kitsu.app/people/{identifier}")),
+            assets: EntityAssets {
+                remote_images: attributes.image.iter().filter_map(|i| i.best()).collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+}
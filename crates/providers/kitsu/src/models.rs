@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use enum_models::MediaLot;
+use serde::Deserialize;
+
+pub static URL: &str = "https://kitsu.app/api/edge";
+pub static JSON_API_CONTENT_TYPE: &str = "application/vnd.api+json";
+
+#[derive(Debug, Deserialize)]
+pub struct ResourceIdentifier {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub lot: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RelationshipData {
+    One(ResourceIdentifier),
+    Many(Vec<ResourceIdentifier>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Relationship {
+    pub data: Option<RelationshipData>,
+}
+
+impl Relationship {
+    pub fn identifiers(&self) -> Vec<&ResourceIdentifier> {
+        match &self.data {
+            None => vec![],
+            Some(RelationshipData::One(one)) => vec![one],
+            Some(RelationshipData::Many(many)) => many.iter().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Image {
+    pub large: Option<String>,
+    pub original: Option<String>,
+}
+
+impl Image {
+    pub fn best(&self) -> Option<String> {
+        self.large.clone().or_else(|| self.original.clone())
+    }
+}
+
+/// The attributes of every resource type that is requested from Kitsu. The API returns
+/// different shapes for media, people, categories and franchises so all of them are optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attributes {
+    pub nsfw: Option<bool>,
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub title: Option<String>,
+    pub image: Option<Image>,
+    pub status: Option<String>,
+    pub synopsis: Option<String>,
+    pub start_date: Option<String>,
+    pub description: Option<String>,
+    pub episode_count: Option<i32>,
+    pub chapter_count: Option<i32>,
+    pub volume_count: Option<i32>,
+    pub poster_image: Option<Image>,
+    pub average_rating: Option<String>,
+    pub canonical_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Resource {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub lot: String,
+    #[serde(default)]
+    pub attributes: Attributes,
+    #[serde(default)]
+    pub relationships: HashMap<String, Relationship>,
+}
+
+impl Resource {
+    pub fn related(&self, name: &str) -> Vec<&ResourceIdentifier> {
+        self.relationships
+            .get(name)
+            .map(|r| r.identifiers())
+            .unwrap_or_default()
+    }
+
+    pub fn media_lot(&self) -> Option<MediaLot> {
+        media_type_to_lot(&self.lot)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Meta {
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Document<T> {
+    pub data: T,
+    #[serde(default)]
+    pub meta: Meta,
+    #[serde(default)]
+    pub included: Vec<Resource>,
+}
+
+impl<T> Document<T> {
+    pub fn find_included(&self, identifier: &ResourceIdentifier) -> Option<&Resource> {
+        self.included
+            .iter()
+            .find(|r| r.id == identifier.id && r.lot == identifier.lot)
+    }
+}
+
+pub fn media_type_to_lot(media_type: &str) -> Option<MediaLot> {
+    match media_type {
+        "anime" => Some(MediaLot::Anime),
+        "manga" => Some(MediaLot::Manga),
+        _ => None,
+    }
+}
@@ -170,6 +170,25 @@ impl MediaProvider for ListennotesService {
 }
 
 impl ListennotesService {
+    pub async fn find_by_feed_url(&self, feed_url: &str) -> Result<Option<String>> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct Podcast {
+            id: String,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct BatchResponse {
+            podcasts: Vec<Podcast>,
+        }
+        let rsp = self
+            .client
+            .post(format!("{}/podcasts", self.url))
+            .form(&[("rsses", feed_url)])
+            .send()
+            .await?;
+        let data: BatchResponse = rsp.json().await?;
+        Ok(data.podcasts.into_iter().next().map(|p| p.id))
+    }
+
     async fn get_genres(&self) -> Result<HashMap<i32, String>> {
         cache_service::get_or_set_with_callback(
             &self.ss,
@@ -207,6 +226,7 @@ impl ListennotesService {
         struct Podcast {
             id: String,
             title: String,
+            rss: Option<String>,
             genre_ids: Vec<i32>,
             image: Option<String>,
             total_episodes: usize,
@@ -260,6 +280,7 @@ impl ListennotesService {
                 ..Default::default()
             },
             podcast_specifics: Some(PodcastSpecifics {
+                feed_url: podcast_data.rss,
                 episodes: podcast_data
                     .episodes
                     .into_iter()
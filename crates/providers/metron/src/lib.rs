@@ -316,6 +316,7 @@ impl MediaProvider for MetronService {
                 .map(|d| d.year()),
             comic_book_specifics: Some(ComicBookSpecifics {
                 page_count: data.page_count,
+                ..Default::default()
             }),
             ..Default::default()
         })
@@ -101,6 +101,21 @@ impl MusicBrainzService {
 
         None
     }
+
+    /// Find the recording that best matches the given artist and title.
+    pub async fn find_recording(&self, artist: &str, title: &str) -> Result<Option<String>> {
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+        let lucene_query = format!(
+            "query=recording:\"{}\" AND artist:\"{}\"",
+            escape(title),
+            escape(artist)
+        );
+        let results = Recording::search(lucene_query)
+            .limit(1)
+            .execute_with_client(&self.client)
+            .await?;
+        Ok(results.entities.into_iter().next().map(|r| r.id))
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Utc};
+use common_models::{EntityAssets, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page, get_base_http_client};
+use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use itertools::Itertools;
+use media_models::{
+    MetadataDetails, MetadataFreeCreator, MetadataSearchItem, PodcastEpisode, PodcastSpecifics,
+};
+use reqwest::{Client, header::AUTHORIZATION};
+use serde::{Deserialize, de::DeserializeOwned};
+use sha1::{Digest, Sha1};
+use traits::MediaProvider;
+
+static URL: &str = "https://api.podcastindex.org/api/1.0";
+
+pub struct PodcastIndexService {
+    client: Client,
+    api_key: String,
+    api_secret: String,
+}
+
+impl PodcastIndexService {
+    pub async fn new(config: &config_definition::PodcastIndexConfig) -> Result<Self> {
+        let client = get_base_http_client(None);
+        Ok(Self {
+            client,
+            api_key: config.api_key.clone(),
+            api_secret: config.api_secret.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Categories {
+    Map(HashMap<String, String>),
+    // The API returns an empty array instead of an empty object
+    List(Vec<String>),
+}
+
+impl Categories {
+    fn names(self) -> Vec<String> {
+        match self {
+            Self::List(list) => list,
+            Self::Map(map) => map.into_values().sorted().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Feed {
+    id: i64,
+    title: String,
+    url: Option<String>,
+    image: Option<String>,
+    author: Option<String>,
+    artwork: Option<String>,
+    explicit: Option<bool>,
+    description: Option<String>,
+    categories: Option<Categories>,
+}
+
+impl Feed {
+    fn image(&self) -> Option<String> {
+        [&self.artwork, &self.image]
+            .into_iter()
+            .flatten()
+            .find(|i| !i.is_empty())
+            .cloned()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Episode {
+    id: i64,
+    title: String,
+    image: Option<String>,
+    duration: Option<i32>,
+    date_published: i64,
+    feed_image: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    feeds: Vec<Feed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedResponse {
+    feed: Feed,
+}
+
+#[derive(Debug, Deserialize)]
+struct EpisodesResponse {
+    items: Vec<Episode>,
+}
+
+impl PodcastIndexService {
+    // Every request has to be signed with the current time, so the authentication
+    // headers can not be set on the client itself.
+    async fn fetch<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T> {
+        let date = Utc::now().timestamp().to_string();
+        let signature = hex::encode(Sha1::digest(format!(
+            "{}{}{}",
+            self.api_key, self.api_secret, date
+        )));
+        let data = self
+            .client
+            .get(format!("{URL}/{path}"))
+            .query(query)
+            .header("X-Auth-Key", &self.api_key)
+            .header("X-Auth-Date", date)
+            .header(AUTHORIZATION, signature)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl MediaProvider for PodcastIndexService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let FeedResponse { feed } = self
+            .fetch("podcasts/byfeedid", &[("id", identifier)])
+            .await?;
+        let data: EpisodesResponse = self
+            .fetch("episodes/byfeedid", &[("id", identifier), ("max", "1000")])
+            .await?;
+
+        // The API does not return episode numbers, so they are assigned from the oldest
+        // episode onwards.
+        let episodes = data
+            .items
+            .into_iter()
+            .filter_map(|e| {
+                let publish_date = DateTime::from_timestamp(e.date_published, 0)?.date_naive();
+                Some((publish_date, e))
+            })
+            .sorted_by_key(|(publish_date, e)| (*publish_date, e.id))
+            .enumerate()
+            .map(|(idx, (publish_date, e))| PodcastEpisode {
+                publish_date,
+                title: e.title,
+                id: e.id.to_string(),
+                number: idx as i32 + 1,
+                overview: e.description,
+                runtime: e.duration.filter(|d| *d > 0).map(|d| d / 60),
+                thumbnail: e.image.filter(|i| !i.is_empty()).or(e.feed_image),
+            })
+            .collect_vec();
+        let publish_date = episodes.first().map(|e| e.publish_date);
+
+        Ok(MetadataDetails {
+            publish_date,
+            title: feed.title.clone(),
+            is_nsfw: feed.explicit,
+            description: feed.description.clone(),
+            publish_year: publish_date.map(|d| d.year()),
+            source_url: Some(format!("https://podcastindex.org/podcast/{identifier}")),
+            creators: Vec::from_iter(feed.author.clone().filter(|a| !a.is_empty()))
+                .into_iter()
+                .map(|name| MetadataFreeCreator {
+                    name,
+                    role: "Publishing".to_owned(),
+                })
+                .collect(),
+            assets: EntityAssets {
+                remote_images: feed.image().into_iter().collect(),
+                ..Default::default()
+            },
+            podcast_specifics: Some(PodcastSpecifics {
+                feed_url: feed.url.clone(),
+                total_episodes: episodes.len(),
+                episodes,
+            }),
+            genres: feed.categories.map(|c| c.names()).unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let data: SearchResponse = self
+            .fetch("search/byterm", &[("q", query), ("max", "100")])
+            .await?;
+        let total_items = data.feeds.len() as u64;
+        let items = data
+            .feeds
+            .into_iter()
+            .skip((page.saturating_sub(1) * PAGE_SIZE) as usize)
+            .take(PAGE_SIZE as usize)
+            .map(|feed| MetadataSearchItem {
+                image: feed.image(),
+                title: feed.title,
+                publish_year: None,
+                identifier: feed.id.to_string(),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, total_items),
+            },
+        })
+    }
+}
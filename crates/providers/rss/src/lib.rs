@@ -0,0 +1,472 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate};
+use common_models::{EntityAssets, SearchDetails};
+use common_utils::{USER_AGENT_STR, ryot_log};
+use database_models::{
+    metadata,
+    prelude::{Metadata, RssFeed},
+    rss_feed,
+};
+use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use enum_models::{MediaLot, MediaSource};
+use futures::TryStreamExt;
+use itertools::Itertools;
+use media_models::{
+    MetadataDetails, MetadataFreeCreator, MetadataSearchItem, PodcastEpisode, PodcastSpecifics,
+};
+use reqwest::{
+    Client, ClientBuilder, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect::Policy,
+};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, sea_query::OnConflict};
+use serde::Deserialize;
+use serde_xml_rs::SerdeXml;
+use sha2::{Digest, Sha256};
+use supporting_service::SupportingService;
+use tokio::io::AsyncReadExt;
+use tokio_util::io::StreamReader;
+use traits::MediaProvider;
+
+#[cfg(test)]
+mod tests;
+
+/// Feeds larger than this are rejected instead of being read into memory.
+const MAX_FEED_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Podcasts that are not listed in any directory (for example private or Patreon feeds)
+/// can be tracked by their RSS feed. Feed URLs often contain private tokens, so the
+/// identifier is a hash of the URL and the URL itself is only stored in `rss_feed`.
+pub struct RssService {
+    client: Client,
+    ss: Arc<SupportingService>,
+}
+
+impl RssService {
+    pub async fn new(ss: Arc<SupportingService>) -> Result<Self> {
+        let client = ClientBuilder::new()
+            .user_agent(USER_AGENT_STR)
+            .timeout(Duration::from_secs(15))
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            .redirect(Policy::custom(|attempt| {
+                if attempt.previous().len() >= 10 {
+                    attempt.error("Too many redirects")
+                } else if !is_public_url(attempt.url()) {
+                    attempt.error("Redirect to a non-public address")
+                } else {
+                    attempt.follow()
+                }
+            }))
+            .build()?;
+        Ok(Self { ss, client })
+    }
+}
+
+/// Feed URLs are entered by users, so they must not be able to make the server send
+/// requests to itself or to other hosts on its network.
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => {
+            let is_shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_multicast()
+                || ip.is_broadcast()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_documentation()
+                || is_shared)
+        }
+        IpAddr::V6(ip) => {
+            !(ip.is_loopback()
+                || ip.is_multicast()
+                || ip.is_unspecified()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local())
+        }
+    }
+}
+
+/// Hosts that are IP addresses are not resolved, so they have to be checked separately.
+fn is_public_url(url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+        Ok(ip) => is_public_ip(ip),
+        Err(_) => true,
+    }
+}
+
+/// Resolves hostnames like the system resolver, but drops every address that is not
+/// public. It is used for every connection, including the ones made for redirects.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect::<Vec<SocketAddr>>();
+            if addrs.is_empty() {
+                return Err(
+                    format!("{} does not resolve to a public address", name.as_str()).into(),
+                );
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Text {
+    #[serde(rename = "#text")]
+    value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ITunesImage {
+    #[serde(rename = "@href")]
+    href: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ITunesCategory {
+    #[serde(rename = "@text")]
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Image {
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Enclosure {
+    #[serde(rename = "@url")]
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Item {
+    guid: Option<Text>,
+    title: Option<String>,
+    #[serde(rename = "pubDate")]
+    pub_date: Option<String>,
+    enclosure: Option<Enclosure>,
+    description: Option<String>,
+    #[serde(rename = "itunes:title")]
+    itunes_title: Option<String>,
+    #[serde(rename = "itunes:image")]
+    itunes_image: Option<ITunesImage>,
+    #[serde(rename = "itunes:summary")]
+    itunes_summary: Option<String>,
+    #[serde(rename = "itunes:duration")]
+    itunes_duration: Option<String>,
+}
+
+impl Item {
+    /// Feeds are not required to have a guid for their items, so the enclosure is the
+    /// next best thing to tell episodes apart.
+    fn id(&self) -> Option<String> {
+        self.guid
+            .as_ref()
+            .and_then(|g| g.value.clone())
+            .or_else(|| self.enclosure.as_ref().and_then(|e| e.url.clone()))
+            .or_else(|| self.title.clone())
+    }
+
+    fn into_episode(self) -> Option<PodcastEpisode> {
+        let id = self.id()?;
+        Some(PodcastEpisode {
+            id,
+            number: 0,
+            publish_date: parse_date(self.pub_date.as_deref()?)?,
+            title: self.title.or(self.itunes_title)?,
+            overview: self.description.or(self.itunes_summary),
+            thumbnail: self.itunes_image.and_then(|i| i.href),
+            runtime: self
+                .itunes_duration
+                .as_deref()
+                .and_then(parse_duration)
+                .map(|d| d / 60),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Channel {
+    title: String,
+    link: Option<String>,
+    image: Option<Image>,
+    description: Option<String>,
+    #[serde(rename = "itunes:image")]
+    itunes_image: Option<ITunesImage>,
+    #[serde(rename = "itunes:author")]
+    itunes_author: Option<String>,
+    #[serde(rename = "itunes:summary")]
+    itunes_summary: Option<String>,
+    #[serde(rename = "itunes:explicit")]
+    itunes_explicit: Option<String>,
+    #[serde(default, rename = "category")]
+    categories: Vec<String>,
+    #[serde(default, rename = "itunes:category")]
+    itunes_categories: Vec<ITunesCategory>,
+    #[serde(default, rename = "item")]
+    items: Vec<Item>,
+}
+
+impl Channel {
+    fn image(&self) -> Option<String> {
+        self.itunes_image
+            .as_ref()
+            .and_then(|i| i.href.clone())
+            .or_else(|| self.image.as_ref().and_then(|i| i.url.clone()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Rss {
+    channel: Channel,
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    let date = date.trim();
+    DateTime::parse_from_rfc2822(date)
+        .or_else(|_| DateTime::parse_from_rfc3339(date))
+        .ok()
+        .map(|d| d.date_naive())
+}
+
+fn parse_channel(body: &str) -> Result<Channel> {
+    let rss: Rss = SerdeXml::new()
+        .overlapping_sequences(true)
+        .from_str(body)
+        .map_err(|e| anyhow!("Could not parse the RSS feed: {e}"))?;
+    Ok(rss.channel)
+}
+
+/// Durations are either a number of seconds or in the `HH:MM:SS` or `MM:SS` formats.
+fn parse_duration(duration: &str) -> Option<i32> {
+    let seconds = duration.trim().split(':').try_fold(0.0, |acc, part| {
+        part.trim().parse::<f64>().ok().map(|p| acc * 60.0 + p)
+    })?;
+    (seconds > 0.0).then_some(seconds as i32)
+}
+
+/// Feeds are free to drop their older items, so episodes that were seen before keep their
+/// number and those that are no longer in the feed are retained. New episodes are numbered
+/// after the existing ones in the order they were published.
+fn merge_episodes(
+    existing_episodes: Vec<PodcastEpisode>,
+    items: Vec<Item>,
+) -> (Vec<PodcastEpisode>, usize) {
+    let mut episodes: HashMap<String, PodcastEpisode> = existing_episodes
+        .into_iter()
+        .map(|e| (e.id.clone(), e))
+        .collect();
+    let mut max_number = episodes.values().map(|e| e.number).max().unwrap_or(0);
+    let new_episodes = items
+        .into_iter()
+        .filter_map(Item::into_episode)
+        .unique_by(|e| e.id.clone())
+        .sorted_by_key(|e| e.publish_date)
+        .collect_vec();
+    let mut new_count = 0;
+    for episode in new_episodes {
+        match episodes.get_mut(&episode.id) {
+            Some(existing) => {
+                *existing = PodcastEpisode {
+                    number: existing.number,
+                    ..episode
+                }
+            }
+            None => {
+                max_number += 1;
+                new_count += 1;
+                episodes.insert(
+                    episode.id.clone(),
+                    PodcastEpisode {
+                        number: max_number,
+                        ..episode
+                    },
+                );
+            }
+        }
+    }
+    let episodes = episodes
+        .into_values()
+        .sorted_by_key(|e| e.number)
+        .collect_vec();
+    (episodes, new_count)
+}
+
+impl RssService {
+    async fn feed(&self, url: &str) -> Result<Channel> {
+        let url = Url::parse(url)?;
+        if !is_public_url(&url) {
+            bail!("The feed must be hosted on a public address");
+        }
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        if response
+            .content_length()
+            .is_some_and(|length| length > MAX_FEED_SIZE)
+        {
+            bail!("The feed is larger than {} MB", MAX_FEED_SIZE / 1024 / 1024);
+        }
+        let stream = response.bytes_stream().map_err(std::io::Error::other);
+        let mut body = vec![];
+        StreamReader::new(stream)
+            .take(MAX_FEED_SIZE + 1)
+            .read_to_end(&mut body)
+            .await?;
+        if body.len() as u64 > MAX_FEED_SIZE {
+            bail!("The feed is larger than {} MB", MAX_FEED_SIZE / 1024 / 1024);
+        }
+        parse_channel(&String::from_utf8_lossy(&body))
+    }
+
+    /// Remembers the URL of a feed and returns the identifier it can be tracked by.
+    pub async fn feed_identifier(&self, url: &str) -> Result<String> {
+        let id = hex::encode(Sha256::digest(url.as_bytes()));
+        let feed = rss_feed::ActiveModel {
+            id: ActiveValue::Set(id.clone()),
+            url: ActiveValue::Set(url.to_owned()),
+            ..Default::default()
+        };
+        RssFeed::insert(feed)
+            .on_conflict(
+                OnConflict::column(rss_feed::Column::Id)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.ss.db)
+            .await?;
+        Ok(id)
+    }
+
+    async fn feed_url(&self, identifier: &str) -> Result<String> {
+        RssFeed::find_by_id(identifier)
+            .one(&self.ss.db)
+            .await?
+            .map(|f| f.url)
+            .ok_or_else(|| anyhow!("No RSS feed is known by this identifier"))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for RssService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let url = self.feed_url(identifier).await?;
+        let mut channel = self.feed(&url).await?;
+        let items = std::mem::take(&mut channel.items);
+
+        let existing_episodes = Metadata::find()
+            .filter(metadata::Column::Identifier.eq(identifier))
+            .filter(metadata::Column::Lot.eq(MediaLot::Podcast))
+            .filter(metadata::Column::Source.eq(MediaSource::Rss))
+            .one(&self.ss.db)
+            .await?
+            .and_then(|m| m.podcast_specifics)
+            .map(|ps| ps.episodes)
+            .unwrap_or_default();
+
+        let (episodes, new_count) = merge_episodes(existing_episodes, items);
+        if new_count > 0 {
+            ryot_log!(
+                debug,
+                "RSS podcast {}: discovered {} new episode(s)",
+                identifier,
+                new_count
+            );
+        }
+        let publish_date = episodes.iter().map(|e| e.publish_date).min();
+
+        let genres = channel
+            .itunes_categories
+            .iter()
+            .filter_map(|c| c.text.clone())
+            .chain(channel.categories.clone())
+            .map(|c| c.trim().to_owned())
+            .filter(|c| !c.is_empty())
+            .unique()
+            .collect();
+        let is_nsfw = channel
+            .itunes_explicit
+            .as_deref()
+            .map(|e| matches!(e.trim(), "yes" | "true" | "explicit"));
+
+        Ok(MetadataDetails {
+            genres,
+            is_nsfw,
+            publish_date,
+            title: channel.title.clone(),
+            publish_year: publish_date.map(|d| d.year()),
+            description: channel
+                .description
+                .clone()
+                .or(channel.itunes_summary.clone()),
+            source_url: channel.link.clone(),
+            creators: Vec::from_iter(channel.itunes_author.clone())
+                .into_iter()
+                .map(|name| MetadataFreeCreator {
+                    name,
+                    role: "Publishing".to_owned(),
+                })
+                .collect(),
+            assets: EntityAssets {
+                remote_images: channel.image().into_iter().collect(),
+                ..Default::default()
+            },
+            podcast_specifics: Some(PodcastSpecifics {
+                total_episodes: episodes.len(),
+                episodes,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// There is no directory to search, so the query is expected to be the URL of the feed.
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let url = query.trim();
+        if page > 1 || !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Ok(SearchResults::default());
+        }
+        let channel = self.feed(url).await?;
+        let identifier = self.feed_identifier(url).await?;
+        let publish_year = channel
+            .items
+            .iter()
+            .filter_map(|i| i.pub_date.as_deref().and_then(parse_date))
+            .min()
+            .map(|d| d.year());
+        Ok(SearchResults {
+            items: vec![MetadataSearchItem {
+                publish_year,
+                image: channel.image(),
+                identifier,
+                title: channel.title,
+            }],
+            details: SearchDetails {
+                total_items: 1,
+                next_page: None,
+            },
+        })
+    }
+}
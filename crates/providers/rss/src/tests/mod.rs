@@ -0,0 +1,137 @@
+use rstest::rstest;
+
+use super::*;
+
+fn fixture_items() -> Vec<Item> {
+    parse_channel(include_str!("fixtures/feed.xml"))
+        .unwrap()
+        .items
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn numbers(episodes: &[PodcastEpisode]) -> Vec<(i32, &str)> {
+    episodes
+        .iter()
+        .map(|e| (e.number, e.id.as_str()))
+        .collect_vec()
+}
+
+#[rstest]
+#[case("8.8.8.8", true)]
+#[case("100.128.0.1", true)]
+#[case("2001:4860:4860::8888", true)]
+#[case("::ffff:8.8.8.8", true)]
+#[case("10.0.0.1", false)]
+#[case("172.16.0.1", false)]
+#[case("192.168.1.1", false)]
+#[case("127.0.0.1", false)]
+#[case("169.254.169.254", false)]
+#[case("100.64.0.1", false)]
+#[case("192.0.2.1", false)]
+#[case("0.0.0.0", false)]
+#[case("255.255.255.255", false)]
+#[case("224.0.0.1", false)]
+#[case("::1", false)]
+#[case("::", false)]
+#[case("::ffff:127.0.0.1", false)]
+#[case("fc00::1", false)]
+#[case("fe80::1", false)]
+fn test_is_public_ip(#[case] ip: &str, #[case] expected: bool) {
+    assert_eq!(is_public_ip(ip.parse().unwrap()), expected);
+}
+
+#[rstest]
+#[case("https://example.com/feed.xml", true)]
+#[case("http://localhost/feed.xml", true)]
+#[case("http://93.184.216.34/feed.xml", true)]
+#[case("http://127.0.0.1/feed.xml", false)]
+#[case("http://10.0.0.1:8080/feed.xml", false)]
+#[case("http://[::1]/feed.xml", false)]
+#[case("http://[::ffff:192.168.1.1]/feed.xml", false)]
+#[case("file:///etc/passwd", false)]
+fn test_is_public_url(#[case] url: &str, #[case] expected: bool) {
+    assert_eq!(is_public_url(&Url::parse(url).unwrap()), expected);
+}
+
+#[rstest]
+#[case("3600", Some(3600))]
+#[case(" 90 ", Some(90))]
+#[case("12.5", Some(12))]
+#[case("45:30", Some(2730))]
+#[case("1:02:03", Some(3723))]
+#[case("0", None)]
+#[case("00:00:00", None)]
+#[case("", None)]
+#[case("one hour", None)]
+fn test_parse_duration(#[case] duration: &str, #[case] expected: Option<i32>) {
+    assert_eq!(parse_duration(duration), expected);
+}
+
+#[rstest]
+#[case("Mon, 01 Jan 2024 08:00:00 +0000", Some(date(2024, 1, 1)))]
+#[case(" Tue, 02 Jan 2024 23:30:00 -0500 ", Some(date(2024, 1, 2)))]
+#[case("Tue, 02 Jan 2024 23:30:00 GMT", Some(date(2024, 1, 2)))]
+#[case("2024-01-15T08:00:00Z", Some(date(2024, 1, 15)))]
+#[case("2024-01-15", None)]
+#[case("yesterday", None)]
+fn test_parse_date(#[case] value: &str, #[case] expected: Option<NaiveDate>) {
+    assert_eq!(parse_date(value), expected);
+}
+
+#[test]
+fn test_episodes_are_numbered_by_publish_date() {
+    let (episodes, new_count) = merge_episodes(vec![], fixture_items());
+    assert_eq!(new_count, 3);
+    assert_eq!(
+        numbers(&episodes),
+        vec![
+            (1, "ep-1"),
+            (2, "https://cdn.example.com/ep-2.mp3"),
+            (3, "ep-4"),
+        ]
+    );
+
+    let first = &episodes[0];
+    assert_eq!(first.title, "Episode 1");
+    assert_eq!(first.publish_date, date(2024, 1, 1));
+    assert_eq!(first.runtime, Some(62));
+    assert_eq!(first.overview.as_deref(), Some("The very first episode."));
+    assert_eq!(episodes[1].runtime, Some(30));
+    assert_eq!(episodes[2].runtime, Some(45));
+}
+
+#[test]
+fn test_existing_episodes_keep_their_numbers() {
+    let existing = vec![
+        PodcastEpisode {
+            number: 1,
+            id: "ep-0".to_owned(),
+            title: "Episode 0".to_owned(),
+            publish_date: date(2023, 12, 25),
+            ..Default::default()
+        },
+        PodcastEpisode {
+            number: 2,
+            id: "ep-1".to_owned(),
+            title: "Old title".to_owned(),
+            publish_date: date(2024, 1, 1),
+            ..Default::default()
+        },
+    ];
+    let (episodes, new_count) = merge_episodes(existing, fixture_items());
+    assert_eq!(new_count, 2);
+    assert_eq!(
+        numbers(&episodes),
+        vec![
+            (1, "ep-0"),
+            (2, "ep-1"),
+            (3, "https://cdn.example.com/ep-2.mp3"),
+            (4, "ep-4"),
+        ]
+    );
+    assert_eq!(episodes[0].title, "Episode 0");
+    assert_eq!(episodes[1].title, "Episode 1");
+}
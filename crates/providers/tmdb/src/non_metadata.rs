@@ -248,4 +248,28 @@ impl NonMediaTmdbService {
             Err(anyhow!("No results found"))
         }
     }
+
+    pub async fn find_movie_by_title(&self, title: &str, year: Option<i32>) -> Result<String> {
+        let mut query = vec![
+            ("query", title.to_owned()),
+            ("language", self.0.get_default_language()),
+        ];
+        if let Some(year) = year {
+            query.push(("primary_release_year", year.to_string()));
+        }
+        let search: TmdbListResponse = self
+            .0
+            .client
+            .get(format!("{URL}/search/movie"))
+            .query(&query)
+            .send()
+            .await?
+            .json()
+            .await?;
+        search
+            .results
+            .first()
+            .map(|entry| entry.id.to_string())
+            .ok_or_else(|| anyhow!("No results found"))
+    }
 }
@@ -2,17 +2,21 @@ use async_graphql::{Context, Object, Result};
 use collection_service::{
     content_operations, deploy_add_entities_to_collection_job,
     deploy_remove_entities_from_collection_job, management_operations, recommendation_operations,
+    share_link_operations,
 };
 use common_models::{
     ChangeCollectionToEntitiesInput, ReorderCollectionEntityInput, StringIdObject,
 };
+use database_models::collection_share_link;
 use dependent_collection_utils::{create_or_update_collection, reorder_collection_entity};
 use dependent_entity_list_utils::user_collections_list;
 use dependent_models::{
     CachedResponse, CollectionContentsInput, CollectionContentsResponse,
-    CollectionRecommendationsInput, SearchResults, UserCollectionsListResponse,
+    CollectionRecommendationsInput, SearchResults, SharedCollectionContentsInput,
+    SharedCollectionContentsResponse, UserCollectionsListResponse,
 };
-use media_models::CreateOrUpdateCollectionInput;
+use enum_models::UserApiKeyScope;
+use media_models::{CreateCollectionShareLinkInput, CreateOrUpdateCollectionInput};
 use traits::GraphqlDependencyInjector;
 
 #[derive(Default)]
@@ -50,6 +54,26 @@ impl CollectionQueryResolver {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
         Ok(recommendation_operations::collection_recommendations(&user_id, input, service).await?)
     }
+
+    /// Get all the share links of a collection owned by the currently logged in user.
+    async fn collection_share_links(
+        &self,
+        gql_ctx: &Context<'_>,
+        collection_id: String,
+    ) -> Result<Vec<collection_share_link::Model>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(share_link_operations::collection_share_links(service, user_id, collection_id).await?)
+    }
+
+    /// Get the contents of a collection using a share link. Does not require authentication.
+    async fn shared_collection_contents(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: SharedCollectionContentsInput,
+    ) -> Result<SharedCollectionContentsResponse> {
+        let service = self.dependency(gql_ctx);
+        Ok(share_link_operations::shared_collection_contents(service, input).await?)
+    }
 }
 
 #[derive(Default)]
@@ -59,6 +83,10 @@ impl GraphqlDependencyInjector for CollectionMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::WriteProgress
+    }
 }
 
 #[Object]
@@ -112,4 +140,27 @@ impl CollectionMutationResolver {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
         Ok(deploy_remove_entities_from_collection_job(service, user_id, input).await?)
     }
+
+    /// Create a link that allows anyone to view the contents of a collection without logging in.
+    async fn create_collection_share_link(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateCollectionShareLinkInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(share_link_operations::create_collection_share_link(service, user_id, input).await?)
+    }
+
+    /// Revoke a collection share link so that it can no longer be used.
+    async fn revoke_collection_share_link(
+        &self,
+        gql_ctx: &Context<'_>,
+        share_link_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(
+            share_link_operations::revoke_collection_share_link(service, user_id, share_link_id)
+                .await?,
+        )
+    }
 }
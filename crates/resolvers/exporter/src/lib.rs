@@ -1,7 +1,12 @@
 use async_graphql::{Context, Object, Result};
-use common_models::ExportJob;
-use exporter_service::export_operations::{deploy_export_job, user_exports};
+use common_models::{DeployExportJobInput, ExportJob};
+use enum_models::UserApiKeyScope;
+use exporter_service::{
+    export_operations::{deploy_export_job, user_exports},
+    scheduled_exports::update_user_export_schedule,
+};
 use traits::GraphqlDependencyInjector;
+use user_models::UserExportSchedule;
 
 #[derive(Default)]
 pub struct ExporterQueryResolver;
@@ -24,13 +29,31 @@ impl GraphqlDependencyInjector for ExporterMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::ImportExport
+    }
 }
 
 #[Object]
 impl ExporterMutationResolver {
     /// Deploy a job to export data for a user.
-    async fn deploy_export_job(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    async fn deploy_export_job(
+        &self,
+        gql_ctx: &Context<'_>,
+        #[graphql(default)] input: DeployExportJobInput,
+    ) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(deploy_export_job(service, user_id, input).await?)
+    }
+
+    /// Set the schedule for recurring exports of the current user. Pass `null` to remove it.
+    async fn update_user_export_schedule(
+        &self,
+        gql_ctx: &Context<'_>,
+        schedule: Option<UserExportSchedule>,
+    ) -> Result<bool> {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
-        Ok(deploy_export_job(service, user_id).await?)
+        Ok(update_user_export_schedule(service, user_id, schedule).await?)
     }
 }
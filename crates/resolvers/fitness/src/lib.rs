@@ -10,6 +10,7 @@ use dependent_models::{
     UserTemplatesOrWorkoutsListInput, UserWorkoutDetails, UserWorkoutTemplateDetails,
     UserWorkoutsListResponse, UserWorkoutsTemplatesListResponse,
 };
+use enum_models::UserApiKeyScope;
 use fitness_models::{
     UpdateUserExerciseSettings, UpdateUserWorkoutAttributesInput, UserExercisesListInput,
     UserMeasurementsListInput, UserWorkoutInput,
@@ -115,6 +116,10 @@ impl GraphqlDependencyInjector for FitnessMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::FitnessWrite
+    }
 }
 
 #[Object]
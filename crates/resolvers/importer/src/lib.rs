@@ -1,5 +1,6 @@
 use async_graphql::{Context, Object, Result};
 use database_models::import_report;
+use enum_models::UserApiKeyScope;
 use importer_service::job_operations;
 use media_models::DeployImportJobInput;
 use traits::GraphqlDependencyInjector;
@@ -28,6 +29,10 @@ impl GraphqlDependencyInjector for ImporterMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::ImportExport
+    }
 }
 
 #[Object]
@@ -2,9 +2,11 @@ use async_graphql::{Context, Object, Result};
 use common_models::EntityWithLot;
 use dependent_models::{
     CachedResponse, UserMetadataDetails, UserMetadataListInput, UserMetadataListResponse,
+    UserMetadataRecommendationsResponse,
 };
 use media_models::GraphqlMetadataDetails;
 use traits::GraphqlDependencyInjector;
+use user_service::recommendation_operations;
 
 #[derive(Default)]
 pub struct MiscellaneousMetadataQueryResolver;
@@ -50,6 +52,15 @@ impl MiscellaneousMetadataQueryResolver {
         )
     }
 
+    /// Get metadata recommendations for the currently logged in user.
+    async fn user_metadata_recommendations(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<CachedResponse<UserMetadataRecommendationsResponse>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(recommendation_operations::user_metadata_recommendations(service, &user_id).await?)
+    }
+
     /// Returns whether the current user has recently consumed the specified entity.
     async fn user_entity_recently_consumed(
         &self,
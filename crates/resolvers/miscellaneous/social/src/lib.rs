@@ -4,6 +4,7 @@ use dependent_models::{
     CachedResponse, GraphqlPersonDetails, UserPeopleListInput, UserPeopleListResponse,
     UserPersonDetails,
 };
+use enum_models::UserApiKeyScope;
 use media_models::{CreateOrUpdateReviewInput, CreateReviewCommentInput};
 use traits::GraphqlDependencyInjector;
 
@@ -57,6 +58,10 @@ impl GraphqlDependencyInjector for MiscellaneousSocialMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::WriteProgress
+    }
 }
 
 #[Object]
@@ -1,5 +1,6 @@
 use async_graphql::{Context, Object, Result};
 use common_models::StringIdObject;
+use enum_models::UserApiKeyScope;
 use media_models::{
     GraphqlCalendarEvent, GroupedCalendarEvent, MetadataProgressUpdateInput, UpdateSeenItemInput,
     UserCalendarEventInput, UserUpcomingCalendarEventInput,
@@ -44,6 +45,10 @@ impl GraphqlDependencyInjector for MiscellaneousTrackingMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::WriteProgress
+    }
 }
 
 #[Object]
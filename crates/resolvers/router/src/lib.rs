@@ -8,18 +8,23 @@ use axum::{
     extract::{Multipart, Path},
     http::{
         HeaderMap, HeaderValue, StatusCode,
-        header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE, PRAGMA},
+        header::{
+            AUTHORIZATION, CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE, PRAGMA,
+        },
     },
     response::{Html, IntoResponse},
 };
 use background_models::{ApplicationJob, SingleApplicationJob};
 use common_utils::get_temporary_directory;
 use config_definition::MaskedConfig;
+use database_models::integration;
 use dependent_models::{ApplicationCacheKey, EmptyCacheValue, ExpireCacheKeyInput};
+use external_models::{koreader_sync::DocumentProgress, listenbrainz::SubmitListens};
+use futures::TryStreamExt;
 use nanoid::nanoid;
 use supporting_service::SupportingService;
-use tokio::fs::File;
-use tokio_util::io::ReaderStream;
+use tokio::{fs::File, io::copy};
+use tokio_util::io::{ReaderStream, StreamReader};
 
 pub async fn graphql_playground_handler() -> impl IntoResponse {
     Html(playground_source(GraphQLPlaygroundConfig::new(
@@ -67,6 +72,143 @@ pub async fn integration_webhook_handler(
     ))
 }
 
+async fn authorize_koreader_sync(
+    ss: &Arc<SupportingService>,
+    integration_slug: &String,
+    headers: &HeaderMap,
+) -> StdResult<integration::Model, StatusCode> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    integration_service::koreader_sync::authorize(
+        ss,
+        integration_slug,
+        header("x-auth-user"),
+        header("x-auth-key"),
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::UNAUTHORIZED)
+}
+
+/// Accounts are managed through the integration, so registration only succeeds for
+/// credentials that already match it.
+pub async fn koreader_sync_create_user_handler(
+    Path(integration_slug): Path<String>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+    Json(payload): Json<serde_json::Value>,
+) -> StdResult<(StatusCode, Json<serde_json::Value>), StatusCode> {
+    let mut headers = headers;
+    for (field, header) in [("username", "x-auth-user"), ("password", "x-auth-key")] {
+        if let Some(value) = payload
+            .get(field)
+            .and_then(|v| v.as_str())
+            .and_then(|v| HeaderValue::from_str(v).ok())
+        {
+            headers.insert(header, value);
+        }
+    }
+    authorize_koreader_sync(&ss, &integration_slug, &headers).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(serde_json::json!({ "username": payload.get("username") })),
+    ))
+}
+
+pub async fn koreader_sync_auth_handler(
+    Path(integration_slug): Path<String>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+) -> StdResult<Json<serde_json::Value>, StatusCode> {
+    authorize_koreader_sync(&ss, &integration_slug, &headers).await?;
+    Ok(Json(serde_json::json!({ "authorized": "OK" })))
+}
+
+pub async fn koreader_sync_get_progress_handler(
+    Path((integration_slug, document)): Path<(String, String)>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+) -> StdResult<Json<serde_json::Value>, StatusCode> {
+    let integration = authorize_koreader_sync(&ss, &integration_slug, &headers).await?;
+    let progress =
+        integration_service::koreader_sync::get_document_progress(&ss, &integration, document)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(match progress {
+        Some(progress) => serde_json::json!(progress),
+        None => serde_json::json!({}),
+    }))
+}
+
+pub async fn koreader_sync_update_progress_handler(
+    Path(integration_slug): Path<String>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+    Json(payload): Json<DocumentProgress>,
+) -> StdResult<Json<serde_json::Value>, StatusCode> {
+    let integration = authorize_koreader_sync(&ss, &integration_slug, &headers).await?;
+    let progress =
+        integration_service::koreader_sync::update_document_progress(&ss, &integration, payload)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(serde_json::json!({
+        "document": progress.document,
+        "timestamp": progress.timestamp.map(|t| t.timestamp()),
+    })))
+}
+
+async fn authorize_listenbrainz(
+    ss: &Arc<SupportingService>,
+    headers: &HeaderMap,
+) -> StdResult<integration::Model, StatusCode> {
+    let authorization = headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok());
+    integration_service::listenbrainz::authorize(ss, authorization)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)
+}
+
+pub async fn listenbrainz_validate_token_handler(
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+) -> Json<serde_json::Value> {
+    let user_name = match authorize_listenbrainz(&ss, &headers).await {
+        Ok(integration) => integration_service::listenbrainz::user_name(&ss, &integration)
+            .await
+            .ok(),
+        Err(_) => None,
+    };
+    Json(match user_name {
+        Some(user_name) => serde_json::json!({
+            "code": 200,
+            "valid": true,
+            "message": "Token valid.",
+            "user_name": user_name,
+        }),
+        None => serde_json::json!({
+            "code": 200,
+            "valid": false,
+            "message": "Invalid token.",
+        }),
+    })
+}
+
+/// Accept listens in the ListenBrainz format and queue them to be processed like any other
+/// sink integration webhook.
+pub async fn listenbrainz_submit_listens_handler(
+    Extension(ss): Extension<Arc<SupportingService>>,
+    headers: HeaderMap,
+    Json(payload): Json<SubmitListens>,
+) -> StdResult<Json<serde_json::Value>, StatusCode> {
+    let integration = authorize_listenbrainz(&ss, &headers).await?;
+    let payload = serde_json::to_string(&payload).map_err(|_| StatusCode::BAD_REQUEST)?;
+    ss.perform_application_job(ApplicationJob::Single(
+        SingleApplicationJob::ProcessIntegrationWebhook(integration.id, payload),
+    ))
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(serde_json::json!({ "status": "ok" })))
+}
+
 pub async fn download_logs_handler(
     Path(token): Path<String>,
     Extension(ss): Extension<Arc<SupportingService>>,
@@ -109,3 +251,62 @@ pub async fn download_logs_handler(
 
     Ok((headers, body))
 }
+
+/// Serve an object from the local file storage backend using a token generated by
+/// `file_storage_service::get_presigned_url`.
+pub async fn file_storage_download_handler(
+    Path(token): Path<String>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+) -> StdResult<impl IntoResponse, StatusCode> {
+    let path = file_storage_service::get_local_download_path(&ss, token)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let file = File::open(&path).await.map_err(|_| StatusCode::NOT_FOUND)?;
+
+    let file_size = file
+        .metadata()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .len();
+
+    let content_type = mime_guess::from_path(&path).first_or_octet_stream();
+    let stream = ReaderStream::new(file);
+    let body = Body::from_stream(stream);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(file_size));
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(content_type.as_ref())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    );
+
+    Ok((headers, body))
+}
+
+/// Receive an object for the local file storage backend using a token generated by
+/// `file_storage_service::get_presigned_put_url`.
+pub async fn file_storage_upload_handler(
+    Path(token): Path<String>,
+    Extension(ss): Extension<Arc<SupportingService>>,
+    body: Body,
+) -> StdResult<StatusCode, StatusCode> {
+    let path = file_storage_service::prepare_local_upload(&ss, token)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let mut file = File::create(&path)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let stream = body.into_data_stream().map_err(std::io::Error::other);
+    let mut reader = StreamReader::new(stream);
+    copy(&mut reader, &mut file)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(StatusCode::OK)
+}
@@ -1,19 +1,28 @@
 use async_graphql::{Context, Object, Result};
+use common_models::StringIdObject;
+use database_models::user_passkey;
+use enum_models::UserApiKeyScope;
 use media_models::{
-    AuthUserInput, LoginResult, OidcTokenOutput, RegisterResult, RegisterUserInput,
-    UserTwoFactorBackupCodesResponse, UserTwoFactorInitiateResponse, UserTwoFactorSetupInput,
-    UserTwoFactorVerifyInput, VerifyTwoFactorResult,
+    AuthUserInput, FinishPasskeyAuthenticationInput, FinishPasskeyRegistrationInput, LoginResult,
+    OidcTokenOutput, RegisterResult, RegisterUserInput, StartPasskeyAuthenticationResponse,
+    UserSession, UserTwoFactorBackupCodesResponse, UserTwoFactorInitiateResponse,
+    UserTwoFactorSetupInput, UserTwoFactorVerifyInput, VerifyTwoFactorResult,
 };
 use traits::GraphqlDependencyInjector;
 use user_service::{
-    authentication_operations, oidc_operations, two_factor_operations, user_data_operations,
-    user_management_operations,
+    authentication_operations, oidc_operations, passkey_operations, two_factor_operations,
+    user_data_operations, user_management_operations,
 };
+use uuid::Uuid;
 
 #[derive(Default)]
 pub struct UserAuthenticationQueryResolver;
 
-impl GraphqlDependencyInjector for UserAuthenticationQueryResolver {}
+impl GraphqlDependencyInjector for UserAuthenticationQueryResolver {
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::Admin
+    }
+}
 
 #[Object]
 impl UserAuthenticationQueryResolver {
@@ -38,6 +47,19 @@ impl UserAuthenticationQueryResolver {
         let service = self.dependency(gql_ctx);
         Ok(user_data_operations::user_by_oidc_issuer_id(service, oidc_issuer_id).await?)
     }
+
+    /// Get all the active sessions of the currently logged in user.
+    async fn user_sessions(&self, gql_ctx: &Context<'_>) -> Result<Vec<UserSession>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        let session_id = self.user_session_id_from_ctx(gql_ctx).ok();
+        Ok(authentication_operations::user_sessions(service, &user_id, session_id).await?)
+    }
+
+    /// Get all the passkeys registered by the currently logged in user.
+    async fn user_passkeys(&self, gql_ctx: &Context<'_>) -> Result<Vec<user_passkey::Model>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(passkey_operations::user_passkeys(service, &user_id).await?)
+    }
 }
 
 #[derive(Default)]
@@ -65,7 +87,17 @@ impl UserAuthenticationMutationResolver {
     /// Login a user using their username and password and return an auth token.
     async fn login_user(&self, gql_ctx: &Context<'_>, input: AuthUserInput) -> Result<LoginResult> {
         let service = self.dependency(gql_ctx);
-        Ok(authentication_operations::login_user(service, input).await?)
+        let client = self.session_client_from_ctx(gql_ctx);
+        Ok(authentication_operations::login_user(service, input, client).await?)
+    }
+
+    /// Login the user whose name was sent in the header configured by
+    /// `USERS_TRUSTED_PROXY_HEADER` by a trusted reverse proxy and return an auth token.
+    async fn login_user_via_proxy(&self, gql_ctx: &Context<'_>) -> Result<LoginResult> {
+        let service = self.dependency(gql_ctx);
+        let client = self.session_client_from_ctx(gql_ctx);
+        let username = self.proxy_username_from_ctx(gql_ctx);
+        Ok(authentication_operations::login_user_via_proxy(service, username, client).await?)
     }
 
     /// Logout the current user by invalidating their session.
@@ -78,7 +110,24 @@ impl UserAuthenticationMutationResolver {
     /// Generate an auth token without any expiry.
     async fn generate_auth_token(&self, gql_ctx: &Context<'_>) -> Result<String> {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
-        Ok(authentication_operations::generate_auth_token(service, user_id).await?)
+        let client = self.session_client_from_ctx(gql_ctx);
+        Ok(authentication_operations::generate_auth_token(service, user_id, client).await?)
+    }
+
+    /// Revoke one of the sessions of the currently logged in user.
+    async fn revoke_session(&self, gql_ctx: &Context<'_>, session_id: Uuid) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(authentication_operations::revoke_session(service, &user_id, session_id).await?)
+    }
+
+    /// Revoke all sessions of the currently logged in user except the one making the request.
+    async fn revoke_all_other_sessions(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        let session_id = self.user_session_id_from_ctx(gql_ctx)?;
+        Ok(
+            authentication_operations::revoke_all_other_sessions(service, &user_id, &session_id)
+                .await?,
+        )
     }
 
     /// Verify a two-factor authentication code (TOTP or backup code).
@@ -88,7 +137,8 @@ impl UserAuthenticationMutationResolver {
         input: UserTwoFactorVerifyInput,
     ) -> Result<VerifyTwoFactorResult> {
         let service = self.dependency(gql_ctx);
-        Ok(two_factor_operations::verify_two_factor(service, input).await?)
+        let client = self.session_client_from_ctx(gql_ctx);
+        Ok(two_factor_operations::verify_two_factor(service, input, client).await?)
     }
 
     /// Initiate two-factor authentication setup by generating a TOTP secret.
@@ -124,4 +174,71 @@ impl UserAuthenticationMutationResolver {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
         Ok(two_factor_operations::regenerate_two_factor_backup_codes(service, user_id).await?)
     }
+
+    /// Link the OIDC identity of the authorization code to the currently logged in user.
+    async fn link_oidc_identity(&self, gql_ctx: &Context<'_>, code: String) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(oidc_operations::link_oidc_identity(service, user_id, code).await?)
+    }
+
+    /// Unlink the OIDC identity of the currently logged in user.
+    async fn unlink_oidc_identity(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(oidc_operations::unlink_oidc_identity(service, user_id).await?)
+    }
+
+    /// Get the options to be passed to the browser to register a new passkey.
+    async fn start_passkey_registration(&self, gql_ctx: &Context<'_>) -> Result<serde_json::Value> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(passkey_operations::start_passkey_registration(service, user_id).await?)
+    }
+
+    /// Verify the credential created by the browser and save it as a passkey.
+    async fn finish_passkey_registration(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: FinishPasskeyRegistrationInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(passkey_operations::finish_passkey_registration(service, user_id, input).await?)
+    }
+
+    /// Rename a passkey of the currently logged in user.
+    async fn update_user_passkey(
+        &self,
+        gql_ctx: &Context<'_>,
+        passkey_id: String,
+        name: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(passkey_operations::update_user_passkey(service, user_id, passkey_id, name).await?)
+    }
+
+    /// Delete a passkey of the currently logged in user.
+    async fn delete_user_passkey(&self, gql_ctx: &Context<'_>, passkey_id: String) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(passkey_operations::delete_user_passkey(service, user_id, passkey_id).await?)
+    }
+
+    /// Start logging in with a passkey. Pass the user id returned by `loginUser` to use the
+    /// passkey as the second factor instead of a TOTP code.
+    async fn start_passkey_authentication(
+        &self,
+        gql_ctx: &Context<'_>,
+        user_id: Option<String>,
+    ) -> Result<StartPasskeyAuthenticationResponse> {
+        let service = self.dependency(gql_ctx);
+        Ok(passkey_operations::start_passkey_authentication(service, user_id).await?)
+    }
+
+    /// Verify the credential returned by the browser and return an auth token.
+    async fn finish_passkey_authentication(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: FinishPasskeyAuthenticationInput,
+    ) -> Result<LoginResult> {
+        let service = self.dependency(gql_ctx);
+        let client = self.session_client_from_ctx(gql_ctx);
+        Ok(passkey_operations::finish_passkey_authentication(service, input, client).await?)
+    }
 }
@@ -1,24 +1,30 @@
 use async_graphql::{Context, Object, Result};
 use common_models::StringIdObject;
-use database_models::access_link;
-use dependent_models::{BasicUserDetails, UserDetailsResult};
+use database_models::{access_link, user_api_key, user_audit_log};
+use dependent_models::{BasicUserDetails, SearchResults, UserDetailsResult};
+use enum_models::UserApiKeyScope;
 use media_models::{
-    CreateAccessLinkInput, GenerateUserImpersonationLinkInput,
-    GenerateUserImpersonationLinkResponse, GetPasswordChangeSessionInput,
-    GetPasswordChangeSessionResponse, ProcessAccessLinkInput, ProcessAccessLinkResult,
-    SetPasswordViaSessionInput, UserResetResult,
+    CreateAccessLinkInput, CreateUserApiKeyInput, CreateUserApiKeyResponse,
+    GenerateUserImpersonationLinkInput, GenerateUserImpersonationLinkResponse,
+    GetPasswordChangeSessionInput, GetPasswordChangeSessionResponse, LoginAttemptSubject,
+    ProcessAccessLinkInput, ProcessAccessLinkResult, SetPasswordViaSessionInput, UserResetResult,
 };
 use traits::GraphqlDependencyInjector;
-use user_models::{UpdateUserInput, UserPreferences};
+use user_models::{UpdateUserInput, UserAuditLogsInput, UserPreferences};
 use user_service::{
-    access_link_operations, authentication_operations, password_change_operations,
-    user_data_operations, user_management_operations, user_preferences_operations,
+    access_link_operations, api_key_operations, audit_log_operations, authentication_operations,
+    login_attempt_operations, password_change_operations, user_data_operations,
+    user_management_operations, user_preferences_operations,
 };
 
 #[derive(Default)]
 pub struct UserManagementQueryResolver;
 
-impl GraphqlDependencyInjector for UserManagementQueryResolver {}
+impl GraphqlDependencyInjector for UserManagementQueryResolver {
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::Admin
+    }
+}
 
 #[Object]
 impl UserManagementQueryResolver {
@@ -34,9 +40,17 @@ impl UserManagementQueryResolver {
 
     /// Get details about the currently logged in user.
     async fn user_details(&self, gql_ctx: &Context<'_>) -> Result<UserDetailsResult> {
+        self.check_api_key_scope(gql_ctx)?;
         let service = self.dependency(gql_ctx);
-        let session_id = self.user_session_id_from_ctx(gql_ctx)?;
-        Ok(authentication_operations::user_details(service, &session_id).await?)
+        match self.user_session_id_from_ctx(gql_ctx) {
+            Ok(session_id) => {
+                Ok(authentication_operations::user_details(service, &session_id).await?)
+            }
+            Err(_) => {
+                let user_id = self.user_id_from_ctx(gql_ctx).await?;
+                Ok(authentication_operations::api_key_user_details(service, &user_id).await?)
+            }
+        }
     }
 
     /// Get all access links generated by the currently logged in user.
@@ -44,6 +58,22 @@ impl UserManagementQueryResolver {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
         Ok(user_data_operations::user_access_links(service, &user_id).await?)
     }
+
+    /// Get all personal API keys created by the currently logged in user.
+    async fn user_api_keys(&self, gql_ctx: &Context<'_>) -> Result<Vec<user_api_key::Model>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(api_key_operations::user_api_keys(service, &user_id).await?)
+    }
+
+    /// Get the security events of the currently logged in user, or of all users for admins.
+    async fn user_audit_logs(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserAuditLogsInput,
+    ) -> Result<SearchResults<user_audit_log::Model>> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(audit_log_operations::user_audit_logs(service, user_id, input).await?)
+    }
 }
 
 #[derive(Default)]
@@ -116,10 +146,13 @@ impl UserManagementMutationResolver {
         input: SetPasswordViaSessionInput,
     ) -> Result<bool> {
         let service = self.dependency(gql_ctx);
+        let current_session_id = self.user_session_id_from_ctx(gql_ctx).ok();
         Ok(password_change_operations::set_password_via_session(
             service,
             input.session_id,
             input.password,
+            input.revoke_other_sessions.unwrap_or_default(),
+            current_session_id,
         )
         .await?)
     }
@@ -141,7 +174,8 @@ impl UserManagementMutationResolver {
         input: ProcessAccessLinkInput,
     ) -> Result<ProcessAccessLinkResult> {
         let service = self.dependency(gql_ctx);
-        Ok(access_link_operations::process_access_link(service, input).await?)
+        let client = self.session_client_from_ctx(gql_ctx);
+        Ok(access_link_operations::process_access_link(service, input, client).await?)
     }
 
     /// Revoke an access link.
@@ -150,8 +184,24 @@ impl UserManagementMutationResolver {
         gql_ctx: &Context<'_>,
         access_link_id: String,
     ) -> Result<bool> {
-        let (service, _) = self.dependency_and_user(gql_ctx).await?;
-        Ok(authentication_operations::revoke_access_link(service, access_link_id).await?)
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(authentication_operations::revoke_access_link(service, user_id, access_link_id).await?)
+    }
+
+    /// Create a personal API key. The key is only returned once.
+    async fn create_user_api_key(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateUserApiKeyInput,
+    ) -> Result<CreateUserApiKeyResponse> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(api_key_operations::create_user_api_key(service, user_id, input).await?)
+    }
+
+    /// Revoke a personal API key.
+    async fn revoke_user_api_key(&self, gql_ctx: &Context<'_>, api_key_id: String) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(api_key_operations::revoke_user_api_key(service, user_id, api_key_id).await?)
     }
 
     /// Generate a user impersonation link. Requires admin access token.
@@ -163,4 +213,15 @@ impl UserManagementMutationResolver {
         let service = self.dependency(gql_ctx);
         Ok(access_link_operations::generate_impersonation_link(service, input).await?)
     }
+
+    /// Clear the failed login attempts of a username or IP address so that they can
+    /// login again. The account clearing the lockout must be an `Admin`.
+    async fn clear_login_lockout(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: LoginAttemptSubject,
+    ) -> Result<bool> {
+        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
+        Ok(login_attempt_operations::clear_login_lockout(service, user_id, input).await?)
+    }
 }
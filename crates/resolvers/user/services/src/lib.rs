@@ -1,29 +1,24 @@
 use async_graphql::{Context, Object, Result};
 use database_models::{integration, notification_platform};
-use dependent_models::{CachedResponse, UserMetadataRecommendationsResponse};
+use enum_models::UserApiKeyScope;
 use media_models::{
     CreateOrUpdateUserIntegrationInput, CreateUserNotificationPlatformInput,
     UpdateUserNotificationPlatformInput,
 };
 use traits::GraphqlDependencyInjector;
-use user_service::{integration_operations, notification_operations, recommendation_operations};
+use user_service::{integration_operations, notification_operations};
 
 #[derive(Default)]
 pub struct UserServicesQueryResolver;
 
-impl GraphqlDependencyInjector for UserServicesQueryResolver {}
+impl GraphqlDependencyInjector for UserServicesQueryResolver {
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        UserApiKeyScope::Admin
+    }
+}
 
 #[Object]
 impl UserServicesQueryResolver {
-    /// Get metadata recommendations for the currently logged in user.
-    async fn user_metadata_recommendations(
-        &self,
-        gql_ctx: &Context<'_>,
-    ) -> Result<CachedResponse<UserMetadataRecommendationsResponse>> {
-        let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
-        Ok(recommendation_operations::user_metadata_recommendations(service, &user_id).await?)
-    }
-
     /// Get all the integrations for the currently logged in user.
     async fn user_integrations(&self, gql_ctx: &Context<'_>) -> Result<Vec<integration::Model>> {
         let (service, user_id) = self.dependency_and_user(gql_ctx).await?;
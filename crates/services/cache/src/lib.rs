@@ -23,12 +23,24 @@ fn get_expiry_for_key(ss: &Arc<SupportingService>, key: &ApplicationCacheKey) ->
 
         ApplicationCacheKey::LogDownloadToken { .. } => Duration::minutes(1),
 
+        ApplicationCacheKey::FileStorageDownloadToken { .. } => Duration::minutes(2),
+
+        ApplicationCacheKey::UserPasskeyRegistration { .. }
+        | ApplicationCacheKey::UserPasskeyAuthentication { .. } => Duration::minutes(5),
+
+        ApplicationCacheKey::FileStorageUploadToken { .. } => Duration::minutes(10),
+
+        ApplicationCacheKey::UserLoginFailedAttempts { .. } => {
+            Duration::minutes(ss.config.users.login_lockout_duration_minutes)
+        }
+
         ApplicationCacheKey::MediaTranslationInProgress { .. } => Duration::minutes(15),
 
         ApplicationCacheKey::SpotifyAccessToken => Duration::minutes(50),
 
         ApplicationCacheKey::CoreDetails
         | ApplicationCacheKey::PeopleSearch { .. }
+        | ApplicationCacheKey::CollectionShareLinkVisit { .. }
         | ApplicationCacheKey::UserAnalytics { .. }
         | ApplicationCacheKey::UserPeopleList { .. }
         | ApplicationCacheKey::MetadataSearch { .. }
@@ -255,6 +267,23 @@ where
     Ok(CachedResponse { cache_id, response })
 }
 
+/// Replace the value stored against a key without touching its expiry.
+pub async fn update_value(
+    ss: &Arc<SupportingService>,
+    key: ApplicationCacheKey,
+    value: ApplicationCacheValue,
+) -> Result<()> {
+    ApplicationCache::update_many()
+        .filter(application_cache::Column::Key.eq(serde_json::to_string(&key)?))
+        .set(application_cache::ActiveModel {
+            value: ActiveValue::Set(serde_json::to_value(&value)?),
+            ..Default::default()
+        })
+        .exec(&ss.db)
+        .await?;
+    Ok(())
+}
+
 pub async fn expire_key(ss: &Arc<SupportingService>, by: ExpireCacheKeyInput) -> Result<()> {
     let expired = ApplicationCache::update_many()
         .filter(application_cache::Column::ExpiresAt.gt(Utc::now()))
@@ -3,18 +3,24 @@ use std::sync::Arc;
 use anyhow::Result;
 use background_models::{ApplicationJob, HpApplicationJob};
 use common_models::ChangeCollectionToEntitiesInput;
+use dependent_collection_utils::get_editable_collection;
 use supporting_service::SupportingService;
 
 pub mod content_operations;
 pub mod event_operations;
 pub mod management_operations;
 pub mod recommendation_operations;
+pub mod share_link_operations;
+
+#[cfg(test)]
+mod tests;
 
 pub async fn deploy_add_entities_to_collection_job(
     ss: &Arc<SupportingService>,
     user_id: String,
     input: ChangeCollectionToEntitiesInput,
 ) -> Result<bool> {
+    get_editable_collection(&user_id, &input.collection_name, &input.creator_user_id, ss).await?;
     ss.perform_application_job(ApplicationJob::Hp(
         HpApplicationJob::AddEntitiesToCollection(user_id, input),
     ))
@@ -27,6 +33,7 @@ pub async fn deploy_remove_entities_from_collection_job(
     user_id: String,
     input: ChangeCollectionToEntitiesInput,
 ) -> Result<bool> {
+    get_editable_collection(&user_id, &input.collection_name, &input.creator_user_id, ss).await?;
     ss.perform_application_job(ApplicationJob::Hp(
         HpApplicationJob::RemoveEntitiesFromCollection(user_id, input),
     ))
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use common_models::StringIdObject;
+use database_models::{
+    collection_share_link,
+    prelude::{Collection, CollectionShareLink},
+};
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, CollectionContentsInput,
+    CollectionShareLinkVisitCacheValue, SharedCollectionContentsInput,
+    SharedCollectionContentsResponse,
+};
+use enum_models::Visibility;
+use media_models::CreateCollectionShareLinkInput;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, IntoActiveModel,
+    QueryFilter, QueryOrder, prelude::DateTimeUtc, sea_query::Expr,
+};
+use supporting_service::SupportingService;
+use uuid::Uuid;
+
+use crate::content_operations::collection_contents;
+
+async fn owned_collection_guard(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    collection_id: &String,
+) -> Result<()> {
+    let collection = Collection::find_by_id(collection_id).one(&ss.db).await?;
+    match collection {
+        Some(collection) if &collection.user_id == user_id => Ok(()),
+        _ => bail!("Collection not found"),
+    }
+}
+
+pub async fn create_collection_share_link(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: CreateCollectionShareLinkInput,
+) -> Result<StringIdObject> {
+    owned_collection_guard(ss, &user_id, &input.collection_id).await?;
+    let link = collection_share_link::ActiveModel {
+        user_id: ActiveValue::Set(user_id),
+        expires_on: ActiveValue::Set(input.expires_on),
+        maximum_uses: ActiveValue::Set(input.maximum_uses),
+        collection_id: ActiveValue::Set(input.collection_id),
+        ..Default::default()
+    };
+    let link = link.insert(&ss.db).await?;
+    Ok(StringIdObject { id: link.id })
+}
+
+pub async fn collection_share_links(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    collection_id: String,
+) -> Result<Vec<collection_share_link::Model>> {
+    owned_collection_guard(ss, &user_id, &collection_id).await?;
+    let links = CollectionShareLink::find()
+        .filter(collection_share_link::Column::CollectionId.eq(collection_id))
+        .order_by_desc(collection_share_link::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(links)
+}
+
+pub async fn revoke_collection_share_link(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    share_link_id: String,
+) -> Result<bool> {
+    let link = CollectionShareLink::find_by_id(share_link_id)
+        .filter(collection_share_link::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?;
+    let Some(link) = link else {
+        bail!("Share link not found");
+    };
+    let mut link = link.into_active_model();
+    link.is_revoked = ActiveValue::Set(Some(true));
+    link.update(&ss.db).await?;
+    Ok(true)
+}
+
+/// A request continues an earlier visit when it sends the visit token that was handed out
+/// for the same share link. Every other request is a new use of the share link.
+pub(crate) fn is_new_share_link_use(
+    share_link_id: &str,
+    visit: Option<&CollectionShareLinkVisitCacheValue>,
+) -> bool {
+    visit.is_none_or(|visit| visit.share_link_id != share_link_id)
+}
+
+pub(crate) fn share_link_error(
+    link: &collection_share_link::Model,
+    is_new_use: bool,
+    now: DateTimeUtc,
+) -> Option<&'static str> {
+    if link.is_revoked == Some(true) {
+        return Some("Share link has been revoked");
+    }
+    if link.expires_on.is_some_and(|expires_on| expires_on < now) {
+        return Some("Share link has expired");
+    }
+    if is_new_use
+        && link
+            .maximum_uses
+            .is_some_and(|maximum_uses| link.times_used >= maximum_uses)
+    {
+        return Some("Share link has reached its maximum uses");
+    }
+    None
+}
+
+/// Get the contents of the collection behind a share link without logging in. Only
+/// reviews that are public are returned since the viewer is not a user of the instance.
+pub async fn shared_collection_contents(
+    ss: &Arc<SupportingService>,
+    input: SharedCollectionContentsInput,
+) -> Result<SharedCollectionContentsResponse> {
+    let link = CollectionShareLink::find_by_id(&input.share_link_id)
+        .one(&ss.db)
+        .await?;
+    let Some(link) = link else {
+        bail!("Share link not found");
+    };
+    let visit = match input.visit_token {
+        Some(token) => cache_service::get_value::<CollectionShareLinkVisitCacheValue>(
+            ss,
+            ApplicationCacheKey::CollectionShareLinkVisit(token.clone()),
+        )
+        .await
+        .map(|(_, visit)| (token, visit)),
+        None => None,
+    };
+    let is_new_use = is_new_share_link_use(&link.id, visit.as_ref().map(|(_, visit)| visit));
+    if let Some(error) = share_link_error(&link, is_new_use, Utc::now()) {
+        bail!(error);
+    }
+    if is_new_use {
+        // The limit is checked again while updating in case the link was opened concurrently.
+        let updated = CollectionShareLink::update_many()
+            .col_expr(
+                collection_share_link::Column::TimesUsed,
+                Expr::col(collection_share_link::Column::TimesUsed).add(1),
+            )
+            .filter(collection_share_link::Column::Id.eq(&link.id))
+            .filter(
+                Condition::any()
+                    .add(collection_share_link::Column::MaximumUses.is_null())
+                    .add(
+                        Expr::col(collection_share_link::Column::TimesUsed)
+                            .lt(Expr::col(collection_share_link::Column::MaximumUses)),
+                    ),
+            )
+            .exec(&ss.db)
+            .await?;
+        if updated.rows_affected == 0 {
+            bail!("Share link has reached its maximum uses");
+        }
+    }
+    let visit_token = match visit {
+        Some((token, _)) if !is_new_use => token,
+        _ => {
+            let token = Uuid::new_v4().to_string();
+            cache_service::set_key(
+                ss,
+                ApplicationCacheKey::CollectionShareLinkVisit(token.clone()),
+                ApplicationCacheValue::CollectionShareLinkVisit(
+                    CollectionShareLinkVisitCacheValue {
+                        share_link_id: link.id.clone(),
+                    },
+                ),
+            )
+            .await?;
+            token
+        }
+    };
+    let contents_input = CollectionContentsInput {
+        sort: input.sort,
+        search: input.search,
+        filter: input.filter,
+        collection_id: link.collection_id,
+    };
+    let mut contents = collection_contents(&link.user_id, contents_input, ss)
+        .await?
+        .response;
+    contents
+        .reviews
+        .retain(|review| review.visibility == Visibility::Public);
+    Ok(SharedCollectionContentsResponse {
+        contents,
+        visit_token,
+    })
+}
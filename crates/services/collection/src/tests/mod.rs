@@ -0,0 +1 @@
+mod share_links;
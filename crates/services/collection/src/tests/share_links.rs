@@ -0,0 +1,103 @@
+use chrono::{Duration, Utc};
+use database_models::collection_share_link;
+use dependent_models::CollectionShareLinkVisitCacheValue;
+use rstest::rstest;
+
+use crate::share_link_operations::{is_new_share_link_use, share_link_error};
+
+fn share_link() -> collection_share_link::Model {
+    collection_share_link::Model {
+        id: "csl_test".to_owned(),
+        user_id: "usr_test".to_owned(),
+        collection_id: "col_test".to_owned(),
+        created_on: Utc::now(),
+        expires_on: None,
+        times_used: 0,
+        maximum_uses: None,
+        is_revoked: None,
+    }
+}
+
+fn visit(share_link_id: &str) -> CollectionShareLinkVisitCacheValue {
+    CollectionShareLinkVisitCacheValue {
+        share_link_id: share_link_id.to_owned(),
+    }
+}
+
+#[test]
+fn test_opening_without_a_visit_is_a_new_use() {
+    assert!(is_new_share_link_use("csl_test", None));
+}
+
+#[test]
+fn test_continuing_a_visit_is_not_a_new_use() {
+    assert!(!is_new_share_link_use("csl_test", Some(&visit("csl_test"))));
+}
+
+#[test]
+fn test_visit_of_another_share_link_is_a_new_use() {
+    assert!(is_new_share_link_use("csl_test", Some(&visit("csl_other"))));
+}
+
+#[test]
+fn test_valid_share_link_can_be_used() {
+    assert_eq!(share_link_error(&share_link(), true, Utc::now()), None);
+}
+
+#[test]
+fn test_expired_share_link_is_rejected() {
+    let link = collection_share_link::Model {
+        expires_on: Some(Utc::now() - Duration::hours(1)),
+        ..share_link()
+    };
+    assert_eq!(
+        share_link_error(&link, false, Utc::now()),
+        Some("Share link has expired")
+    );
+}
+
+#[test]
+fn test_share_link_is_usable_until_it_expires() {
+    let link = collection_share_link::Model {
+        expires_on: Some(Utc::now() + Duration::hours(1)),
+        ..share_link()
+    };
+    assert_eq!(share_link_error(&link, true, Utc::now()), None);
+}
+
+#[test]
+fn test_revoked_share_link_is_rejected() {
+    let link = collection_share_link::Model {
+        is_revoked: Some(true),
+        ..share_link()
+    };
+    assert_eq!(
+        share_link_error(&link, false, Utc::now()),
+        Some("Share link has been revoked")
+    );
+}
+
+#[rstest]
+#[case(0, None)]
+#[case(1, Some("Share link has reached its maximum uses"))]
+fn test_maximum_uses_are_enforced_for_new_uses(
+    #[case] times_used: i32,
+    #[case] expected: Option<&str>,
+) {
+    let link = collection_share_link::Model {
+        times_used,
+        maximum_uses: Some(1),
+        ..share_link()
+    };
+    assert_eq!(share_link_error(&link, true, Utc::now()), expected);
+}
+
+#[test]
+fn test_visits_of_a_used_up_share_link_can_continue() {
+    let link = collection_share_link::Model {
+        times_used: 1,
+        maximum_uses: Some(1),
+        ..share_link()
+    };
+    assert_eq!(share_link_error(&link, false, Utc::now()), None);
+}
@@ -1,15 +1,16 @@
-use std::{fs::File as StdFile, sync::Arc};
+use std::sync::Arc;
 
 use anyhow::Result;
 use common_utils::ryot_log;
 use dependent_entity_list_utils::user_collections_list;
-use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
 
+use crate::export_writers::ExportWriter;
+
 pub async fn export_collections(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     ryot_log!(debug, "Getting collections list for user_id = {}", user_id);
     let collections_resp = user_collections_list(user_id, ss).await?;
@@ -17,7 +18,7 @@ pub async fn export_collections(
 
     ryot_log!(debug, "Exporting {} collections", collections.len());
     for collection in collections {
-        writer.serialize_value(&collection)?;
+        writer.write_collection(collection)?;
     }
     Ok(())
 }
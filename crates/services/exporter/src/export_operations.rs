@@ -2,35 +2,73 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use anyhow::{Result, bail};
 use background_models::{ApplicationJob, MpApplicationJob};
-use chrono::{DateTime, Utc};
-use common_models::ExportJob;
+use chrono::{DateTime, NaiveDate, Utc};
+use common_models::{
+    ApplicationDateRange, DeployExportJobInput, ExportFormat, ExportItem, ExportJob,
+};
 use common_utils::{get_temporary_directory, ryot_log};
+use itertools::Itertools;
 use nanoid::nanoid;
 use reqwest::{
     Body, Client,
     header::{CONTENT_LENGTH, CONTENT_TYPE},
 };
 use sea_orm::Iterable;
-use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
 use tokio::fs::File;
 use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
     collection_exports::export_collections,
-    export_utilities::ExportItem,
+    export_writers::{
+        CsvExportWriter, ExportWriter, JsonExportWriter, LetterboxdExportWriter, OpmlExportWriter,
+    },
     fitness_exports::{
         export_exercises, export_measurements, export_workout_templates, export_workouts,
     },
-    media_exports::{export_media, export_media_group, export_people},
+    media_exports::{export_media, export_media_group, export_people, podcast_feed_urls},
+    scheduled_exports::handle_export_completed,
 };
 
-pub async fn deploy_export_job(ss: &Arc<SupportingService>, user_id: String) -> Result<bool> {
-    ss.perform_application_job(ApplicationJob::Mp(MpApplicationJob::PerformExport(user_id)))
-        .await?;
+pub(crate) fn validate_export_input(input: &DeployExportJobInput) -> Result<()> {
+    if let Some(items) = &input.items
+        && items.is_empty()
+    {
+        bail!("At least one item needs to be selected for the export");
+    }
+    let metadata_only_format = match input.format {
+        Some(ExportFormat::Letterboxd) => Some("Letterboxd"),
+        Some(ExportFormat::Opml) => Some("OPML"),
+        _ => None,
+    };
+    if let Some(format) = metadata_only_format
+        && input
+            .items
+            .as_ref()
+            .is_some_and(|items| !items.contains(&ExportItem::Metadata))
+    {
+        bail!("{format} exports can only contain metadata");
+    }
+    Ok(())
+}
+
+pub async fn deploy_export_job(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: DeployExportJobInput,
+) -> Result<bool> {
+    validate_export_input(&input)?;
+    ss.perform_application_job(ApplicationJob::Mp(
+        MpApplicationJob::PerformExportWithInput(user_id, input),
+    ))
+    .await?;
     Ok(true)
 }
 
+fn parse_date_metadata(metadata: &HashMap<String, String>, key: &str) -> Option<NaiveDate> {
+    metadata.get(key).and_then(|d| d.parse().ok())
+}
+
 pub async fn user_exports(ss: &Arc<SupportingService>, user_id: String) -> Result<Vec<ExportJob>> {
     if !ss.config.file_storage.is_enabled() {
         return Ok(vec![]);
@@ -45,59 +83,121 @@ pub async fn user_exports(ss: &Arc<SupportingService>, user_id: String) -> Resul
             DateTime::parse_from_rfc2822(metadata.get("started_at").unwrap())?.with_timezone(&Utc);
         let ended_at =
             DateTime::parse_from_rfc2822(metadata.get("ended_at").unwrap())?.with_timezone(&Utc);
+        let format = match metadata.get("format").map(String::as_str) {
+            Some("csv") => ExportFormat::Csv,
+            Some("letterboxd") => ExportFormat::Letterboxd,
+            Some("opml") => ExportFormat::Opml,
+            _ => ExportFormat::Json,
+        };
+        let items = match metadata.get("items") {
+            None => ExportItem::iter().collect(),
+            Some(items) => ExportItem::iter()
+                .filter(|i| items.split(',').contains(&i.to_string().as_str()))
+                .collect(),
+        };
+        let date_range = ApplicationDateRange {
+            end_date: parse_date_metadata(&metadata, "end_date"),
+            start_date: parse_date_metadata(&metadata, "start_date"),
+        };
+        let date_range = (date_range.start_date.is_some() || date_range.end_date.is_some())
+            .then_some(date_range);
+        let is_scheduled = metadata.get("scheduled").is_some_and(|s| s == "true");
         resp.push(ExportJob {
             key,
             url,
             size,
+            items,
+            format,
             ended_at,
             started_at,
+            date_range,
+            is_scheduled,
         });
     }
     resp.sort_by(|a, b| b.ended_at.cmp(&a.ended_at));
     Ok(resp)
 }
 
-pub async fn perform_export(ss: &Arc<SupportingService>, user_id: String) -> Result<()> {
+pub async fn perform_export(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: DeployExportJobInput,
+    is_scheduled: bool,
+) -> Result<()> {
     if !ss.config.file_storage.is_enabled() {
         bail!("File storage needs to be enabled to perform an export.");
     }
     let started_at = Utc::now();
-    let export_path =
-        PathBuf::from(get_temporary_directory()).join(format!("ryot-export-{}.json", nanoid!()));
-    let file = std::fs::File::create(&export_path)?;
-    let mut writer = JsonStreamWriter::new(file);
-    writer.begin_object()?;
+    let format = input.format.unwrap_or_default();
+    let items = ExportItem::iter()
+        .filter(|i| input.items.as_ref().is_none_or(|items| items.contains(i)))
+        .filter(|i| {
+            !matches!(format, ExportFormat::Letterboxd | ExportFormat::Opml)
+                || *i == ExportItem::Metadata
+        })
+        .collect_vec();
+    let date_range = &input.date_range;
+    let extension = match format {
+        ExportFormat::Json => "json",
+        ExportFormat::Csv => "zip",
+        ExportFormat::Letterboxd => "csv",
+        ExportFormat::Opml => "opml",
+    };
+    let export_path = PathBuf::from(get_temporary_directory())
+        .join(format!("ryot-export-{}.{extension}", nanoid!()));
+    let mut writer: Box<dyn ExportWriter> = match format {
+        ExportFormat::Json => Box::new(JsonExportWriter::new(export_path)?),
+        ExportFormat::Csv => Box::new(CsvExportWriter::new(export_path)?),
+        ExportFormat::Letterboxd => Box::new(LetterboxdExportWriter::new(export_path)?),
+        ExportFormat::Opml => Box::new(OpmlExportWriter::new(
+            export_path,
+            podcast_feed_urls(ss, &user_id).await?,
+        )),
+    };
 
-    for export in ExportItem::iter() {
+    for export in items.iter().copied() {
         ryot_log!(debug, "Exporting {export}");
-        writer.name(&export.to_string())?;
-        writer.begin_array()?;
+        let writer = writer.as_mut();
+        writer.begin_item(export)?;
         match export {
-            ExportItem::People => export_people(ss, &user_id, &mut writer).await?,
-            ExportItem::Metadata => export_media(ss, &user_id, &mut writer).await?,
-            ExportItem::Workouts => export_workouts(ss, &user_id, &mut writer).await?,
-            ExportItem::Exercises => export_exercises(ss, &user_id, &mut writer).await?,
-            ExportItem::Collections => export_collections(ss, &user_id, &mut writer).await?,
-            ExportItem::Measurements => export_measurements(ss, &user_id, &mut writer).await?,
-            ExportItem::MetadataGroups => export_media_group(ss, &user_id, &mut writer).await?,
-            ExportItem::WorkoutTemplates => {
-                export_workout_templates(ss, &user_id, &mut writer).await?
+            ExportItem::Collections => export_collections(ss, &user_id, writer).await?,
+            ExportItem::People => export_people(ss, &user_id, date_range, writer).await?,
+            ExportItem::Metadata => export_media(ss, &user_id, date_range, writer).await?,
+            ExportItem::Workouts => export_workouts(ss, &user_id, date_range, writer).await?,
+            ExportItem::Exercises => export_exercises(ss, &user_id, date_range, writer).await?,
+            ExportItem::WorkoutTemplates => export_workout_templates(ss, &user_id, writer).await?,
+            ExportItem::Measurements => {
+                export_measurements(ss, &user_id, date_range, writer).await?
+            }
+            ExportItem::MetadataGroups => {
+                export_media_group(ss, &user_id, date_range, writer).await?
             }
         };
-        writer.end_array()?;
+        writer.end_item()?;
     }
-    writer.end_object()?;
-    writer.finish_document()?;
+    let export_path = writer.finish()?;
     ryot_log!(debug, "Exporting completed");
     let ended_at = Utc::now();
+    let mut metadata = HashMap::from([
+        ("format".to_string(), format.to_string()),
+        ("started_at".to_string(), started_at.to_rfc2822()),
+        ("ended_at".to_string(), ended_at.to_rfc2822()),
+        ("items".to_string(), items.iter().join(",")),
+        ("scheduled".to_string(), is_scheduled.to_string()),
+    ]);
+    if let Some(range) = date_range {
+        if let Some(start_date) = range.start_date {
+            metadata.insert("start_date".to_string(), start_date.to_string());
+        }
+        if let Some(end_date) = range.end_date {
+            metadata.insert("end_date".to_string(), end_date.to_string());
+        }
+    }
     let (_key, url) = file_storage_service::get_presigned_put_url(
         ss,
         format!("exports/{user_id}"),
         false,
-        Some(HashMap::from([
-            ("started_at".to_string(), started_at.to_rfc2822()),
-            ("ended_at".to_string(), ended_at.to_rfc2822()),
-        ])),
+        Some(metadata.clone()),
     )
     .await?;
     let file = File::open(&export_path).await?;
@@ -106,14 +206,16 @@ pub async fn perform_export(ss: &Arc<SupportingService>, user_id: String) -> Res
     let stream = FramedRead::new(file, BytesCodec::new());
     let body = Body::wrap_stream(stream);
     let client = Client::new();
-    client
+    let mut request = client
         .put(url)
         .header(CONTENT_TYPE, content_type.to_string())
-        .header(CONTENT_LENGTH, content_length)
-        .header("x-amz-meta-started_at", started_at.to_rfc2822())
-        .header("x-amz-meta-ended_at", ended_at.to_rfc2822())
-        .body(body)
-        .send()
-        .await?;
+        .header(CONTENT_LENGTH, content_length);
+    for (key, value) in metadata {
+        request = request.header(format!("x-amz-meta-{key}"), value);
+    }
+    request.body(body).send().await?;
+    if is_scheduled {
+        handle_export_completed(ss, &user_id, format).await?;
+    }
     Ok(())
 }
@@ -1,17 +1,28 @@
+use common_models::ApplicationDateRange;
 use media_models::{ImportOrExportItemRating, ImportOrExportItemReview, ReviewItem};
-use sea_orm::{EnumIter, strum::Display};
+use sea_orm::prelude::DateTimeUtc;
 
-#[derive(Eq, PartialEq, Copy, Display, Clone, Debug, EnumIter)]
-#[strum(serialize_all = "snake_case")]
-pub enum ExportItem {
-    People,
-    Workouts,
-    Metadata,
-    Exercises,
-    Collections,
-    Measurements,
-    MetadataGroups,
-    WorkoutTemplates,
+pub fn is_date_range_empty(range: &Option<ApplicationDateRange>) -> bool {
+    range
+        .as_ref()
+        .is_none_or(|r| r.start_date.is_none() && r.end_date.is_none())
+}
+
+/// Whether a date falls within the (inclusive) range. Items without a date are only
+/// included when no range has been specified.
+pub fn is_within_date_range(
+    range: &Option<ApplicationDateRange>,
+    date: Option<DateTimeUtc>,
+) -> bool {
+    if is_date_range_empty(range) {
+        return true;
+    }
+    let (Some(range), Some(date)) = (range, date) else {
+        return false;
+    };
+    let date = date.date_naive();
+    range.start_date.is_none_or(|start| date >= start)
+        && range.end_date.is_none_or(|end| date <= end)
 }
 
 pub fn get_review_export_item(rev: ReviewItem) -> ImportOrExportItemRating {
@@ -0,0 +1,652 @@
+use std::{
+    collections::HashMap,
+    fs::{File as StdFile, create_dir_all},
+    io::copy,
+    path::PathBuf,
+};
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use common_models::{DefaultCollection, ExportItem};
+use database_models::user_measurement;
+use dependent_models::{
+    ImportOrExportExerciseItem, ImportOrExportMetadataGroupItem, ImportOrExportMetadataItem,
+    ImportOrExportPersonItem, ImportOrExportWorkoutItem, ImportOrExportWorkoutTemplateItem,
+};
+use enum_models::{EntityLot, ExerciseLot, MediaLot, MediaSource, SeenState, Visibility};
+use fitness_models::SetLot;
+use itertools::Itertools;
+use media_models::{CollectionItem, ImportOrExportItemRating};
+use rust_decimal::{Decimal, dec};
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use struson::writer::{JsonStreamWriter, JsonWriter};
+use zip::{ZipWriter, write::SimpleFileOptions};
+
+/// A destination for the items gathered during an export. Each implementation decides
+/// how (and whether) an item is represented in the final file.
+pub trait ExportWriter: Send {
+    fn begin_item(&mut self, item: ExportItem) -> Result<()>;
+    fn end_item(&mut self) -> Result<()>;
+    fn write_person(&mut self, item: ImportOrExportPersonItem) -> Result<()>;
+    fn write_metadata(&mut self, item: ImportOrExportMetadataItem) -> Result<()>;
+    fn write_workout(&mut self, item: ImportOrExportWorkoutItem) -> Result<()>;
+    fn write_exercise(&mut self, item: ImportOrExportExerciseItem) -> Result<()>;
+    fn write_collection(&mut self, item: CollectionItem) -> Result<()>;
+    fn write_measurement(&mut self, item: user_measurement::Model) -> Result<()>;
+    fn write_metadata_group(&mut self, item: ImportOrExportMetadataGroupItem) -> Result<()>;
+    fn write_workout_template(&mut self, item: ImportOrExportWorkoutTemplateItem) -> Result<()>;
+    /// Complete the export and return the path of the file that should be uploaded.
+    fn finish(self: Box<Self>) -> Result<PathBuf>;
+}
+
+pub struct JsonExportWriter {
+    path: PathBuf,
+    writer: JsonStreamWriter<StdFile>,
+}
+
+impl JsonExportWriter {
+    pub fn new(path: PathBuf) -> Result<Self> {
+        let file = StdFile::create(&path)?;
+        let mut writer = JsonStreamWriter::new(file);
+        writer.begin_object()?;
+        Ok(Self { path, writer })
+    }
+}
+
+impl ExportWriter for JsonExportWriter {
+    fn begin_item(&mut self, item: ExportItem) -> Result<()> {
+        self.writer.name(&item.to_string())?;
+        self.writer.begin_array()?;
+        Ok(())
+    }
+
+    fn end_item(&mut self) -> Result<()> {
+        self.writer.end_array()?;
+        Ok(())
+    }
+
+    fn write_person(&mut self, item: ImportOrExportPersonItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_metadata(&mut self, item: ImportOrExportMetadataItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_workout(&mut self, item: ImportOrExportWorkoutItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_exercise(&mut self, item: ImportOrExportExerciseItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_collection(&mut self, item: CollectionItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_measurement(&mut self, item: user_measurement::Model) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_metadata_group(&mut self, item: ImportOrExportMetadataGroupItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn write_workout_template(&mut self, item: ImportOrExportWorkoutTemplateItem) -> Result<()> {
+        Ok(self.writer.serialize_value(&item)?)
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<PathBuf> {
+        self.writer.end_object()?;
+        self.writer.finish_document()?;
+        Ok(self.path)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct MetadataSeenRow {
+    lot: MediaLot,
+    title: String,
+    source: MediaSource,
+    identifier: String,
+    state: Option<SeenState>,
+    progress: Option<Decimal>,
+    ended_on: Option<DateTimeUtc>,
+    started_on: Option<DateTimeUtc>,
+    show_season_number: Option<i32>,
+    show_episode_number: Option<i32>,
+    manga_volume_number: Option<i32>,
+    anime_episode_number: Option<i32>,
+    manual_time_spent: Option<Decimal>,
+    podcast_episode_number: Option<i32>,
+    manga_chapter_number: Option<Decimal>,
+    providers_consumed_on: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewRow {
+    title: String,
+    identifier: String,
+    entity_lot: EntityLot,
+    lot: Option<MediaLot>,
+    rating: Option<Decimal>,
+    text: Option<String>,
+    source: Option<MediaSource>,
+    is_spoiler: Option<bool>,
+    posted_on: Option<DateTimeUtc>,
+    visibility: Option<Visibility>,
+    show_season_number: Option<i32>,
+    show_episode_number: Option<i32>,
+    anime_episode_number: Option<i32>,
+    podcast_episode_number: Option<i32>,
+    manga_chapter_number: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkoutSetRow {
+    workout_id: String,
+    workout_name: String,
+    start_time: DateTimeUtc,
+    end_time: DateTimeUtc,
+    exercise_idx: usize,
+    exercise_id: String,
+    exercise_lot: ExerciseLot,
+    set_idx: usize,
+    set_lot: SetLot,
+    rpe: Option<u8>,
+    reps: Option<Decimal>,
+    pace: Option<Decimal>,
+    note: Option<String>,
+    weight: Option<Decimal>,
+    one_rm: Option<Decimal>,
+    volume: Option<Decimal>,
+    rest_time: Option<u16>,
+    duration: Option<Decimal>,
+    distance: Option<Decimal>,
+    confirmed_at: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Serialize)]
+struct MeasurementRow {
+    timestamp: DateTimeUtc,
+    name: Option<String>,
+    comment: Option<String>,
+    statistic: String,
+    value: Decimal,
+}
+
+const METADATA_SEEN_FILE: &str = "metadata_seen_history.csv";
+const REVIEWS_FILE: &str = "reviews.csv";
+const WORKOUT_SETS_FILE: &str = "workout_sets.csv";
+const MEASUREMENTS_FILE: &str = "measurements.csv";
+
+/// Writes one CSV file per entity type into a temporary directory and bundles them
+/// into a zip archive once the export is complete. Items that do not have a tabular
+/// representation (collections, workout templates) are skipped.
+pub struct CsvExportWriter {
+    path: PathBuf,
+    directory: PathBuf,
+    writers: HashMap<&'static str, csv::Writer<StdFile>>,
+}
+
+impl CsvExportWriter {
+    pub fn new(path: PathBuf) -> Result<Self> {
+        let directory = path.with_extension("d");
+        create_dir_all(&directory)?;
+        Ok(Self {
+            path,
+            directory,
+            writers: HashMap::new(),
+        })
+    }
+
+    fn serialize_row<T: Serialize>(&mut self, file_name: &'static str, row: T) -> Result<()> {
+        if !self.writers.contains_key(file_name) {
+            let writer = csv::Writer::from_path(self.directory.join(file_name))?;
+            self.writers.insert(file_name, writer);
+        }
+        self.writers.get_mut(file_name).unwrap().serialize(row)?;
+        Ok(())
+    }
+
+    fn write_reviews(
+        &mut self,
+        reviews: Vec<ImportOrExportItemRating>,
+        title: &str,
+        identifier: &str,
+        entity_lot: EntityLot,
+        lot: Option<MediaLot>,
+        source: Option<MediaSource>,
+    ) -> Result<()> {
+        for rating in reviews {
+            let review = rating.review.unwrap_or_default();
+            self.serialize_row(
+                REVIEWS_FILE,
+                ReviewRow {
+                    lot,
+                    source,
+                    entity_lot,
+                    text: review.text,
+                    rating: rating.rating,
+                    posted_on: review.date,
+                    title: title.to_owned(),
+                    is_spoiler: review.spoiler,
+                    visibility: review.visibility,
+                    identifier: identifier.to_owned(),
+                    show_season_number: rating.show_season_number,
+                    show_episode_number: rating.show_episode_number,
+                    anime_episode_number: rating.anime_episode_number,
+                    manga_chapter_number: rating.manga_chapter_number,
+                    podcast_episode_number: rating.podcast_episode_number,
+                },
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ExportWriter for CsvExportWriter {
+    fn begin_item(&mut self, _item: ExportItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn end_item(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_person(&mut self, item: ImportOrExportPersonItem) -> Result<()> {
+        self.write_reviews(
+            item.reviews,
+            &item.name,
+            &item.identifier,
+            EntityLot::Person,
+            None,
+            Some(item.source),
+        )
+    }
+
+    fn write_metadata(&mut self, item: ImportOrExportMetadataItem) -> Result<()> {
+        for seen in item.seen_history {
+            self.serialize_row(
+                METADATA_SEEN_FILE,
+                MetadataSeenRow {
+                    lot: item.lot,
+                    state: seen.state,
+                    source: item.source,
+                    progress: seen.progress,
+                    ended_on: seen.ended_on,
+                    started_on: seen.started_on,
+                    title: item.source_id.clone(),
+                    identifier: item.identifier.clone(),
+                    manual_time_spent: seen.manual_time_spent,
+                    show_season_number: seen.show_season_number,
+                    show_episode_number: seen.show_episode_number,
+                    manga_volume_number: seen.manga_volume_number,
+                    anime_episode_number: seen.anime_episode_number,
+                    manga_chapter_number: seen.manga_chapter_number,
+                    podcast_episode_number: seen.podcast_episode_number,
+                    providers_consumed_on: seen.providers_consumed_on.map(|p| p.join(", ")),
+                },
+            )?;
+        }
+        self.write_reviews(
+            item.reviews,
+            &item.source_id,
+            &item.identifier,
+            EntityLot::Metadata,
+            Some(item.lot),
+            Some(item.source),
+        )
+    }
+
+    fn write_workout(&mut self, item: ImportOrExportWorkoutItem) -> Result<()> {
+        let workout = item.details;
+        for (exercise_idx, exercise) in workout.information.exercises.into_iter().enumerate() {
+            for (set_idx, set) in exercise.sets.into_iter().enumerate() {
+                self.serialize_row(
+                    WORKOUT_SETS_FILE,
+                    WorkoutSetRow {
+                        set_idx,
+                        exercise_idx,
+                        rpe: set.rpe,
+                        note: set.note,
+                        set_lot: set.lot,
+                        rest_time: set.rest_time,
+                        exercise_lot: exercise.lot,
+                        reps: set.statistic.reps,
+                        pace: set.statistic.pace,
+                        end_time: workout.end_time,
+                        confirmed_at: set.confirmed_at,
+                        weight: set.statistic.weight,
+                        one_rm: set.statistic.one_rm,
+                        volume: set.statistic.volume,
+                        start_time: workout.start_time,
+                        exercise_id: exercise.id.clone(),
+                        workout_id: workout.id.clone(),
+                        duration: set.statistic.duration,
+                        distance: set.statistic.distance,
+                        workout_name: workout.name.clone(),
+                    },
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_exercise(&mut self, item: ImportOrExportExerciseItem) -> Result<()> {
+        self.write_reviews(
+            item.reviews,
+            &item.name,
+            &item.id,
+            EntityLot::Exercise,
+            None,
+            None,
+        )
+    }
+
+    fn write_collection(&mut self, _item: CollectionItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_measurement(&mut self, item: user_measurement::Model) -> Result<()> {
+        for statistic in item.information.statistics {
+            self.serialize_row(
+                MEASUREMENTS_FILE,
+                MeasurementRow {
+                    value: statistic.value,
+                    name: item.name.clone(),
+                    timestamp: item.timestamp,
+                    statistic: statistic.name,
+                    comment: item.comment.clone(),
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_metadata_group(&mut self, item: ImportOrExportMetadataGroupItem) -> Result<()> {
+        self.write_reviews(
+            item.reviews,
+            &item.title,
+            &item.identifier,
+            EntityLot::MetadataGroup,
+            Some(item.lot),
+            Some(item.source),
+        )
+    }
+
+    fn write_workout_template(&mut self, _item: ImportOrExportWorkoutTemplateItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let mut zip = ZipWriter::new(StdFile::create(&self.path)?);
+        let mut file_names = self.writers.keys().copied().collect::<Vec<_>>();
+        file_names.sort();
+        for (_, mut writer) in self.writers {
+            writer.flush()?;
+        }
+        for file_name in file_names {
+            zip.start_file(file_name, SimpleFileOptions::default())?;
+            let mut file = StdFile::open(self.directory.join(file_name))?;
+            copy(&mut file, &mut zip)?;
+        }
+        zip.finish()?;
+        std::fs::remove_dir_all(&self.directory)?;
+        Ok(self.path)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LetterboxdRow {
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "tmdbID")]
+    tmdb_id: String,
+    #[serde(rename = "WatchedDate")]
+    watched_date: Option<NaiveDate>,
+    #[serde(rename = "Rating")]
+    rating: Option<Decimal>,
+    #[serde(rename = "Rewatch")]
+    rewatch: bool,
+    #[serde(rename = "Review")]
+    review: Option<String>,
+}
+
+/// Writes movies from TMDB in the CSV format accepted by Letterboxd's importer. Each
+/// completed seen entry becomes a diary entry and reviews are attached to the entry
+/// watched on the same day. Everything else is skipped.
+pub struct LetterboxdExportWriter {
+    path: PathBuf,
+    writer: csv::Writer<StdFile>,
+}
+
+impl LetterboxdExportWriter {
+    pub fn new(path: PathBuf) -> Result<Self> {
+        let writer = csv::Writer::from_path(&path)?;
+        Ok(Self { path, writer })
+    }
+}
+
+impl ExportWriter for LetterboxdExportWriter {
+    fn begin_item(&mut self, _item: ExportItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn end_item(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_person(&mut self, _item: ImportOrExportPersonItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_metadata(&mut self, item: ImportOrExportMetadataItem) -> Result<()> {
+        if item.lot != MediaLot::Movie || item.source != MediaSource::Tmdb {
+            return Ok(());
+        }
+        // DEV: Letterboxd rates items out of 5 in steps of 0.5
+        let rating = item
+            .reviews
+            .iter()
+            .filter(|r| r.rating.is_some())
+            .max_by_key(|r| r.review.as_ref().and_then(|r| r.date))
+            .and_then(|r| r.rating)
+            .map(|r| (r / dec!(10)).round() / dec!(2))
+            .filter(|r| !r.is_zero());
+        let mut reviews = item
+            .reviews
+            .into_iter()
+            .filter_map(|r| r.review)
+            .filter_map(|r| Some((r.date.map(|d| d.date_naive()), r.text?)))
+            .collect_vec();
+        let watched_dates = item
+            .seen_history
+            .into_iter()
+            .filter_map(|s| s.ended_on.map(|d| d.date_naive()))
+            .sorted()
+            .collect_vec();
+        let mut rows = vec![];
+        for (idx, watched_date) in watched_dates.into_iter().enumerate() {
+            let review = reviews
+                .iter()
+                .position(|(date, _)| *date == Some(watched_date))
+                .map(|pos| reviews.remove(pos).1);
+            rows.push((Some(watched_date), idx > 0, review));
+        }
+        rows.extend(
+            reviews
+                .into_iter()
+                .map(|(date, text)| (date, false, Some(text))),
+        );
+        if rows.is_empty() && rating.is_some() {
+            rows.push((None, false, None));
+        }
+        for (watched_date, rewatch, review) in rows {
+            self.writer.serialize(LetterboxdRow {
+                rating,
+                review,
+                rewatch,
+                watched_date,
+                title: item.source_id.clone(),
+                tmdb_id: item.identifier.clone(),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn write_workout(&mut self, _item: ImportOrExportWorkoutItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_exercise(&mut self, _item: ImportOrExportExerciseItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_collection(&mut self, _item: CollectionItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_measurement(&mut self, _item: user_measurement::Model) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_metadata_group(&mut self, _item: ImportOrExportMetadataGroupItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_workout_template(&mut self, _item: ImportOrExportWorkoutTemplateItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<PathBuf> {
+        self.writer.flush()?;
+        Ok(self.path)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OpmlOutline {
+    #[serde(rename = "@type")]
+    lot: &'static str,
+    #[serde(rename = "@text")]
+    text: String,
+    #[serde(rename = "@title")]
+    title: String,
+    #[serde(rename = "@xmlUrl")]
+    xml_url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OpmlHead {
+    title: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct OpmlBody {
+    #[serde(rename = "outline")]
+    outlines: Vec<OpmlOutline>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "opml")]
+struct Opml {
+    #[serde(rename = "@version")]
+    version: &'static str,
+    head: OpmlHead,
+    body: OpmlBody,
+}
+
+/// Writes podcasts that are being monitored or are in progress as an OPML file that can
+/// be imported by podcast apps. Podcasts whose feed URL is not known yet are skipped.
+pub struct OpmlExportWriter {
+    path: PathBuf,
+    outlines: Vec<OpmlOutline>,
+    feed_urls: HashMap<(MediaSource, String), String>,
+}
+
+impl OpmlExportWriter {
+    pub fn new(path: PathBuf, feed_urls: HashMap<(MediaSource, String), String>) -> Self {
+        Self {
+            path,
+            feed_urls,
+            outlines: vec![],
+        }
+    }
+}
+
+impl ExportWriter for OpmlExportWriter {
+    fn begin_item(&mut self, _item: ExportItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn end_item(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_person(&mut self, _item: ImportOrExportPersonItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_metadata(&mut self, item: ImportOrExportMetadataItem) -> Result<()> {
+        let subscribed =
+            [DefaultCollection::Monitoring, DefaultCollection::InProgress].map(|c| c.to_string());
+        if item.lot != MediaLot::Podcast
+            || !item
+                .collections
+                .iter()
+                .any(|c| subscribed.contains(&c.collection_name))
+        {
+            return Ok(());
+        }
+        if let Some(xml_url) = self.feed_urls.remove(&(item.source, item.identifier)) {
+            self.outlines.push(OpmlOutline {
+                xml_url,
+                lot: "rss",
+                text: item.source_id.clone(),
+                title: item.source_id,
+            });
+        }
+        Ok(())
+    }
+
+    fn write_workout(&mut self, _item: ImportOrExportWorkoutItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_exercise(&mut self, _item: ImportOrExportExerciseItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_collection(&mut self, _item: CollectionItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_measurement(&mut self, _item: user_measurement::Model) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_metadata_group(&mut self, _item: ImportOrExportMetadataGroupItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_workout_template(&mut self, _item: ImportOrExportWorkoutTemplateItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let opml = Opml {
+            version: "2.0",
+            head: OpmlHead {
+                title: "Ryot Podcast Subscriptions",
+            },
+            body: OpmlBody {
+                outlines: self.outlines,
+            },
+        };
+        std::fs::write(&self.path, serde_xml_rs::to_string(&opml)?)?;
+        Ok(self.path)
+    }
+}
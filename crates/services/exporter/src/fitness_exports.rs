@@ -1,7 +1,7 @@
-use std::{fs::File as StdFile, sync::Arc};
+use std::sync::Arc;
 
 use anyhow::{Result, anyhow};
-use common_models::SearchInput;
+use common_models::{ApplicationDateRange, SearchInput};
 use common_utils::ryot_log;
 use database_models::prelude::Exercise;
 use database_utils::{
@@ -19,15 +19,18 @@ use enum_models::EntityLot;
 use fitness_models::{UserExercisesListInput, UserMeasurementsListInput};
 use itertools::Itertools;
 use sea_orm::EntityTrait;
-use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
 
-use crate::export_utilities::get_review_export_item;
+use crate::{
+    export_utilities::{get_review_export_item, is_date_range_empty, is_within_date_range},
+    export_writers::ExportWriter,
+};
 
 pub async fn export_workouts(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -47,6 +50,9 @@ pub async fn export_workouts(
         ryot_log!(debug, "Exporting workouts list page: {current_page}");
         for workout_id in workout_ids.response.items {
             let details = user_workout_details(user_id, workout_id, ss).await?;
+            if !is_within_date_range(date_range, Some(details.response.details.start_time)) {
+                continue;
+            }
             let exp = ImportOrExportWorkoutItem {
                 details: details.response.details,
                 collections: details
@@ -56,7 +62,7 @@ pub async fn export_workouts(
                     .map(|c| c.details)
                     .collect(),
             };
-            writer.serialize_value(&exp)?;
+            writer.write_workout(exp)?;
         }
         if let Some(next_page) = workout_ids.response.details.next_page {
             current_page = next_page;
@@ -70,12 +76,15 @@ pub async fn export_workouts(
 pub async fn export_measurements(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let measurements =
         user_measurements_list(user_id, ss, UserMeasurementsListInput::default()).await?;
     for measurement in measurements.response {
-        writer.serialize_value(&measurement)?;
+        if is_within_date_range(date_range, Some(measurement.timestamp)) {
+            writer.write_measurement(measurement)?;
+        }
     }
     Ok(())
 }
@@ -83,7 +92,8 @@ pub async fn export_measurements(
 pub async fn export_exercises(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -104,6 +114,7 @@ pub async fn export_exercises(
             let reviews = item_reviews(user_id, &exercise_id, EntityLot::Exercise, false, ss)
                 .await?
                 .into_iter()
+                .filter(|r| is_within_date_range(date_range, Some(r.posted_on)))
                 .map(get_review_export_item)
                 .collect_vec();
             let collections =
@@ -112,7 +123,7 @@ pub async fn export_exercises(
                     .into_iter()
                     .map(|c| c.details)
                     .collect_vec();
-            if reviews.is_empty() && collections.is_empty() {
+            if reviews.is_empty() && (collections.is_empty() || !is_date_range_empty(date_range)) {
                 continue;
             }
             let exercise = Exercise::find_by_id(exercise_id.clone())
@@ -125,7 +136,7 @@ pub async fn export_exercises(
                 id: exercise_id,
                 name: exercise.name,
             };
-            writer.serialize_value(&exp)?;
+            writer.write_exercise(exp)?;
         }
         if let Some(next_page) = exercises.response.details.next_page {
             current_page = next_page;
@@ -139,7 +150,7 @@ pub async fn export_exercises(
 pub async fn export_workout_templates(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -168,7 +179,7 @@ pub async fn export_workout_templates(
                     .map(|c| c.details)
                     .collect(),
             };
-            writer.serialize_value(&exp)?;
+            writer.write_workout_template(exp)?;
         }
         if let Some(next_page) = workout_template_ids.response.details.next_page {
             current_page = next_page;
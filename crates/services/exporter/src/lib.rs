@@ -1,5 +1,10 @@
 mod collection_exports;
 pub mod export_operations;
 mod export_utilities;
+mod export_writers;
 mod fitness_exports;
 mod media_exports;
+pub mod scheduled_exports;
+
+#[cfg(test)]
+mod tests;
@@ -1,8 +1,9 @@
-use std::{fs::File as StdFile, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
-use common_models::SearchInput;
+use common_models::{ApplicationDateRange, SearchInput};
 use common_utils::ryot_log;
+use database_models::prelude::RssFeed;
 use database_utils::{entity_in_collections_with_details, item_reviews};
 use dependent_details_utils::{metadata_details, metadata_group_details, person_details};
 use dependent_entity_list_utils::{
@@ -12,18 +13,24 @@ use dependent_models::{
     ImportOrExportMetadataGroupItem, ImportOrExportMetadataItem, ImportOrExportPersonItem,
     UserMetadataGroupsListInput, UserMetadataListInput, UserPeopleListInput,
 };
+use dependent_provider_utils::details_from_provider;
 use dependent_seen_utils::metadata_seen_history;
-use enum_models::EntityLot;
+use enum_models::{EntityLot, MediaLot, MediaSource};
+use itertools::Itertools;
 use media_models::ImportOrExportMetadataItemSeen;
-use struson::writer::{JsonStreamWriter, JsonWriter};
+use sea_orm::EntityTrait;
 use supporting_service::SupportingService;
 
-use crate::export_utilities::get_review_export_item;
+use crate::{
+    export_utilities::{get_review_export_item, is_date_range_empty, is_within_date_range},
+    export_writers::ExportWriter,
+};
 
 pub async fn export_media(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -46,6 +53,7 @@ pub async fn export_media(
             let seen_history = metadata_seen_history(user_id, &m.id, ss).await?;
             let seen_history = seen_history
                 .into_iter()
+                .filter(|s| is_within_date_range(date_range, s.finished_on.or(s.started_on)))
                 .map(|s| {
                     let (show_season_number, show_episode_number) = match s.show_extra_information {
                         Some(d) => (Some(d.season), Some(d.episode)),
@@ -56,12 +64,19 @@ pub async fn export_media(
                     let manga_chapter_number =
                         s.manga_extra_information.clone().and_then(|d| d.chapter);
                     let manga_volume_number = s.manga_extra_information.and_then(|d| d.volume);
+                    let (board_game_player_count, board_game_winner) =
+                        match s.board_game_extra_information {
+                            Some(d) => (d.player_count, d.winner),
+                            None => (None, None),
+                        };
                     ImportOrExportMetadataItemSeen {
                         show_season_number,
                         show_episode_number,
                         manga_volume_number,
                         anime_episode_number,
                         manga_chapter_number,
+                        board_game_winner,
+                        board_game_player_count,
                         state: Some(s.state),
                         podcast_episode_number,
                         ended_on: s.finished_on,
@@ -71,12 +86,16 @@ pub async fn export_media(
                         providers_consumed_on: Some(s.providers_consumed_on),
                     }
                 })
-                .collect();
+                .collect_vec();
             let reviews = item_reviews(user_id, &m.id, EntityLot::Metadata, false, ss)
                 .await?
                 .into_iter()
+                .filter(|r| is_within_date_range(date_range, Some(r.posted_on)))
                 .map(get_review_export_item)
-                .collect();
+                .collect_vec();
+            if !is_date_range_empty(date_range) && seen_history.is_empty() && reviews.is_empty() {
+                continue;
+            }
             let collections =
                 entity_in_collections_with_details(user_id, &m.id, EntityLot::Metadata, ss)
                     .await?
@@ -92,7 +111,7 @@ pub async fn export_media(
                 source_id: m.title,
                 identifier: m.identifier.clone(),
             };
-            writer.serialize_value(&exp)?;
+            writer.write_metadata(exp)?;
         }
         if let Some(next_page) = related_metadata.response.details.next_page {
             current_page = next_page;
@@ -103,10 +122,64 @@ pub async fn export_media(
     Ok(())
 }
 
+/// Feed URLs are not a part of the exported items, so they are gathered for all the
+/// podcasts of the user before an OPML export is written. Podcasts tracked by their feed
+/// are identified by a hash of its URL, so the URL is looked up in `rss_feed`. Podcasts
+/// stored before feed URLs were tracked do not have one, so it is fetched from their
+/// provider instead.
+pub async fn podcast_feed_urls(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<HashMap<(MediaSource, String), String>> {
+    let mut feed_urls = HashMap::new();
+    let mut current_page = 1;
+    loop {
+        let related_metadata = user_metadata_list(
+            user_id,
+            UserMetadataListInput {
+                lot: Some(MediaLot::Podcast),
+                search: Some(SearchInput {
+                    take: Some(1000),
+                    page: Some(current_page),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ss,
+        )
+        .await?;
+        for rm in related_metadata.response.items.iter() {
+            let m = metadata_details(ss, rm).await?.response;
+            let feed_url = match (m.source, m.podcast_specifics.and_then(|p| p.feed_url)) {
+                (MediaSource::Rss, _) => RssFeed::find_by_id(&m.identifier)
+                    .one(&ss.db)
+                    .await?
+                    .map(|f| f.url),
+                (_, Some(feed_url)) => Some(feed_url),
+                (_, None) => details_from_provider(m.lot, m.source, &m.identifier, ss)
+                    .await
+                    .ok()
+                    .and_then(|d| d.podcast_specifics)
+                    .and_then(|p| p.feed_url),
+            };
+            if let Some(feed_url) = feed_url {
+                feed_urls.insert((m.source, m.identifier), feed_url);
+            }
+        }
+        if let Some(next_page) = related_metadata.response.details.next_page {
+            current_page = next_page;
+        } else {
+            break;
+        }
+    }
+    Ok(feed_urls)
+}
+
 pub async fn export_media_group(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -129,8 +202,12 @@ pub async fn export_media_group(
             let reviews = item_reviews(user_id, &m.id, EntityLot::MetadataGroup, false, ss)
                 .await?
                 .into_iter()
+                .filter(|r| is_within_date_range(date_range, Some(r.posted_on)))
                 .map(get_review_export_item)
-                .collect();
+                .collect_vec();
+            if !is_date_range_empty(date_range) && reviews.is_empty() {
+                continue;
+            }
             let collections =
                 entity_in_collections_with_details(user_id, &m.id, EntityLot::MetadataGroup, ss)
                     .await?
@@ -145,7 +222,7 @@ pub async fn export_media_group(
                 source: m.source,
                 identifier: m.identifier.clone(),
             };
-            writer.serialize_value(&exp)?;
+            writer.write_metadata_group(exp)?;
         }
         if let Some(next_page) = related_metadata.response.details.next_page {
             current_page = next_page;
@@ -159,7 +236,8 @@ pub async fn export_media_group(
 pub async fn export_people(
     ss: &Arc<SupportingService>,
     user_id: &String,
-    writer: &mut JsonStreamWriter<StdFile>,
+    date_range: &Option<ApplicationDateRange>,
+    writer: &mut dyn ExportWriter,
 ) -> Result<()> {
     let mut current_page = 1;
     loop {
@@ -182,8 +260,12 @@ pub async fn export_people(
             let reviews = item_reviews(user_id, &p.id, EntityLot::Person, false, ss)
                 .await?
                 .into_iter()
+                .filter(|r| is_within_date_range(date_range, Some(r.posted_on)))
                 .map(get_review_export_item)
-                .collect();
+                .collect_vec();
+            if !is_date_range_empty(date_range) && reviews.is_empty() {
+                continue;
+            }
             let collections =
                 entity_in_collections_with_details(user_id, &p.id, EntityLot::Person, ss)
                     .await?
@@ -198,7 +280,7 @@ pub async fn export_people(
                 identifier: p.identifier,
                 source_specifics: p.source_specifics,
             };
-            writer.serialize_value(&exp)?;
+            writer.write_person(exp)?;
         }
         if let Some(next_page) = related_people.response.details.next_page {
             current_page = next_page;
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use background_models::{ApplicationJob, MpApplicationJob};
+use chrono::{Days, Months, NaiveDate, Utc};
+use common_models::{DeployExportJobInput, ExportFormat, ExportJob};
+use common_utils::ryot_log;
+use database_models::user;
+use database_utils::{get_enabled_users_query, user_by_id};
+use dependent_notification_utils::send_notification_for_user;
+use enum_models::UserNotificationContent;
+use itertools::Itertools;
+use sea_orm::{ActiveModelTrait, ActiveValue, IntoActiveModel, QueryFilter, prelude::Expr};
+use supporting_service::SupportingService;
+use user_models::{UserExportSchedule, UserExportScheduleFrequency};
+
+use crate::export_operations::{user_exports, validate_export_input};
+
+fn is_export_due(schedule: &UserExportSchedule, today: NaiveDate) -> bool {
+    let Some(last_triggered_on) = schedule.last_triggered_on else {
+        return true;
+    };
+    let next_run_on = match schedule.frequency {
+        UserExportScheduleFrequency::Daily => last_triggered_on.checked_add_days(Days::new(1)),
+        UserExportScheduleFrequency::Weekly => last_triggered_on.checked_add_days(Days::new(7)),
+        UserExportScheduleFrequency::Monthly => {
+            last_triggered_on.checked_add_months(Months::new(1))
+        }
+    };
+    next_run_on.is_none_or(|date| date <= today)
+}
+
+/// The keys of the scheduled exports that fall outside the retention window. Exports
+/// the user started by hand are never pruned.
+pub(crate) fn exports_to_prune(exports: Vec<ExportJob>, retention_count: usize) -> Vec<String> {
+    exports
+        .into_iter()
+        .filter(|export| export.is_scheduled)
+        .sorted_by(|a, b| b.ended_at.cmp(&a.ended_at))
+        .skip(retention_count)
+        .map(|export| export.key)
+        .collect()
+}
+
+pub async fn update_user_export_schedule(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    schedule: Option<UserExportSchedule>,
+) -> Result<bool> {
+    if let Some(schedule) = &schedule {
+        if schedule
+            .items
+            .as_ref()
+            .is_some_and(|items| items.is_empty())
+        {
+            bail!("At least one item needs to be selected for the export");
+        }
+        if schedule.retention_count == Some(0) {
+            bail!("At least one export needs to be retained");
+        }
+    }
+    let user = user_by_id(&user_id, ss).await?;
+    let mut extra_information = user.extra_information.clone().unwrap_or_default();
+    let last_triggered_on = extra_information
+        .export_schedule
+        .and_then(|s| s.last_triggered_on);
+    extra_information.export_schedule = schedule.map(|s| UserExportSchedule {
+        last_triggered_on,
+        ..s
+    });
+    let mut user = user.into_active_model();
+    user.extra_information = ActiveValue::Set(Some(extra_information));
+    user.update(&ss.db).await?;
+    Ok(true)
+}
+
+async fn deploy_scheduled_export_job(
+    ss: &Arc<SupportingService>,
+    user: user::Model,
+    today: NaiveDate,
+) -> Result<()> {
+    let mut extra_information = user.extra_information.clone().unwrap_or_default();
+    let Some(schedule) = extra_information.export_schedule.as_mut() else {
+        return Ok(());
+    };
+    if !is_export_due(schedule, today) {
+        return Ok(());
+    }
+    ryot_log!(debug, "Deploying scheduled export for {}", user.id);
+    let input = DeployExportJobInput {
+        date_range: None,
+        format: Some(schedule.format),
+        items: schedule.items.clone(),
+    };
+    validate_export_input(&input)?;
+    ss.perform_application_job(ApplicationJob::Mp(
+        MpApplicationJob::PerformScheduledExport(user.id.clone(), input),
+    ))
+    .await?;
+    schedule.last_triggered_on = Some(today);
+    let mut user = user.into_active_model();
+    user.extra_information = ActiveValue::Set(Some(extra_information));
+    user.update(&ss.db).await?;
+    Ok(())
+}
+
+pub async fn deploy_scheduled_export_jobs(ss: &Arc<SupportingService>) -> Result<()> {
+    if !ss.config.file_storage.is_enabled() {
+        return Ok(());
+    }
+    let users = get_enabled_users_query()
+        .filter(Expr::cust(
+            "jsonb_typeof(extra_information -> 'export_schedule') = 'object'",
+        ))
+        .all(&ss.db)
+        .await?;
+    let today = Utc::now().date_naive();
+    for user in users {
+        let user_id = user.id.clone();
+        // One broken schedule should not keep the exports of other users from running.
+        if let Err(err) = deploy_scheduled_export_job(ss, user, today).await {
+            ryot_log!(
+                warn,
+                "Could not deploy scheduled export for {}: {:?}",
+                user_id,
+                err
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Apply the retention and notification settings of the user's export schedule once a
+/// scheduled export has been uploaded.
+pub async fn handle_export_completed(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    format: ExportFormat,
+) -> Result<()> {
+    let user = user_by_id(user_id, ss).await?;
+    let Some(schedule) = user.extra_information.and_then(|e| e.export_schedule) else {
+        return Ok(());
+    };
+    if let Some(retention_count) = schedule.retention_count {
+        let exports = user_exports(ss, user_id.clone()).await?;
+        for key in exports_to_prune(exports, retention_count) {
+            ryot_log!(debug, "Deleting old scheduled export {}", key);
+            file_storage_service::delete_object(ss, key).await?;
+        }
+    }
+    if schedule.notify_on_completion {
+        send_notification_for_user(
+            user_id,
+            ss,
+            UserNotificationContent::ExportCompleted {
+                format: format.to_string(),
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
@@ -0,0 +1 @@
+mod scheduled_exports;
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Duration, Utc};
+use common_models::{ExportFormat, ExportJob};
+
+use crate::scheduled_exports::exports_to_prune;
+
+fn export(key: &str, days_ago: i64, is_scheduled: bool) -> ExportJob {
+    let ended_at = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap()
+        - Duration::days(days_ago);
+    ExportJob {
+        size: 1,
+        is_scheduled,
+        items: vec![],
+        date_range: None,
+        started_at: ended_at,
+        key: key.to_string(),
+        format: ExportFormat::Json,
+        url: format!("https://ryot.example.com/{key}"),
+        ended_at,
+    }
+}
+
+#[test]
+fn test_manual_exports_survive_pruning() {
+    let exports = vec![
+        export("scheduled-newest", 0, true),
+        export("manual-newest", 1, false),
+        export("scheduled-middle", 2, true),
+        export("manual-oldest", 3, false),
+        export("scheduled-oldest", 4, true),
+    ];
+
+    let pruned = exports_to_prune(exports, 1);
+    assert_eq!(pruned, vec!["scheduled-middle", "scheduled-oldest"]);
+}
+
+#[test]
+fn test_nothing_is_pruned_within_retention() {
+    let exports = vec![
+        export("scheduled-newest", 0, true),
+        export("manual", 1, false),
+        export("scheduled-oldest", 2, true),
+    ];
+
+    assert!(exports_to_prune(exports, 2).is_empty());
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput, FileStorageTokenValue,
+};
+use supporting_service::SupportingService;
+use tokio::fs;
+use uuid::Uuid;
+
+fn storage_directory(ss: &Arc<SupportingService>) -> PathBuf {
+    PathBuf::from(&ss.config.file_storage.local_directory)
+}
+
+fn objects_directory(ss: &Arc<SupportingService>) -> PathBuf {
+    storage_directory(ss).join("objects")
+}
+
+fn metadata_directory(ss: &Arc<SupportingService>) -> PathBuf {
+    storage_directory(ss).join("metadata")
+}
+
+/// Make sure the key can not be used to escape the storage directory.
+fn validated_key(key: &str) -> Result<&Path> {
+    let path = Path::new(key);
+    if key.is_empty() || !path.components().all(|c| matches!(c, Component::Normal(_))) {
+        bail!("Invalid file storage key: {key}");
+    }
+    Ok(path)
+}
+
+fn object_path(ss: &Arc<SupportingService>, key: &str) -> Result<PathBuf> {
+    Ok(objects_directory(ss).join(validated_key(key)?))
+}
+
+fn metadata_path(ss: &Arc<SupportingService>, key: &str) -> Result<PathBuf> {
+    let path = metadata_directory(ss).join(validated_key(key)?);
+    Ok(path.with_file_name(format!(
+        "{}.json",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    )))
+}
+
+fn token_url(ss: &Arc<SupportingService>, action: &str, token: &str) -> String {
+    format!("{}/backend/files/{action}/{token}", ss.config.frontend.url)
+}
+
+pub async fn is_enabled(ss: &Arc<SupportingService>) -> bool {
+    fs::create_dir_all(objects_directory(ss)).await.is_ok()
+        && fs::create_dir_all(metadata_directory(ss)).await.is_ok()
+}
+
+pub async fn get_presigned_url(ss: &Arc<SupportingService>, key: String) -> Result<String> {
+    validated_key(&key)?;
+    let token = Uuid::new_v4().to_string();
+    cache_service::set_key(
+        ss,
+        ApplicationCacheKey::FileStorageDownloadToken(token.clone()),
+        ApplicationCacheValue::FileStorageDownloadToken(FileStorageTokenValue {
+            key,
+            metadata: None,
+        }),
+    )
+    .await?;
+    Ok(token_url(ss, "download", &token))
+}
+
+pub async fn delete_object(ss: &Arc<SupportingService>, key: String) -> Result<bool> {
+    let response = fs::remove_file(object_path(ss, &key)?).await.is_ok();
+    fs::remove_file(metadata_path(ss, &key)?).await.ok();
+    Ok(response)
+}
+
+pub async fn get_presigned_put_url(
+    ss: &Arc<SupportingService>,
+    key: &str,
+    metadata: Option<HashMap<String, String>>,
+) -> Result<String> {
+    validated_key(key)?;
+    let token = Uuid::new_v4().to_string();
+    cache_service::set_key(
+        ss,
+        ApplicationCacheKey::FileStorageUploadToken(token.clone()),
+        ApplicationCacheValue::FileStorageUploadToken(FileStorageTokenValue {
+            metadata,
+            key: key.to_owned(),
+        }),
+    )
+    .await?;
+    Ok(token_url(ss, "upload", &token))
+}
+
+pub async fn list_objects_at_prefix(
+    ss: &Arc<SupportingService>,
+    prefix: String,
+) -> Result<Vec<(i64, String)>> {
+    let root = objects_directory(ss);
+    let mut items = vec![];
+    let mut pending = vec![root.clone()];
+    while let Some(directory) = pending.pop() {
+        let Ok(mut entries) = fs::read_dir(&directory).await else {
+            continue;
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+                continue;
+            }
+            let path = entry.path();
+            let Ok(relative) = path.strip_prefix(&root) else {
+                continue;
+            };
+            let key = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if key.starts_with(&prefix) {
+                let size = entry.metadata().await?.len();
+                items.push((size.try_into().unwrap_or_default(), key));
+            }
+        }
+    }
+    items.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(items)
+}
+
+pub async fn get_object_metadata(
+    ss: &Arc<SupportingService>,
+    key: String,
+) -> Result<HashMap<String, String>> {
+    if fs::metadata(object_path(ss, &key)?).await.is_err() {
+        bail!("Object not found: {key}");
+    }
+    let meta = match fs::read(metadata_path(ss, &key)?).await {
+        Ok(contents) => serde_json::from_slice(&contents)?,
+        Err(_) => HashMap::new(),
+    };
+    Ok(meta)
+}
+
+/// Resolve a download token created by `get_presigned_url` to the path of the object
+/// on disk.
+pub async fn get_local_download_path(
+    ss: &Arc<SupportingService>,
+    token: String,
+) -> Result<Option<PathBuf>> {
+    let key = ApplicationCacheKey::FileStorageDownloadToken(token);
+    let Some((_, value)) = cache_service::get_value::<FileStorageTokenValue>(ss, key).await else {
+        return Ok(None);
+    };
+    Ok(Some(object_path(ss, &value.key)?))
+}
+
+/// Consume an upload token created by `get_presigned_put_url`. Persists the metadata
+/// associated with the upload and returns the path the contents should be written to.
+pub async fn prepare_local_upload(
+    ss: &Arc<SupportingService>,
+    token: String,
+) -> Result<Option<PathBuf>> {
+    let key = ApplicationCacheKey::FileStorageUploadToken(token);
+    let Some((_, value)) = cache_service::get_value::<FileStorageTokenValue>(ss, key.clone()).await
+    else {
+        return Ok(None);
+    };
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ByKey(Box::new(key))).await?;
+    let path = object_path(ss, &value.key)?;
+    let meta_path = metadata_path(ss, &value.key)?;
+    for dir in [path.parent(), meta_path.parent()].into_iter().flatten() {
+        fs::create_dir_all(dir).await?;
+    }
+    fs::write(
+        meta_path,
+        serde_json::to_vec(&value.metadata.unwrap_or_default())?,
+    )
+    .await?;
+    Ok(Some(path))
+}
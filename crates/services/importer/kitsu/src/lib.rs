@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use common_models::DefaultCollection;
+use common_utils::{get_base_http_client, ryot_log};
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{ImportSource, MediaLot, MediaSource, Visibility};
+use media_models::{
+    DeployKitsuImportInput, ImportOrExportItemRating, ImportOrExportItemReview,
+    ImportOrExportMetadataItemSeen,
+};
+use reqwest::{
+    Client,
+    header::{ACCEPT, HeaderValue},
+};
+use rust_decimal::Decimal;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, de::DeserializeOwned};
+
+#[cfg(test)]
+mod tests;
+
+const API_URL: &str = "https://kitsu.app/api/edge";
+const LIBRARY_PAGE_SIZE: usize = 500;
+
+#[derive(Debug, Deserialize)]
+struct ResourceIdentifier {
+    id: String,
+    #[serde(rename = "type")]
+    lot: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Relationship {
+    data: Option<ResourceIdentifier>,
+}
+
+#[derive(Debug, Deserialize)]
+struct User {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LibraryEntryAttributes {
+    status: String,
+    progress: i32,
+    private: bool,
+    notes: Option<String>,
+    rating_twenty: Option<i32>,
+    started_at: Option<DateTimeUtc>,
+    finished_at: Option<DateTimeUtc>,
+    progressed_at: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LibraryEntry {
+    id: String,
+    attributes: LibraryEntryAttributes,
+    relationships: HashMap<String, Relationship>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MediaAttributes {
+    canonical_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Media {
+    id: String,
+    #[serde(rename = "type")]
+    lot: String,
+    attributes: MediaAttributes,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Meta {
+    count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Document<T> {
+    data: T,
+    #[serde(default)]
+    meta: Meta,
+    #[serde(default)]
+    included: Vec<Media>,
+}
+
+async fn fetch<T: DeserializeOwned>(
+    client: &Client,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<Document<T>> {
+    let document = client
+        .get(format!("{API_URL}/{path}"))
+        .query(query)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(document)
+}
+
+pub async fn import(input: DeployKitsuImportInput) -> Result<ImportResult> {
+    let client = get_base_http_client(Some(vec![(
+        ACCEPT,
+        HeaderValue::from_static("application/vnd.api+json"),
+    )]));
+    let users = fetch::<Vec<User>>(&client, "users", &[("filter[slug]", input.username)]).await?;
+    let Some(user) = users.data.into_iter().next() else {
+        bail!("No Kitsu user found with the given username");
+    };
+
+    let mut entries = vec![];
+    let mut titles = HashMap::new();
+    loop {
+        let page = fetch::<Vec<LibraryEntry>>(
+            &client,
+            "library-entries",
+            &[
+                ("filter[userId]", user.id.clone()),
+                ("include", "anime,manga".to_owned()),
+                ("page[limit]", LIBRARY_PAGE_SIZE.to_string()),
+                ("page[offset]", entries.len().to_string()),
+            ],
+        )
+        .await?;
+        let fetched = page.data.len();
+        titles.extend(media_titles(page.included));
+        entries.extend(page.data);
+        ryot_log!(debug, "Fetched {} Kitsu library entries", entries.len());
+        if fetched < LIBRARY_PAGE_SIZE || entries.len() >= page.meta.count.unwrap_or_default() {
+            break;
+        }
+    }
+
+    let completed = convert_entries(entries, &titles);
+
+    Ok(ImportResult {
+        completed,
+        ..Default::default()
+    })
+}
+
+fn media_titles(included: Vec<Media>) -> impl Iterator<Item = ((String, String), String)> {
+    included.into_iter().filter_map(|media| {
+        let title = media.attributes.canonical_title?;
+        Some(((media.lot, media.id), title))
+    })
+}
+
+fn convert_entries(
+    entries: Vec<LibraryEntry>,
+    titles: &HashMap<(String, String), String>,
+) -> Vec<ImportCompletedItem> {
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let media = ["anime", "manga"]
+                .into_iter()
+                .find_map(|key| entry.relationships.get(key).and_then(|r| r.data.as_ref()))?;
+            let lot = match media.lot.as_str() {
+                "anime" => MediaLot::Anime,
+                "manga" => MediaLot::Manga,
+                _ => return None,
+            };
+            let source_id = titles
+                .get(&(media.lot.clone(), media.id.clone()))
+                .cloned()
+                .unwrap_or_else(|| entry.id.clone());
+            Some(convert_to_format(
+                lot,
+                media.id.clone(),
+                source_id,
+                entry.attributes,
+            ))
+        })
+        .map(ImportCompletedItem::Metadata)
+        .collect()
+}
+
+fn convert_to_format(
+    lot: MediaLot,
+    identifier: String,
+    source_id: String,
+    entry: LibraryEntryAttributes,
+) -> ImportOrExportMetadataItem {
+    let ended_on = entry.finished_at.or(entry.progressed_at);
+    let seen_history = (1..entry.progress + 1)
+        .map(|num| {
+            let mut history = ImportOrExportMetadataItemSeen {
+                ended_on,
+                started_on: entry.started_at,
+                providers_consumed_on: Some(vec![ImportSource::Kitsu.to_string()]),
+                ..Default::default()
+            };
+            match lot {
+                MediaLot::Anime => history.anime_episode_number = Some(num),
+                _ => history.manga_chapter_number = Some(Decimal::from(num)),
+            }
+            history
+        })
+        .collect();
+
+    let collections = match entry.status.as_str() {
+        "current" => Some(DefaultCollection::InProgress),
+        "planned" => Some(DefaultCollection::Watchlist),
+        _ => None,
+    }
+    .map(|collection| CollectionToEntityDetails {
+        collection_name: collection.to_string(),
+        ..Default::default()
+    })
+    .into_iter()
+    .collect();
+
+    // Kitsu stores ratings on a scale of 2 to 20.
+    let rating = entry
+        .rating_twenty
+        .filter(|r| *r > 0)
+        .map(|r| Decimal::from(r * 5));
+    let review = entry
+        .notes
+        .filter(|n| !n.is_empty())
+        .map(|text| ImportOrExportItemReview {
+            text: Some(text),
+            visibility: Some(match entry.private {
+                true => Visibility::Private,
+                false => Visibility::Public,
+            }),
+            ..Default::default()
+        });
+    let reviews = match rating.is_some() || review.is_some() {
+        true => vec![ImportOrExportItemRating {
+            rating,
+            review,
+            ..Default::default()
+        }],
+        false => vec![],
+    };
+
+    ImportOrExportMetadataItem {
+        lot,
+        reviews,
+        source_id,
+        identifier,
+        collections,
+        seen_history,
+        source: MediaSource::Kitsu,
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+fn fixture_items() -> Vec<ImportOrExportMetadataItem> {
+    let page: Document<Vec<LibraryEntry>> =
+        serde_json::from_str(include_str!("fixtures/library-entries.json")).unwrap();
+    assert_eq!(page.meta.count, Some(4));
+    let titles = media_titles(page.included).collect::<HashMap<_, _>>();
+    convert_entries(page.data, &titles)
+        .into_iter()
+        .map(|item| match item {
+            ImportCompletedItem::Metadata(item) => item,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn ratings(item: &ImportOrExportMetadataItem) -> Vec<Option<Decimal>> {
+    item.reviews.iter().map(|r| r.rating).collect()
+}
+
+fn collections(item: &ImportOrExportMetadataItem) -> Vec<&str> {
+    item.collections
+        .iter()
+        .map(|c| c.collection_name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_entries_without_media_are_skipped() {
+    let items = fixture_items();
+    let identifiers = items
+        .iter()
+        .map(|i| (i.lot, i.identifier.as_str(), i.source_id.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        identifiers,
+        vec![
+            (MediaLot::Anime, "1", "Cowboy Bebop"),
+            (MediaLot::Manga, "2", "Berserk"),
+            (MediaLot::Anime, "3", "103"),
+        ]
+    );
+    assert!(items.iter().all(|i| i.source == MediaSource::Kitsu));
+}
+
+#[test]
+fn test_ratings_are_converted_to_percentages() {
+    let items = fixture_items();
+    assert_eq!(ratings(&items[0]), vec![Some(Decimal::from(85))]);
+    assert!(items[1].reviews.is_empty());
+    assert!(items[2].reviews.is_empty());
+}
+
+#[test]
+fn test_notes_are_imported_as_reviews() {
+    let items = fixture_items();
+    let review = items[0].reviews[0].review.as_ref().unwrap();
+    assert_eq!(review.text.as_deref(), Some("Loving the animation so far."));
+    assert!(matches!(review.visibility, Some(Visibility::Public)));
+}
+
+#[test]
+fn test_progress_is_mapped_to_seen_history() {
+    let items = fixture_items();
+
+    let anime = &items[0].seen_history;
+    let episodes = anime
+        .iter()
+        .map(|s| s.anime_episode_number)
+        .collect::<Vec<_>>();
+    assert_eq!(episodes, vec![Some(1), Some(2), Some(3)]);
+    assert!(anime.iter().all(|s| s.manga_chapter_number.is_none()));
+    assert!(anime.iter().all(|s| {
+        s.started_on == "2024-01-02T10:00:00Z".parse().ok()
+            && s.ended_on == "2024-01-05T21:30:00Z".parse().ok()
+    }));
+
+    let manga = &items[1].seen_history;
+    let chapters = manga
+        .iter()
+        .map(|s| s.manga_chapter_number)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chapters,
+        vec![Some(Decimal::from(1)), Some(Decimal::from(2))]
+    );
+    assert!(
+        manga
+            .iter()
+            .all(|s| s.ended_on == "2023-06-01T08:00:00Z".parse().ok())
+    );
+
+    assert!(items[2].seen_history.is_empty());
+}
+
+#[test]
+fn test_status_is_mapped_to_collections() {
+    let items = fixture_items();
+    assert_eq!(
+        collections(&items[0]),
+        vec![DefaultCollection::InProgress.to_string()]
+    );
+    assert!(collections(&items[1]).is_empty());
+    assert_eq!(
+        collections(&items[2]),
+        vec![DefaultCollection::Watchlist.to_string()]
+    );
+}
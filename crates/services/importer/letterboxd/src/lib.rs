@@ -0,0 +1,281 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{BufReader, Read, Seek},
+};
+
+use anyhow::{Result, bail};
+use chrono::NaiveDate;
+use common_models::DefaultCollection;
+use common_utils::{convert_naive_to_utc, ryot_log};
+use csv::Reader;
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{ImportSource, MediaLot, MediaSource};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use indexmap::{IndexMap, IndexSet};
+use media_models::{
+    DeployPathImportInput, ImportOrExportItemRating, ImportOrExportItemReview,
+    ImportOrExportMetadataItemSeen,
+};
+use rust_decimal::{Decimal, dec};
+use serde::{Deserialize, de::DeserializeOwned};
+use tmdb_provider::NonMediaTmdbService;
+use zip::ZipArchive;
+
+#[cfg(test)]
+mod tests;
+
+const DIARY_FILE: &str = "diary.csv";
+const RATINGS_FILE: &str = "ratings.csv";
+const REVIEWS_FILE: &str = "reviews.csv";
+const WATCHLIST_FILE: &str = "watchlist.csv";
+
+type FilmKey = (String, Option<i32>);
+
+#[derive(Debug, Deserialize)]
+struct DiaryItem {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "Date")]
+    date: NaiveDate,
+    #[serde(rename = "Watched Date")]
+    watched_date: Option<NaiveDate>,
+    #[serde(rename = "Rating")]
+    rating: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewItem {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "Date")]
+    date: NaiveDate,
+    #[serde(rename = "Watched Date")]
+    watched_date: Option<NaiveDate>,
+    #[serde(rename = "Rating")]
+    rating: Option<Decimal>,
+    #[serde(rename = "Review")]
+    review: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RatingItem {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "Rating")]
+    rating: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+struct WatchlistItem {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+}
+
+#[derive(Debug, Default)]
+struct LetterboxdExport {
+    diary: Vec<DiaryItem>,
+    ratings: Vec<RatingItem>,
+    reviews: Vec<ReviewItem>,
+    watchlist: Vec<WatchlistItem>,
+}
+
+fn read_csv<R: Read, T: DeserializeOwned>(
+    reader: R,
+    file_name: &str,
+    failed: &mut Vec<ImportFailedItem>,
+) -> Vec<T> {
+    let mut items = vec![];
+    for (idx, result) in Reader::from_reader(reader).deserialize().enumerate() {
+        match result {
+            Ok(item) => items.push(item),
+            Err(e) => failed.push(ImportFailedItem {
+                lot: Some(MediaLot::Movie),
+                step: ImportFailStep::InputTransformation,
+                identifier: format!("{file_name}:{idx}"),
+                error: Some(format!("{e:#?}")),
+            }),
+        }
+    }
+    items
+}
+
+fn read_file<R: Read + Seek, T: DeserializeOwned>(
+    archive: &mut ZipArchive<R>,
+    file_name: &str,
+    failed: &mut Vec<ImportFailedItem>,
+) -> Vec<T> {
+    let Ok(file) = archive.by_name(file_name) else {
+        ryot_log!(debug, "{file_name} not found in the export");
+        return vec![];
+    };
+    read_csv(file, file_name, failed)
+}
+
+// DEV: Letterboxd rates items out of 5
+fn convert_rating(rating: Decimal) -> Decimal {
+    rating.saturating_mul(dec!(20))
+}
+
+fn media_entry<'a>(
+    media: &'a mut IndexMap<String, ImportOrExportMetadataItem>,
+    identifiers: &IndexMap<FilmKey, String>,
+    name: &str,
+    year: Option<i32>,
+) -> Option<&'a mut ImportOrExportMetadataItem> {
+    let identifier = identifiers.get(&(name.to_owned(), year))?;
+    Some(
+        media
+            .entry(identifier.clone())
+            .or_insert_with(|| ImportOrExportMetadataItem {
+                lot: MediaLot::Movie,
+                source: MediaSource::Tmdb,
+                source_id: name.to_owned(),
+                identifier: identifier.clone(),
+                ..Default::default()
+            }),
+    )
+}
+
+impl LetterboxdExport {
+    fn films(&self) -> IndexSet<FilmKey> {
+        self.diary
+            .iter()
+            .map(|d| (d.name.clone(), d.year))
+            .chain(self.reviews.iter().map(|r| (r.name.clone(), r.year)))
+            .chain(self.ratings.iter().map(|r| (r.name.clone(), r.year)))
+            .chain(self.watchlist.iter().map(|w| (w.name.clone(), w.year)))
+            .collect()
+    }
+
+    /// Every review is also a diary entry, so the diary only adds a rating for the
+    /// entries that do not have a written review. The overall rating from `ratings.csv`
+    /// is only used for films that were not rated anywhere else.
+    fn into_media(
+        self,
+        identifiers: &IndexMap<FilmKey, String>,
+    ) -> IndexMap<String, ImportOrExportMetadataItem> {
+        let mut media = IndexMap::new();
+        let reviewed = self
+            .reviews
+            .iter()
+            .map(|r| (r.name.clone(), r.year, r.watched_date.unwrap_or(r.date)))
+            .collect::<HashSet<_>>();
+        for item in self.reviews {
+            let Some(entry) = media_entry(&mut media, identifiers, &item.name, item.year) else {
+                continue;
+            };
+            entry.reviews.push(ImportOrExportItemRating {
+                rating: item.rating.map(convert_rating),
+                review: Some(ImportOrExportItemReview {
+                    text: Some(item.review),
+                    date: Some(convert_naive_to_utc(item.date)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        for item in self.diary {
+            let Some(entry) = media_entry(&mut media, identifiers, &item.name, item.year) else {
+                continue;
+            };
+            let watched_on = item.watched_date.unwrap_or(item.date);
+            entry.seen_history.push(ImportOrExportMetadataItemSeen {
+                ended_on: Some(convert_naive_to_utc(watched_on)),
+                providers_consumed_on: Some(vec![ImportSource::Letterboxd.to_string()]),
+                ..Default::default()
+            });
+            if let Some(rating) = item.rating
+                && !reviewed.contains(&(item.name, item.year, watched_on))
+            {
+                entry.reviews.push(ImportOrExportItemRating {
+                    rating: Some(convert_rating(rating)),
+                    review: Some(ImportOrExportItemReview {
+                        date: Some(convert_naive_to_utc(watched_on)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                });
+            }
+        }
+        for item in self.ratings {
+            let Some(entry) = media_entry(&mut media, identifiers, &item.name, item.year) else {
+                continue;
+            };
+            if entry.reviews.iter().all(|r| r.rating.is_none()) {
+                entry.reviews.push(ImportOrExportItemRating {
+                    rating: Some(convert_rating(item.rating)),
+                    ..Default::default()
+                });
+            }
+        }
+        for item in self.watchlist {
+            let Some(entry) = media_entry(&mut media, identifiers, &item.name, item.year) else {
+                continue;
+            };
+            entry.collections.push(CollectionToEntityDetails {
+                collection_name: DefaultCollection::Watchlist.to_string(),
+                ..Default::default()
+            });
+        }
+        media
+    }
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    tmdb_service: &NonMediaTmdbService,
+) -> Result<ImportResult> {
+    let file = File::open(&input.export_path)?;
+    let mut archive = ZipArchive::new(BufReader::new(file))?;
+    if [DIARY_FILE, RATINGS_FILE, REVIEWS_FILE, WATCHLIST_FILE]
+        .iter()
+        .all(|f| archive.index_for_name(f).is_none())
+    {
+        bail!("Required Letterboxd CSV files not found in export");
+    }
+    let mut failed = vec![];
+    let export = LetterboxdExport {
+        diary: read_file(&mut archive, DIARY_FILE, &mut failed),
+        ratings: read_file(&mut archive, RATINGS_FILE, &mut failed),
+        reviews: read_file(&mut archive, REVIEWS_FILE, &mut failed),
+        watchlist: read_file(&mut archive, WATCHLIST_FILE, &mut failed),
+    };
+
+    let films = export.films();
+    let total = films.len();
+    let mut identifiers = IndexMap::new();
+    for (idx, (name, year)) in films.into_iter().enumerate() {
+        match tmdb_service.find_movie_by_title(&name, year).await {
+            Ok(identifier) => {
+                ryot_log!(debug, "Tmdb id: {} ({}/{})", identifier, idx + 1, total);
+                identifiers.insert((name, year), identifier);
+            }
+            Err(e) => failed.push(ImportFailedItem {
+                identifier: name,
+                lot: Some(MediaLot::Movie),
+                step: ImportFailStep::ItemDetailsFromSource,
+                error: Some(format!("Could not fetch details from TMDB: {e}")),
+            }),
+        }
+    }
+
+    Ok(ImportResult {
+        failed,
+        completed: export
+            .into_media(&identifiers)
+            .into_values()
+            .map(ImportCompletedItem::Metadata)
+            .collect(),
+    })
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+
+use super::*;
+
+const IDENTIFIERS: [(&str, i32, &str); 4] = [
+    ("Heat", 1995, "949"),
+    ("Arrival", 2016, "329865"),
+    ("Alien", 1979, "348"),
+    ("Dune", 2021, "438631"),
+];
+
+fn date(year: i32, month: u32, day: u32) -> Option<DateTime<Utc>> {
+    Some(convert_naive_to_utc(
+        NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+    ))
+}
+
+fn fixture_export(failed: &mut Vec<ImportFailedItem>) -> LetterboxdExport {
+    LetterboxdExport {
+        diary: read_csv(
+            include_bytes!("fixtures/diary.csv").as_slice(),
+            DIARY_FILE,
+            failed,
+        ),
+        ratings: read_csv(
+            include_bytes!("fixtures/ratings.csv").as_slice(),
+            RATINGS_FILE,
+            failed,
+        ),
+        reviews: read_csv(
+            include_bytes!("fixtures/reviews.csv").as_slice(),
+            REVIEWS_FILE,
+            failed,
+        ),
+        watchlist: read_csv(
+            include_bytes!("fixtures/watchlist.csv").as_slice(),
+            WATCHLIST_FILE,
+            failed,
+        ),
+    }
+}
+
+fn fixture_media() -> IndexMap<String, ImportOrExportMetadataItem> {
+    let mut failed = vec![];
+    let export = fixture_export(&mut failed);
+    assert!(failed.is_empty(), "{failed:#?}");
+    let identifiers = IDENTIFIERS
+        .iter()
+        .map(|(name, year, id)| ((name.to_string(), Some(*year)), id.to_string()))
+        .collect::<IndexMap<_, _>>();
+    assert_eq!(export.films().len(), identifiers.len());
+    export.into_media(&identifiers)
+}
+
+fn ratings(item: &ImportOrExportMetadataItem) -> Vec<Option<Decimal>> {
+    item.reviews.iter().map(|r| r.rating).collect()
+}
+
+#[test]
+fn test_diary_ratings_are_imported() {
+    let media = fixture_media();
+    let heat = &media["949"];
+    assert_eq!(heat.seen_history.len(), 2);
+    assert_eq!(ratings(heat), vec![Some(dec!(90))]);
+    let review = heat.reviews[0].review.as_ref().unwrap();
+    assert_eq!(review.text, None);
+    assert_eq!(review.date, date(2024, 1, 4));
+}
+
+#[test]
+fn test_reviews_are_imported_once_with_their_rating() {
+    let media = fixture_media();
+    let arrival = &media["329865"];
+    assert_eq!(arrival.seen_history.len(), 1);
+    assert_eq!(ratings(arrival), vec![Some(dec!(100))]);
+    let review = arrival.reviews[0].review.as_ref().unwrap();
+    assert_eq!(
+        review.text.as_deref(),
+        Some("Heptapods, and a lot of feelings.")
+    );
+    assert_eq!(review.date, date(2024, 3, 1));
+}
+
+#[test]
+fn test_ratings_file_is_used_for_unrated_films() {
+    let media = fixture_media();
+    let alien = &media["348"];
+    assert_eq!(alien.seen_history.len(), 1);
+    assert_eq!(ratings(alien), vec![Some(dec!(70))]);
+    assert!(alien.reviews[0].review.is_none());
+}
+
+#[test]
+fn test_watchlist_is_imported() {
+    let media = fixture_media();
+    let dune = &media["438631"];
+    assert!(dune.reviews.is_empty());
+    assert!(dune.seen_history.is_empty());
+    assert_eq!(
+        dune.collections[0].collection_name,
+        DefaultCollection::Watchlist.to_string()
+    );
+}
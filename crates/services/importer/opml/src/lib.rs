@@ -0,0 +1,122 @@
+use std::{fs, sync::Arc};
+
+use anyhow::{Result, anyhow};
+use common_models::DefaultCollection;
+use common_utils::ryot_log;
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use itunes_provider::ITunesService;
+use listennotes_provider::ListennotesService;
+use media_models::DeployPathImportInput;
+use rss_provider::RssService;
+use serde::Deserialize;
+use supporting_service::SupportingService;
+
+#[derive(Debug, Deserialize)]
+struct Outline {
+    #[serde(rename = "@text")]
+    text: Option<String>,
+    #[serde(rename = "@title")]
+    title: Option<String>,
+    #[serde(rename = "@xmlUrl")]
+    xml_url: Option<String>,
+    #[serde(default, rename = "outline")]
+    outlines: Vec<Outline>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Body {
+    #[serde(default, rename = "outline")]
+    outlines: Vec<Outline>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Opml {
+    body: Body,
+}
+
+/// Podcast apps are free to group feeds into (nested) folders, so all of them are
+/// flattened into a list of titles and feed URLs.
+fn flatten_feeds(outlines: Vec<Outline>, feeds: &mut Vec<(String, String)>) {
+    for outline in outlines {
+        if let Some(url) = outline.xml_url.filter(|u| !u.trim().is_empty()) {
+            let url = url.trim().to_owned();
+            let title = outline
+                .title
+                .or(outline.text)
+                .unwrap_or_else(|| url.clone());
+            feeds.push((title, url));
+        }
+        flatten_feeds(outline.outlines, feeds);
+    }
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    ss: &Arc<SupportingService>,
+) -> Result<ImportResult> {
+    let text = fs::read_to_string(&input.export_path)?;
+    let opml: Opml =
+        serde_xml_rs::from_str(&text).map_err(|e| anyhow!("Could not parse the OPML file: {e}"))?;
+    let mut feeds = vec![];
+    flatten_feeds(opml.body.outlines, &mut feeds);
+
+    let rss_service = RssService::new(ss.clone()).await?;
+    let itunes_service = ITunesService::new(ss.clone()).await?;
+    let listennotes_service = match ss.config.podcasts.listennotes.api_token.is_empty() {
+        true => None,
+        false => Some(ListennotesService::new(ss.clone()).await?),
+    };
+
+    let feeds = feeds
+        .into_iter()
+        .unique_by(|(_, url)| url.clone())
+        .collect_vec();
+    let total = feeds.len();
+    let mut completed = vec![];
+    for (idx, (title, url)) in feeds.into_iter().enumerate() {
+        let mut resolved = itunes_service
+            .find_by_feed_url(&title, &url)
+            .await
+            .unwrap_or_default()
+            .map(|id| (MediaSource::Itunes, id));
+        if resolved.is_none()
+            && let Some(service) = &listennotes_service
+        {
+            resolved = service
+                .find_by_feed_url(&url)
+                .await
+                .unwrap_or_default()
+                .map(|id| (MediaSource::Listennotes, id));
+        }
+        // Feeds that are not listed in any directory are tracked directly.
+        let (source, identifier) = match resolved {
+            Some(resolved) => resolved,
+            None => (MediaSource::Rss, rss_service.feed_identifier(&url).await?),
+        };
+        ryot_log!(
+            debug,
+            "Resolved {title} to {source}: {identifier} ({}/{total})",
+            idx + 1
+        );
+        completed.push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+            source,
+            identifier,
+            source_id: title,
+            lot: MediaLot::Podcast,
+            collections: vec![CollectionToEntityDetails {
+                collection_name: DefaultCollection::Monitoring.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }));
+    }
+
+    Ok(ImportResult {
+        completed,
+        ..Default::default()
+    })
+}
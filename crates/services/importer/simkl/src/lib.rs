@@ -0,0 +1,256 @@
+use std::fs;
+
+use anyhow::Result;
+use common_models::DefaultCollection;
+use common_utils::ryot_log;
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{ImportSource, MediaLot, MediaSource};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use media_models::{
+    DeployPathImportInput, ImportOrExportItemRating, ImportOrExportMetadataItemSeen,
+};
+use rust_decimal::{Decimal, dec};
+use sea_orm::prelude::DateTimeUtc;
+use serde::Deserialize;
+use tmdb_provider::NonMediaTmdbService;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Id {
+    Number(u64),
+    Text(String),
+}
+
+impl Id {
+    fn value(&self) -> Option<String> {
+        match self {
+            Id::Number(n) => Some(n.to_string()),
+            Id::Text(s) if s.is_empty() => None,
+            Id::Text(s) => Some(s.to_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Ids {
+    mal: Option<Id>,
+    tmdb: Option<Id>,
+    imdb: Option<Id>,
+    simkl: Option<Id>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Media {
+    title: String,
+    #[serde(default)]
+    ids: Ids,
+}
+
+#[derive(Debug, Deserialize)]
+struct Episode {
+    number: i32,
+    watched_at: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Season {
+    number: i32,
+    #[serde(default)]
+    episodes: Vec<Episode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Item {
+    status: Option<String>,
+    user_rating: Option<Decimal>,
+    last_watched_at: Option<DateTimeUtc>,
+    #[serde(alias = "movie")]
+    show: Media,
+    #[serde(default)]
+    seasons: Vec<Season>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Backup {
+    #[serde(default)]
+    shows: Vec<Item>,
+    #[serde(default)]
+    anime: Vec<Item>,
+    #[serde(default)]
+    movies: Vec<Item>,
+}
+
+enum Resolution {
+    Found(MediaLot, MediaSource, String),
+    LookupImdb(MediaLot, String),
+    Missing,
+}
+
+fn watched_season_count(item: &Item) -> usize {
+    item.seasons
+        .iter()
+        .filter(|s| !s.episodes.is_empty())
+        .count()
+}
+
+fn resolution(item: &Item, lot: MediaLot) -> Resolution {
+    let ids = &item.show.ids;
+    let mal = match lot {
+        MediaLot::Anime => ids.mal.as_ref().and_then(Id::value),
+        _ => None,
+    };
+    // DEV: Every season of an anime is a separate entry on MAL, so anime watched across
+    // multiple seasons are tracked as TMDB shows to keep their season numbers
+    if let Some(mal) = &mal
+        && watched_season_count(item) <= 1
+    {
+        return Resolution::Found(MediaLot::Anime, MediaSource::Myanimelist, mal.clone());
+    }
+    let show_lot = match lot {
+        MediaLot::Movie => MediaLot::Movie,
+        _ => MediaLot::Show,
+    };
+    if let Some(tmdb) = ids.tmdb.as_ref().and_then(Id::value) {
+        return Resolution::Found(show_lot, MediaSource::Tmdb, tmdb);
+    }
+    if let Some(imdb) = ids.imdb.as_ref().and_then(Id::value) {
+        return Resolution::LookupImdb(show_lot, imdb);
+    }
+    match mal {
+        Some(mal) => Resolution::Found(MediaLot::Anime, MediaSource::Myanimelist, mal),
+        None => Resolution::Missing,
+    }
+}
+
+async fn resolve_identifier(
+    item: &Item,
+    lot: MediaLot,
+    tmdb_service: &NonMediaTmdbService,
+) -> Result<(MediaLot, MediaSource, String), String> {
+    match resolution(item, lot) {
+        Resolution::Found(lot, source, identifier) => Ok((lot, source, identifier)),
+        Resolution::LookupImdb(lot, imdb) => tmdb_service
+            .find_by_external_id(&imdb, "imdb_id")
+            .await
+            .map(|identifier| (lot, MediaSource::Tmdb, identifier))
+            .map_err(|e| format!("Could not fetch details from TMDB: {e}")),
+        Resolution::Missing => {
+            Err("Item does not have an associated TMDB, IMDB or MAL id".to_owned())
+        }
+    }
+}
+
+fn seen_history(item: &Item, lot: MediaLot) -> Vec<ImportOrExportMetadataItemSeen> {
+    let providers_consumed_on = Some(vec![ImportSource::Simkl.to_string()]);
+    if lot == MediaLot::Movie {
+        let is_watched = item.status.as_deref() == Some("completed");
+        return match is_watched || item.last_watched_at.is_some() {
+            true => vec![ImportOrExportMetadataItemSeen {
+                providers_consumed_on,
+                ended_on: item.last_watched_at,
+                ..Default::default()
+            }],
+            false => vec![],
+        };
+    }
+    let mut seasons = item.seasons.iter().collect::<Vec<_>>();
+    seasons.sort_by_key(|s| s.number);
+    // DEV: Anime only have a MAL id when they could not be found on TMDB, in which case
+    // episodes of later seasons continue the numbering of the earlier ones
+    let mut episode_offset = 0;
+    let mut history = vec![];
+    for season in seasons {
+        for episode in &season.episodes {
+            let mut seen = ImportOrExportMetadataItemSeen {
+                ended_on: episode.watched_at,
+                providers_consumed_on: providers_consumed_on.clone(),
+                ..Default::default()
+            };
+            match lot {
+                MediaLot::Anime => {
+                    seen.anime_episode_number = Some(episode_offset + episode.number)
+                }
+                _ => {
+                    seen.show_season_number = Some(season.number);
+                    seen.show_episode_number = Some(episode.number);
+                }
+            }
+            history.push(seen);
+        }
+        episode_offset += season.episodes.iter().map(|e| e.number).max().unwrap_or(0);
+    }
+    history
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    tmdb_service: &NonMediaTmdbService,
+) -> Result<ImportResult> {
+    let backup: Backup = serde_json::from_str(&fs::read_to_string(&input.export_path)?)?;
+    let items = backup
+        .shows
+        .into_iter()
+        .map(|i| (i, MediaLot::Show))
+        .chain(backup.anime.into_iter().map(|i| (i, MediaLot::Anime)))
+        .chain(backup.movies.into_iter().map(|i| (i, MediaLot::Movie)))
+        .collect::<Vec<_>>();
+    let total = items.len();
+    let mut completed = vec![];
+    let mut failed = vec![];
+    for (idx, (item, lot)) in items.into_iter().enumerate() {
+        let (lot, source, identifier) = match resolve_identifier(&item, lot, tmdb_service).await {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                failed.push(ImportFailedItem {
+                    lot: Some(lot),
+                    error: Some(error),
+                    identifier: item.show.title,
+                    step: ImportFailStep::ItemDetailsFromSource,
+                });
+                continue;
+            }
+        };
+        ryot_log!(debug, "{source} id: {identifier} ({}/{})", idx + 1, total);
+        let collection = match item.status.as_deref() {
+            Some("plantowatch") => Some(DefaultCollection::Watchlist),
+            Some("watching") => Some(DefaultCollection::InProgress),
+            _ => None,
+        };
+        let reviews = item
+            .user_rating
+            .map(|rating| ImportOrExportItemRating {
+                // DEV: Rates items out of 10
+                rating: Some(rating.saturating_mul(dec!(10))),
+                ..Default::default()
+            })
+            .into_iter()
+            .collect();
+        completed.push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+            lot,
+            source,
+            reviews,
+            identifier,
+            seen_history: seen_history(&item, lot),
+            source_id: item
+                .show
+                .ids
+                .simkl
+                .as_ref()
+                .and_then(Id::value)
+                .unwrap_or(item.show.title),
+            collections: collection
+                .map(|c| CollectionToEntityDetails {
+                    collection_name: c.to_string(),
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+        }));
+    }
+    Ok(ImportResult { completed, failed })
+}
@@ -0,0 +1,95 @@
+use serde_json::json;
+
+use super::*;
+
+fn anime(ids: serde_json::Value, seasons: serde_json::Value) -> Item {
+    serde_json::from_value(json!({
+        "status": "watching",
+        "show": { "title": "Attack on Titan", "ids": ids },
+        "seasons": seasons,
+    }))
+    .unwrap()
+}
+
+fn episodes(numbers: &[i32]) -> serde_json::Value {
+    numbers
+        .iter()
+        .map(|n| json!({ "number": n, "watched_at": "2024-01-01T00:00:00Z" }))
+        .collect()
+}
+
+fn found(resolution: Resolution) -> (MediaLot, MediaSource, String) {
+    match resolution {
+        Resolution::Found(lot, source, identifier) => (lot, source, identifier),
+        _ => panic!("identifier was not resolved without a lookup"),
+    }
+}
+
+#[test]
+fn test_single_season_anime_is_mapped_to_mal() {
+    let item = anime(
+        json!({ "mal": 16498, "tmdb": "1429" }),
+        json!([{ "number": 1, "episodes": episodes(&[1, 2, 3]) }]),
+    );
+    let (lot, source, identifier) = found(resolution(&item, MediaLot::Anime));
+    assert_eq!(lot, MediaLot::Anime);
+    assert_eq!(source, MediaSource::Myanimelist);
+    assert_eq!(identifier, "16498");
+
+    let history = seen_history(&item, lot);
+    let numbers = history
+        .iter()
+        .map(|s| (s.show_season_number, s.anime_episode_number))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        numbers,
+        vec![(None, Some(1)), (None, Some(2)), (None, Some(3))]
+    );
+}
+
+#[test]
+fn test_multi_season_anime_keeps_seasons_on_tmdb() {
+    let item = anime(
+        json!({ "mal": 16498, "tmdb": 1429 }),
+        json!([
+            { "number": 2, "episodes": episodes(&[1]) },
+            { "number": 1, "episodes": episodes(&[24, 25]) },
+        ]),
+    );
+    let (lot, source, identifier) = found(resolution(&item, MediaLot::Anime));
+    assert_eq!(lot, MediaLot::Show);
+    assert_eq!(source, MediaSource::Tmdb);
+    assert_eq!(identifier, "1429");
+
+    let history = seen_history(&item, lot);
+    let numbers = history
+        .iter()
+        .map(|s| (s.show_season_number, s.show_episode_number))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        numbers,
+        vec![(Some(1), Some(24)), (Some(1), Some(25)), (Some(2), Some(1))]
+    );
+    assert!(history.iter().all(|s| s.anime_episode_number.is_none()));
+}
+
+#[test]
+fn test_multi_season_anime_without_tmdb_continues_mal_numbering() {
+    let item = anime(
+        json!({ "mal": 16498, "tmdb": "" }),
+        json!([
+            { "number": 1, "episodes": episodes(&[24, 25]) },
+            { "number": 2, "episodes": episodes(&[1, 2]) },
+        ]),
+    );
+    let (lot, source, identifier) = found(resolution(&item, MediaLot::Anime));
+    assert_eq!(lot, MediaLot::Anime);
+    assert_eq!(source, MediaSource::Myanimelist);
+    assert_eq!(identifier, "16498");
+
+    let numbers = seen_history(&item, lot)
+        .iter()
+        .map(|s| s.anime_episode_number)
+        .collect::<Vec<_>>();
+    assert_eq!(numbers, vec![Some(24), Some(25), Some(26), Some(27)]);
+}
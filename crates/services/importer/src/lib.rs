@@ -45,6 +45,7 @@ pub async fn perform_import(
         ImportSource::Watcharr => watcharr_importer_service::import(input.path.unwrap()).await,
         ImportSource::Jellyfin => jellyfin_importer_service::import(input.jellyfin.unwrap()).await,
         ImportSource::Myanimelist => myanimelist_importer_service::import(input.mal.unwrap()).await,
+        ImportSource::Kitsu => kitsu_importer_service::import(input.kitsu.unwrap()).await,
         ImportSource::Grouvee => grouvee_importer_service::import(input.generic_csv.unwrap()).await,
         ImportSource::Hardcover => {
             hardcover_importer_service::import(input.generic_csv.unwrap()).await
@@ -64,6 +65,7 @@ pub async fn perform_import(
             open_scale_importer_service::import(input.generic_csv.unwrap(), &ss.timezone).await
         }
         ImportSource::Anilist => anilist_importer_service::import(input.path.unwrap(), ss).await,
+        ImportSource::Opml => opml_importer_service::import(input.path.unwrap(), ss).await,
         ImportSource::StrongApp => {
             strong_app_importer_service::import(input.strong_app.unwrap(), ss, &user_id).await
         }
@@ -81,6 +83,20 @@ pub async fn perform_import(
             )
             .await
         }
+        ImportSource::Simkl => {
+            simkl_importer_service::import(
+                input.path.unwrap(),
+                &get_tmdb_non_media_service(ss).await?,
+            )
+            .await
+        }
+        ImportSource::Letterboxd => {
+            letterboxd_importer_service::import(
+                input.path.unwrap(),
+                &get_tmdb_non_media_service(ss).await?,
+            )
+            .await
+        }
         ImportSource::Goodreads => {
             goodreads_importer_service::import(
                 input.generic_csv.unwrap(),
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use background_models::{ApplicationJob, SingleApplicationJob};
+use chrono::Utc;
+use database_models::{integration, koreader_sync_progress, prelude::Integration};
+use enum_models::IntegrationProvider;
+use external_models::koreader_sync::DocumentProgress;
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
+};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, sea_query::OnConflict};
+use subtle::ConstantTimeEq;
+use supporting_service::SupportingService;
+
+/// Find the integration for the given slug and check the credentials sent by KOReader.
+/// The key sent by KOReader is the MD5 hash of the password entered on the device and is
+/// verified against the hash stored when the integration was saved.
+pub async fn authorize(
+    ss: &Arc<SupportingService>,
+    integration_slug: &String,
+    username: Option<&str>,
+    key: Option<&str>,
+) -> Result<Option<integration::Model>> {
+    let Some(integration) = Integration::find_by_id(integration_slug)
+        .one(&ss.db)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if integration.provider != IntegrationProvider::KoreaderSync
+        || integration.is_disabled.unwrap_or_default()
+    {
+        return Ok(None);
+    }
+    let specifics = integration.provider_specifics.clone().unwrap_or_default();
+    let (Some(expected_username), Some(password_hash), Some(username), Some(key)) = (
+        specifics.koreader_sync_username,
+        specifics.koreader_sync_password_hash,
+        username,
+        key,
+    ) else {
+        return Ok(None);
+    };
+    let username_matches: bool = expected_username
+        .as_bytes()
+        .ct_eq(username.as_bytes())
+        .into();
+    let key_matches = PasswordHash::new(&password_hash).is_ok_and(|hash| {
+        Argon2::default()
+            .verify_password(key.to_lowercase().as_bytes(), &hash)
+            .is_ok()
+    });
+    Ok((username_matches && key_matches).then_some(integration))
+}
+
+pub async fn get_document_progress(
+    ss: &Arc<SupportingService>,
+    integration: &integration::Model,
+    document: String,
+) -> Result<Option<DocumentProgress>> {
+    let progress = koreader_sync_progress::Entity::find()
+        .filter(koreader_sync_progress::Column::IntegrationId.eq(&integration.id))
+        .filter(koreader_sync_progress::Column::Document.eq(document))
+        .one(&ss.db)
+        .await?;
+    Ok(progress.map(|p| DocumentProgress {
+        device: p.device,
+        document: p.document,
+        progress: p.progress,
+        device_id: p.device_id,
+        timestamp: Some(p.updated_on),
+        percentage: p.percentage.to_f64().unwrap_or_default(),
+    }))
+}
+
+/// Store the latest position for the document and queue it to be processed like any other
+/// sink integration webhook.
+pub async fn update_document_progress(
+    ss: &Arc<SupportingService>,
+    integration: &integration::Model,
+    progress: DocumentProgress,
+) -> Result<DocumentProgress> {
+    let now = Utc::now();
+    let model = koreader_sync_progress::ActiveModel {
+        updated_on: ActiveValue::Set(now),
+        device: ActiveValue::Set(progress.device.clone()),
+        document: ActiveValue::Set(progress.document.clone()),
+        progress: ActiveValue::Set(progress.progress.clone()),
+        device_id: ActiveValue::Set(progress.device_id.clone()),
+        integration_id: ActiveValue::Set(integration.id.clone()),
+        percentage: ActiveValue::Set(Decimal::from_f64(progress.percentage).unwrap_or_default()),
+        ..Default::default()
+    };
+    koreader_sync_progress::Entity::insert(model)
+        .on_conflict(
+            OnConflict::columns([
+                koreader_sync_progress::Column::IntegrationId,
+                koreader_sync_progress::Column::Document,
+            ])
+            .update_columns([
+                koreader_sync_progress::Column::Device,
+                koreader_sync_progress::Column::Progress,
+                koreader_sync_progress::Column::DeviceId,
+                koreader_sync_progress::Column::UpdatedOn,
+                koreader_sync_progress::Column::Percentage,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(&ss.db)
+        .await?;
+    let progress = DocumentProgress {
+        timestamp: Some(now),
+        ..progress
+    };
+    ss.perform_application_job(ApplicationJob::Single(
+        SingleApplicationJob::ProcessIntegrationWebhook(
+            integration.id.clone(),
+            serde_json::to_string(&progress)?,
+        ),
+    ))
+    .await?;
+    Ok(progress)
+}
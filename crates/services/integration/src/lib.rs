@@ -1,6 +1,8 @@
 mod data_operations;
 mod event_handlers;
 mod integration_operations;
+pub mod koreader_sync;
+pub mod listenbrainz;
 mod push;
 mod sink;
 mod utils;
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use database_models::{integration, prelude::Integration};
+use database_utils::user_by_id;
+use enum_models::IntegrationProvider;
+use sea_orm::EntityTrait;
+use supporting_service::SupportingService;
+
+/// ListenBrainz clients authenticate with a `Token <token>` authorization header where the
+/// token is the slug of the integration.
+pub async fn authorize(
+    ss: &Arc<SupportingService>,
+    authorization: Option<&str>,
+) -> Result<Option<integration::Model>> {
+    let Some(token) = authorization.and_then(|a| a.strip_prefix("Token ")) else {
+        return Ok(None);
+    };
+    let Some(integration) = Integration::find_by_id(token.trim()).one(&ss.db).await? else {
+        return Ok(None);
+    };
+    if integration.provider != IntegrationProvider::ListenBrainz
+        || integration.is_disabled.unwrap_or_default()
+    {
+        return Ok(None);
+    }
+    Ok(Some(integration))
+}
+
+/// The name shown by scrobblers once the token has been validated.
+pub async fn user_name(
+    ss: &Arc<SupportingService>,
+    integration: &integration::Model,
+) -> Result<String> {
+    let user = user_by_id(&integration.user_id, ss).await?;
+    Ok(user.name)
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use common_utils::ryot_log;
+use database_models::prelude::Metadata;
+use dependent_models::{ImportCompletedItem, ImportOrExportMetadataItem, ImportResult};
+use dependent_provider_utils::{
+    get_google_books_service, get_hardcover_service, get_identifier_from_book_isbn,
+    get_openlibrary_service,
+};
+use enum_models::MediaLot;
+use external_models::koreader_sync::DocumentProgress;
+use futures::try_join;
+use media_models::{ImportOrExportMetadataItemSeen, KoreaderSyncDocument};
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use sea_orm::EntityTrait;
+use supporting_service::SupportingService;
+
+pub async fn sink_progress(
+    payload: String,
+    documents: Option<Vec<KoreaderSyncDocument>>,
+    ss: &Arc<SupportingService>,
+) -> Result<Option<ImportResult>> {
+    let payload = match serde_json::from_str::<DocumentProgress>(&payload) {
+        Ok(val) => val,
+        Err(err) => bail!(err),
+    };
+    let Some(document) = documents
+        .unwrap_or_default()
+        .into_iter()
+        .find(|d| d.document == payload.document)
+    else {
+        ryot_log!(debug, "No mapping found for document {}", payload.document);
+        return Ok(None);
+    };
+
+    let (lot, source, identifier) = if let Some(metadata_id) = document.metadata_id {
+        let Some(metadata) = Metadata::find_by_id(metadata_id).one(&ss.db).await? else {
+            bail!(
+                "Metadata mapped to document {} does not exist",
+                payload.document
+            );
+        };
+        (metadata.lot, metadata.source, metadata.identifier)
+    } else if let Some(isbn) = document.isbn {
+        let (hardcover, google_books, openlibrary) = try_join!(
+            get_hardcover_service(&ss.config),
+            get_google_books_service(&ss.config),
+            get_openlibrary_service(&ss.config)
+        )?;
+        let Some((identifier, source)) =
+            get_identifier_from_book_isbn(&isbn, &hardcover, &google_books, &openlibrary).await
+        else {
+            bail!("No book found for ISBN {isbn}");
+        };
+        (MediaLot::Book, source, identifier)
+    } else {
+        bail!(
+            "Document {} has neither an ISBN nor a metadata id",
+            payload.document
+        );
+    };
+
+    Ok(Some(ImportResult {
+        completed: vec![ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+            lot,
+            source,
+            identifier,
+            seen_history: vec![ImportOrExportMetadataItemSeen {
+                progress: Decimal::from_f64(payload.percentage * 100.0),
+                providers_consumed_on: Some(vec!["KOReader".to_string()]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })],
+        ..Default::default()
+    }))
+}
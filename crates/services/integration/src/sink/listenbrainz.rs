@@ -0,0 +1,56 @@
+use anyhow::{Result, bail};
+use common_utils::ryot_log;
+use dependent_models::{ImportCompletedItem, ImportOrExportMetadataItem, ImportResult};
+use enum_models::{MediaLot, MediaSource};
+use external_models::listenbrainz::{ListenType, SubmitListens};
+use media_models::ImportOrExportMetadataItemSeen;
+use music_brainz_provider::MusicBrainzService;
+use rust_decimal::dec;
+
+pub async fn sink_progress(payload: String) -> Result<Option<ImportResult>> {
+    let payload = match serde_json::from_str::<SubmitListens>(&payload) {
+        Ok(val) => val,
+        Err(err) => bail!(err),
+    };
+    if payload.listen_type == ListenType::PlayingNow {
+        return Ok(None);
+    }
+    let service = MusicBrainzService::new()?;
+    let mut result = ImportResult::default();
+    for listen in payload.payload {
+        let track = listen.track_metadata;
+        let identifier = match track.additional_info.recording_mbid {
+            Some(mbid) => mbid,
+            None => match service
+                .find_recording(&track.artist_name, &track.track_name)
+                .await
+            {
+                Ok(Some(mbid)) => mbid,
+                _ => {
+                    ryot_log!(
+                        debug,
+                        "No recording found for {} - {}",
+                        track.artist_name,
+                        track.track_name
+                    );
+                    continue;
+                }
+            },
+        };
+        result
+            .completed
+            .push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+                identifier,
+                lot: MediaLot::Music,
+                source: MediaSource::MusicBrainz,
+                seen_history: vec![ImportOrExportMetadataItemSeen {
+                    progress: Some(dec!(100)),
+                    ended_on: listen.listened_at,
+                    providers_consumed_on: Some(vec!["ListenBrainz".to_owned()]),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }));
+    }
+    Ok(Some(result))
+}
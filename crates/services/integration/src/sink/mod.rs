@@ -2,5 +2,7 @@ pub mod emby;
 pub mod generic_json;
 pub mod jellyfin;
 pub mod kodi;
+pub mod koreader_sync;
+pub mod listenbrainz;
 pub mod plex;
 pub mod ryot_browser_extension;
@@ -146,6 +146,11 @@ pub async fn process_integration_webhook(
             )
             .await
         }
+        IntegrationProvider::KoreaderSync => {
+            let specifics = integration.clone().provider_specifics.unwrap();
+            sink::koreader_sync::sink_progress(payload, specifics.koreader_sync_documents, ss).await
+        }
+        IntegrationProvider::ListenBrainz => sink::listenbrainz::sink_progress(payload).await,
         IntegrationProvider::PlexSink => {
             let specifics = integration.clone().provider_specifics.unwrap();
             sink::plex::sink_progress(payload, specifics.plex_sink_username, ss).await
@@ -1,15 +1,15 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use common_utils::{BULK_DATABASE_UPDATE_OR_DELETE_CHUNK_SIZE, ryot_log};
 use database_models::{
     access_link, application_cache, genre, metadata, metadata_group, metadata_to_genre, person,
     prelude::{
         AccessLink, ApplicationCache, Genre, Metadata, MetadataGroup, MetadataToGenre, Person,
-        UserToEntity,
+        UserAuditLog, UserToEntity,
     },
-    user_to_entity,
+    user_audit_log, user_to_entity,
 };
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, UpdateMany, prelude::Expr};
 use supporting_service::SupportingService;
@@ -86,6 +86,15 @@ pub async fn remove_useless_data(ss: &Arc<SupportingService>) -> Result<()> {
         .exec(&ss.db)
         .await
         .trace_ok();
+    ryot_log!(debug, "Deleting old audit logs");
+    UserAuditLog::delete_many()
+        .filter(
+            user_audit_log::Column::CreatedOn
+                .lt(Utc::now() - Duration::days(ss.config.users.audit_log_retention_days)),
+        )
+        .exec(&ss.db)
+        .await
+        .trace_ok();
     ryot_log!(debug, "Deleting expired application caches");
     ApplicationCache::delete_many()
         .filter(application_cache::Column::ExpiresAt.lt(Utc::now()))
@@ -500,6 +500,7 @@ fn get_data_for_custom_metadata(
         MediaLot::AudioBook => input.audio_book_specifics.is_none(),
         MediaLot::VideoGame => input.video_game_specifics.is_none(),
         MediaLot::ComicBook => input.comic_book_specifics.is_none(),
+        MediaLot::BoardGame => input.board_game_specifics.is_none(),
         MediaLot::VisualNovel => input.visual_novel_specifics.is_none(),
     };
     metadata::ActiveModel {
@@ -523,6 +524,7 @@ fn get_data_for_custom_metadata(
         audio_book_specifics: ActiveValue::Set(input.audio_book_specifics),
         video_game_specifics: ActiveValue::Set(input.video_game_specifics),
         comic_book_specifics: ActiveValue::Set(input.comic_book_specifics),
+        board_game_specifics: ActiveValue::Set(input.board_game_specifics),
         visual_novel_specifics: ActiveValue::Set(input.visual_novel_specifics),
         publish_year: ActiveValue::Set(
             input
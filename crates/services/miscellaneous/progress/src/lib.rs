@@ -9,8 +9,9 @@ use dependent_utility_utils::associate_user_with_entity;
 use enum_models::EntityLot;
 use futures::try_join;
 use media_models::{
-    MetadataProgressUpdateInput, SeenAnimeExtraInformation, SeenMangaExtraInformation,
-    SeenPodcastExtraInformation, SeenShowExtraInformation, UpdateSeenItemInput,
+    MetadataProgressUpdateInput, SeenAnimeExtraInformation, SeenBoardGameExtraInformation,
+    SeenMangaExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
+    UpdateSeenItemInput,
 };
 use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait, IntoActiveModel, ModelTrait};
 use supporting_service::SupportingService;
@@ -65,6 +66,12 @@ pub async fn update_seen_item(
         seen.podcast_extra_information =
             ActiveValue::Set(Some(SeenPodcastExtraInformation { episode }));
     }
+    if input.common.board_game_player_count.is_some() || input.common.board_game_winner.is_some() {
+        seen.board_game_extra_information = ActiveValue::Set(Some(SeenBoardGameExtraInformation {
+            winner: input.common.board_game_winner,
+            player_count: input.common.board_game_player_count,
+        }));
+    }
     if let Some(review_id) = input.review_id {
         let (review, to_update_review_id) = match review_id.is_empty() {
             false => (
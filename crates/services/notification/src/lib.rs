@@ -1,27 +1,100 @@
 use std::{env, time::Duration};
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use askama::Template;
+use common_models::NotificationWebhookHeader;
 use common_utils::{APPLICATION_JSON_HEADER, AVATAR_URL, PROJECT_NAME, ryot_log};
 use config_definition::AppConfig;
 use convert_case::{Case, Casing};
+use hmac::{Hmac, Mac};
 use lettre::{
     AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
     message::{MultiPart, SinglePart, header},
     transport::smtp::authentication::Credentials,
 };
+use markdown::Options;
+use minijinja::{AutoEscape, Environment};
 use reqwest::{
     Client,
-    header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue},
+    header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue},
 };
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use url::Url;
 use user_models::NotificationPlatformSpecifics;
+use uuid::Uuid;
+
+#[cfg(test)]
+mod tests;
+
+/// Structured details of a notification. Platforms that only accept text use `message`
+/// while the webhook platform sends the whole object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEvent {
+    pub event: String,
+    pub message: String,
+    pub url: Option<String>,
+    pub data: serde_json::Value,
+}
+
+/// Every value interpolated in a webhook body template is serialized as JSON, so
+/// `{"text": {{ message }}}` always produces a valid document.
+fn webhook_template_environment<'source>() -> Environment<'source> {
+    let mut env = Environment::new();
+    env.set_auto_escape_callback(|_| AutoEscape::Json);
+    env
+}
+
+fn webhook_headers(headers: &[NotificationWebhookHeader]) -> Result<HeaderMap> {
+    let mut header_map = HeaderMap::new();
+    for header in headers {
+        let name = HeaderName::from_bytes(header.name.as_bytes())
+            .map_err(|_| anyhow!("Invalid header name: {}", header.name))?;
+        let value = HeaderValue::from_str(&header.value)
+            .map_err(|_| anyhow!("Invalid value for header: {}", header.name))?;
+        header_map.insert(name, value);
+    }
+    Ok(header_map)
+}
+
+fn webhook_body(notification: &NotificationEvent, body_template: Option<&str>) -> Result<String> {
+    Ok(match body_template {
+        Some(template) => webhook_template_environment().render_str(template, notification)?,
+        None => serde_json::to_string(notification)?,
+    })
+}
+
+/// The value of the `X-Ryot-Signature` header, the hex encoded HMAC-SHA256 of the body.
+fn webhook_signature(secret: &str, body: &str) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+    mac.update(body.as_bytes());
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+/// Check the configuration of a webhook platform so that mistakes are reported when it is
+/// created instead of every time a notification is sent.
+pub fn validate_webhook_platform(
+    headers: Option<&[NotificationWebhookHeader]>,
+    body_template: Option<&str>,
+) -> Result<()> {
+    webhook_headers(headers.unwrap_or_default())?;
+    if let Some(template) = body_template {
+        webhook_template_environment()
+            .template_from_str(template)
+            .map_err(|e| anyhow!("Invalid body template: {e}"))?;
+    }
+    Ok(())
+}
 
 pub async fn send_notification(
-    msg: &str,
+    notification: &NotificationEvent,
     config: &AppConfig,
     specifics: NotificationPlatformSpecifics,
 ) -> Result<()> {
+    let msg = notification.message.as_str();
     let project_name = PROJECT_NAME.to_case(Case::Title);
     let client = Client::new();
     if env::var("DISABLE_NOTIFICATIONS").is_ok() {
@@ -148,6 +221,63 @@ pub async fn send_notification(
                 .send()
                 .await?;
         }
+        NotificationPlatformSpecifics::Matrix {
+            room_id,
+            base_url,
+            access_token,
+        } => {
+            let mut url = Url::parse(&base_url)?;
+            url.path_segments_mut()
+                .map_err(|_| anyhow!("Invalid homeserver URL"))?
+                .pop_if_empty()
+                .extend([
+                    "_matrix",
+                    "client",
+                    "v3",
+                    "rooms",
+                    &room_id,
+                    "send",
+                    "m.room.message",
+                    &Uuid::new_v4().to_string(),
+                ]);
+            let formatted_body =
+                markdown::to_html_with_options(msg, &Options::gfm()).unwrap_or_default();
+            client
+                .put(url)
+                .bearer_auth(access_token)
+                .json(&serde_json::json!({
+                    "body": msg,
+                    "msgtype": "m.text",
+                    "formatted_body": formatted_body,
+                    "format": "org.matrix.custom.html",
+                }))
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+        NotificationPlatformSpecifics::Webhook {
+            url,
+            secret,
+            headers,
+            body_template,
+        } => {
+            let body = webhook_body(notification, body_template.as_deref())?;
+            let mut header_map = webhook_headers(&headers.unwrap_or_default())?;
+            header_map.insert(CONTENT_TYPE, APPLICATION_JSON_HEADER.clone());
+            if let Some(secret) = secret {
+                header_map.insert(
+                    "X-Ryot-Signature",
+                    HeaderValue::from_str(&webhook_signature(&secret, &body)?)?,
+                );
+            }
+            client
+                .post(url)
+                .headers(header_map)
+                .body(body)
+                .send()
+                .await?
+                .error_for_status()?;
+        }
         NotificationPlatformSpecifics::Email { email } => {
             #[derive(Template, Serialize, Deserialize, Debug, Clone)]
             #[template(path = "generic.html")]
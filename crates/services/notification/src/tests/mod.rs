@@ -0,0 +1,94 @@
+use rstest::rstest;
+
+use super::*;
+
+fn notification() -> NotificationEvent {
+    NotificationEvent {
+        event: "metadata_published".to_owned(),
+        message: "Episode \"1\" is out\nWatch it now".to_owned(),
+        url: Some("https://ryot.io/media/item/met_test".to_owned()),
+        data: serde_json::json!({ "title": "Andor", "tags": ["sci-fi", "drama"] }),
+    }
+}
+
+fn header(name: &str, value: &str) -> NotificationWebhookHeader {
+    NotificationWebhookHeader {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+#[test]
+fn test_webhook_body_without_template_is_the_whole_event() {
+    let body = webhook_body(&notification(), None).unwrap();
+    let parsed: NotificationEvent = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed.event, "metadata_published");
+    assert_eq!(parsed.message, notification().message);
+    assert_eq!(parsed.data["title"], "Andor");
+}
+
+#[test]
+fn test_webhook_body_template_values_are_json_escaped() {
+    let body = webhook_body(
+        &notification(),
+        Some(r#"{"text": {{ message }}, "link": {{ url }}}"#),
+    )
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed["text"], "Episode \"1\" is out\nWatch it now");
+    assert_eq!(parsed["link"], "https://ryot.io/media/item/met_test");
+}
+
+#[test]
+fn test_webhook_body_template_serializes_nested_values() {
+    let body = webhook_body(
+        &notification(),
+        Some(r#"{"title": {{ data.title }}, "tags": {{ data.tags }}}"#),
+    )
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed["title"], "Andor");
+    assert_eq!(parsed["tags"], serde_json::json!(["sci-fi", "drama"]));
+}
+
+#[test]
+fn test_webhook_signature_is_hmac_sha256_of_the_body() {
+    assert_eq!(
+        webhook_signature("key", "The quick brown fox jumps over the lazy dog").unwrap(),
+        "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+}
+
+#[test]
+fn test_webhook_signature_depends_on_the_secret() {
+    let body = webhook_body(&notification(), None).unwrap();
+    assert_ne!(
+        webhook_signature("first", &body).unwrap(),
+        webhook_signature("second", &body).unwrap()
+    );
+}
+
+#[test]
+fn test_valid_webhook_platform_is_accepted() {
+    let headers = [
+        header("X-Api-Key", "secret"),
+        header("Authorization", "Bearer abc"),
+    ];
+    assert!(validate_webhook_platform(Some(&headers), Some(r#"{"text": {{ message }}}"#)).is_ok());
+    assert!(validate_webhook_platform(None, None).is_ok());
+}
+
+#[rstest]
+#[case("Invalid Header", "value")]
+#[case("X-Api-Key", "line\nbreak")]
+#[case("", "value")]
+fn test_webhook_platform_with_invalid_header_is_rejected(#[case] name: &str, #[case] value: &str) {
+    assert!(validate_webhook_platform(Some(&[header(name, value)]), None).is_err());
+}
+
+#[rstest]
+#[case(r#"{"text": {{ message }"#)]
+#[case(r#"{% if message %}{"text": {{ message }}}"#)]
+fn test_webhook_platform_with_invalid_template_is_rejected(#[case] template: &str) {
+    assert!(validate_webhook_platform(None, Some(template)).is_err());
+}
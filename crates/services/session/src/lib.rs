@@ -1,24 +1,48 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use chrono::Duration;
+use chrono::{Duration, Utc};
 use common_utils::generate_session_id;
+use database_models::{
+    application_cache,
+    prelude::{ApplicationCache, User, UserApiKey},
+    user_api_key,
+};
 use dependent_models::{
-    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput, UserSessionValue,
+    ApplicationCacheKey, ApplicationCacheKeyDiscriminants, ApplicationCacheValue,
+    ExpireCacheKeyInput, SessionClientDetails, UserSessionValue,
+};
+use enum_models::UserApiKeyScope;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    prelude::Expr,
 };
+use sha2::{Digest, Sha256};
 use supporting_service::SupportingService;
+use uuid::Uuid;
+
+const LAST_SEEN_UPDATE_INTERVAL_MINUTES: i64 = 5;
+
+/// Prefix used to tell personal API keys apart from regular session ids.
+pub const API_KEY_PREFIX: &str = "ryot_";
 
 pub async fn create_session(
     ss: &Arc<SupportingService>,
     user_id: String,
     access_link_id: Option<String>,
     expiry_duration: Option<Duration>,
+    client: SessionClientDetails,
 ) -> Result<String> {
+    let now = Utc::now();
     let session_id = generate_session_id(None);
     let cache_key = ApplicationCacheKey::UserSession(session_id.to_owned());
     let cache_value = ApplicationCacheValue::UserSession(UserSessionValue {
         user_id,
         access_link_id,
+        created_on: Some(now),
+        last_seen_on: Some(now),
+        ip_address: client.ip_address,
+        user_agent: client.user_agent,
     });
     match expiry_duration {
         Some(duration) => {
@@ -42,8 +66,127 @@ pub async fn validate_session(
     Ok(value)
 }
 
+/// Record that the session was just used. Writes are skipped when the session was seen
+/// recently so that every request does not end up updating the cache table.
+pub async fn record_session_activity(
+    ss: &Arc<SupportingService>,
+    session_id: &str,
+    session: UserSessionValue,
+    client: SessionClientDetails,
+) -> Result<()> {
+    let now = Utc::now();
+    if session
+        .last_seen_on
+        .is_some_and(|seen| now - seen < Duration::minutes(LAST_SEEN_UPDATE_INTERVAL_MINUTES))
+    {
+        return Ok(());
+    }
+    let cache_key = ApplicationCacheKey::UserSession(session_id.to_owned());
+    let cache_value = ApplicationCacheValue::UserSession(UserSessionValue {
+        last_seen_on: Some(now),
+        ip_address: client.ip_address.or(session.ip_address),
+        user_agent: client.user_agent.or(session.user_agent),
+        ..session
+    });
+    cache_service::update_value(ss, cache_key, cache_value).await
+}
+
 pub async fn invalidate_session(ss: &Arc<SupportingService>, session_id: &str) -> Result<()> {
     let cache_key = ApplicationCacheKey::UserSession(session_id.to_owned());
     cache_service::expire_key(ss, ExpireCacheKeyInput::ByKey(Box::new(cache_key))).await?;
     Ok(())
 }
+
+/// Get all the sessions of a user which have not expired yet along with their cache ids.
+/// The session ids are returned as well since they are needed to tell the current session
+/// apart, but they must never be sent to the client.
+pub async fn user_sessions(
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+) -> Result<Vec<(Uuid, String, UserSessionValue)>> {
+    let caches = ApplicationCache::find()
+        .select_only()
+        .columns([
+            application_cache::Column::Id,
+            application_cache::Column::Key,
+            application_cache::Column::Value,
+        ])
+        .filter(
+            application_cache::Column::SanitizedKey
+                .eq(ApplicationCacheKeyDiscriminants::UserSession.to_string()),
+        )
+        .filter(application_cache::Column::ExpiresAt.gt(Utc::now()))
+        .filter(Expr::cust_with_values(
+            "value -> 'UserSession' ->> 'user_id' = $1",
+            [user_id],
+        ))
+        .order_by_desc(application_cache::Column::CreatedAt)
+        .into_tuple::<(Uuid, String, serde_json::Value)>()
+        .all(&ss.db)
+        .await?;
+    let sessions = caches
+        .into_iter()
+        .filter_map(|(id, key, value)| {
+            let ApplicationCacheKey::UserSession(session_id) = serde_json::from_str(&key).ok()?
+            else {
+                return None;
+            };
+            let ApplicationCacheValue::UserSession(session) = serde_json::from_value(value).ok()?
+            else {
+                return None;
+            };
+            Some((id, session_id, session))
+        })
+        .collect();
+    Ok(sessions)
+}
+
+/// Expire all sessions of a user, optionally keeping the one with the given session id.
+pub async fn invalidate_user_sessions(
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+    except_session_id: Option<&str>,
+) -> Result<()> {
+    for (id, session_id, _) in user_sessions(ss, user_id).await? {
+        if Some(session_id.as_str()) == except_session_id {
+            continue;
+        }
+        cache_service::expire_key(ss, ExpireCacheKeyInput::ById(id)).await?;
+    }
+    Ok(())
+}
+
+pub fn hash_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
+
+/// Generate a new API key. Only its hash is stored, so the raw value has to be shown to
+/// the user right away.
+pub fn generate_api_key() -> String {
+    format!(
+        "{API_KEY_PREFIX}{}",
+        generate_session_id(None).to_lowercase()
+    )
+}
+
+pub async fn validate_api_key(
+    ss: &Arc<SupportingService>,
+    api_key: &str,
+) -> Result<Option<(String, Vec<UserApiKeyScope>)>> {
+    let Some((key, Some(user))) = UserApiKey::find()
+        .filter(user_api_key::Column::TokenHash.eq(hash_api_key(api_key)))
+        .find_also_related(User)
+        .one(&ss.db)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if key.is_revoked.unwrap_or_default() || user.is_disabled.unwrap_or_default() {
+        return Ok(None);
+    }
+    let scopes = key.scopes.clone();
+    let mut key: user_api_key::ActiveModel = key.into();
+    key.last_used_on = ActiveValue::Set(Some(Utc::now()));
+    key.update(&ss.db).await?;
+    Ok(Some((user.id, scopes)))
+}
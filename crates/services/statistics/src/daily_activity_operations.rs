@@ -197,6 +197,14 @@ pub async fn get_daily_user_activities(
             daily_user_activity::Column::ComicBookPages.sum(),
             "total_comic_book_pages",
         )
+        .column_as(
+            daily_user_activity::Column::BoardGameCount.sum(),
+            "board_game_count",
+        )
+        .column_as(
+            daily_user_activity::Column::BoardGameDuration.sum(),
+            "total_board_game_duration",
+        )
         .column_as(
             daily_user_activity::Column::VisualNovelCount.sum(),
             "visual_novel_count",
@@ -6,6 +6,8 @@ use common_models::StringIdObject;
 use database_models::{access_link, prelude::AccessLink, prelude::User, user};
 use database_utils::{get_enabled_users_query, server_key_validation_guard};
 use dependent_core_utils::is_server_key_validated;
+use dependent_models::SessionClientDetails;
+use enum_models::UserAuditEventLot;
 use media_models::{
     CreateAccessLinkInput, GenerateUserImpersonationLinkInput,
     GenerateUserImpersonationLinkResponse, ProcessAccessLinkError, ProcessAccessLinkErrorVariant,
@@ -16,12 +18,15 @@ use sea_orm::{
     QueryFilter,
 };
 use supporting_service::SupportingService;
+use user_models::UserAuditLogInformation;
 
-pub async fn create_access_link(
+use crate::audit_log_operations::{client_information, record_audit_event};
+
+async fn insert_access_link(
     ss: &Arc<SupportingService>,
     input: CreateAccessLinkInput,
     user_id: String,
-) -> Result<StringIdObject> {
+) -> Result<access_link::Model> {
     server_key_validation_guard(is_server_key_validated(ss).await?).await?;
     let new_link = access_link::ActiveModel {
         user_id: ActiveValue::Set(user_id),
@@ -33,13 +38,33 @@ pub async fn create_access_link(
         is_mutation_allowed: ActiveValue::Set(input.is_mutation_allowed),
         ..Default::default()
     };
-    let link = new_link.insert(&ss.db).await?;
+    Ok(new_link.insert(&ss.db).await?)
+}
+
+pub async fn create_access_link(
+    ss: &Arc<SupportingService>,
+    input: CreateAccessLinkInput,
+    user_id: String,
+) -> Result<StringIdObject> {
+    let link = insert_access_link(ss, input, user_id).await?;
+    let information = UserAuditLogInformation {
+        entity_id: Some(link.id.clone()),
+        ..Default::default()
+    };
+    record_audit_event(
+        ss,
+        Some(link.user_id),
+        UserAuditEventLot::AccessLinkCreated,
+        information,
+    )
+    .await?;
     Ok(StringIdObject { id: link.id })
 }
 
 pub async fn process_access_link(
     ss: &Arc<SupportingService>,
     input: ProcessAccessLinkInput,
+    client: SessionClientDetails,
 ) -> Result<ProcessAccessLinkResult> {
     let maybe_link = match input {
         ProcessAccessLinkInput::Id(id) => AccessLink::find_by_id(id).one(&ss.db).await?,
@@ -87,11 +112,23 @@ pub async fn process_access_link(
             error: ProcessAccessLinkErrorVariant::Revoked,
         }));
     }
+    let information = UserAuditLogInformation {
+        entity_id: Some(link.id.clone()),
+        ..client_information(&client)
+    };
+    record_audit_event(
+        ss,
+        Some(link.user_id.clone()),
+        UserAuditEventLot::AccessLinkUsed,
+        information,
+    )
+    .await?;
     let api_key = session_service::create_session(
         ss,
         link.user_id.clone(),
         Some(link.id.clone()),
         link.expires_on.map(|s| s - Utc::now()),
+        client,
     )
     .await?;
     let mut issued_tokens = link.issued_tokens.clone();
@@ -128,7 +165,19 @@ pub async fn generate_impersonation_link(
         name: format!("Admin Impersonation - {}", timestamp),
         ..Default::default()
     };
-    let result = create_access_link(ss, access_link_input, input.user_id).await?;
-    let impersonation_url = format!("{}/_s/{}", ss.config.frontend.url, result.id);
+    // The impersonation event is recorded instead of the usual access link event.
+    let link = insert_access_link(ss, access_link_input, input.user_id.clone()).await?;
+    let information = UserAuditLogInformation {
+        entity_id: Some(link.id.clone()),
+        ..Default::default()
+    };
+    record_audit_event(
+        ss,
+        Some(input.user_id),
+        UserAuditEventLot::ImpersonationLinkGenerated,
+        information,
+    )
+    .await?;
+    let impersonation_url = format!("{}/_s/{}", ss.config.frontend.url, link.id);
     Ok(GenerateUserImpersonationLinkResponse { impersonation_url })
 }
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use database_models::{prelude::UserApiKey, user_api_key};
+use media_models::{CreateUserApiKeyInput, CreateUserApiKeyResponse};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder,
+};
+use session_service::{generate_api_key, hash_api_key};
+use supporting_service::SupportingService;
+
+pub async fn user_api_keys(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<Vec<user_api_key::Model>> {
+    let keys = UserApiKey::find()
+        .filter(user_api_key::Column::UserId.eq(user_id))
+        .order_by_desc(user_api_key::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(keys)
+}
+
+pub async fn create_user_api_key(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: CreateUserApiKeyInput,
+) -> Result<CreateUserApiKeyResponse> {
+    if input.scopes.is_empty() {
+        bail!("At least one scope is required");
+    }
+    let api_key = generate_api_key();
+    let new_key = user_api_key::ActiveModel {
+        name: ActiveValue::Set(input.name),
+        user_id: ActiveValue::Set(user_id),
+        scopes: ActiveValue::Set(input.scopes),
+        token_hash: ActiveValue::Set(hash_api_key(&api_key)),
+        ..Default::default()
+    };
+    let key = new_key.insert(&ss.db).await?;
+    Ok(CreateUserApiKeyResponse {
+        api_key,
+        id: key.id,
+    })
+}
+
+pub async fn revoke_user_api_key(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    api_key_id: String,
+) -> Result<bool> {
+    let Some(key) = UserApiKey::find_by_id(api_key_id)
+        .filter(user_api_key::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("API key does not exist");
+    };
+    let mut key = key.into_active_model();
+    key.is_revoked = ActiveValue::Set(Some(true));
+    key.update(&ss.db).await?;
+    Ok(true)
+}
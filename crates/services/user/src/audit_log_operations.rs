@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use common_models::SearchDetails;
+use database_models::{prelude::UserAuditLog, user_audit_log};
+use database_utils::{admin_account_guard, extract_pagination_params};
+use dependent_models::{SearchResults, SessionClientDetails};
+use enum_models::UserAuditEventLot;
+use media_models::{LoginError, LoginErrorVariant, LoginResult};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ItemsAndPagesNumber, PaginatorTrait,
+    QueryFilter, QueryOrder, QueryTrait,
+};
+use supporting_service::SupportingService;
+use user_models::{UserAuditLogInformation, UserAuditLogsInput};
+
+pub fn client_information(client: &SessionClientDetails) -> UserAuditLogInformation {
+    UserAuditLogInformation {
+        ip_address: client.ip_address.clone(),
+        user_agent: client.user_agent.clone(),
+        ..Default::default()
+    }
+}
+
+pub async fn record_audit_event(
+    ss: &Arc<SupportingService>,
+    user_id: Option<String>,
+    lot: UserAuditEventLot,
+    information: UserAuditLogInformation,
+) -> Result<()> {
+    let event = user_audit_log::ActiveModel {
+        lot: ActiveValue::Set(lot),
+        user_id: ActiveValue::Set(user_id),
+        information: ActiveValue::Set(information),
+        ..Default::default()
+    };
+    event.insert(&ss.db).await?;
+    Ok(())
+}
+
+pub async fn record_login(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    client: &SessionClientDetails,
+) -> Result<()> {
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::Login,
+        client_information(client),
+    )
+    .await
+}
+
+/// Record the failed login attempt and return the error to send back to the client.
+pub async fn record_failed_login(
+    ss: &Arc<SupportingService>,
+    user_id: Option<String>,
+    username: Option<String>,
+    client: &SessionClientDetails,
+    error: LoginErrorVariant,
+) -> Result<LoginResult> {
+    let information = UserAuditLogInformation {
+        username,
+        reason: Some(format!("{error:?}")),
+        ..client_information(client)
+    };
+    record_audit_event(ss, user_id, UserAuditEventLot::LoginFailed, information).await?;
+    Ok(LoginResult::Error(LoginError { error }))
+}
+
+pub async fn user_audit_logs(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: UserAuditLogsInput,
+) -> Result<SearchResults<user_audit_log::Model>> {
+    let all_users = input.all_users.unwrap_or_default();
+    if all_users {
+        admin_account_guard(&user_id, ss).await?;
+    }
+    let (take, page) = extract_pagination_params(input.search, &user_id, ss).await?;
+    let paginator = UserAuditLog::find()
+        .apply_if((!all_users).then_some(&user_id), |query, v| {
+            query.filter(user_audit_log::Column::UserId.eq(v))
+        })
+        .apply_if(input.lot, |query, v| {
+            query.filter(user_audit_log::Column::Lot.eq(v))
+        })
+        .order_by_desc(user_audit_log::Column::CreatedOn)
+        .paginate(&ss.db, take);
+    let ItemsAndPagesNumber {
+        number_of_items,
+        number_of_pages,
+    } = paginator.num_items_and_pages().await?;
+    Ok(SearchResults {
+        items: paginator.fetch_page(page - 1).await?,
+        details: SearchDetails {
+            total_items: number_of_items,
+            next_page: (page < number_of_pages).then(|| page + 1),
+        },
+    })
+}
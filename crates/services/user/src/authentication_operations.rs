@@ -1,31 +1,62 @@
 use std::sync::Arc;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use chrono::Utc;
 use common_models::StringIdObject;
+use common_utils::generate_session_id;
 use database_models::{prelude::User, user};
 use database_utils::{revoke_access_link as db_revoke_access_link, user_by_id};
-use dependent_models::{UserDetails, UserDetailsResult};
+use dependent_models::{ExpireCacheKeyInput, SessionClientDetails, UserDetails, UserDetailsResult};
+use enum_models::UserAuditEventLot;
 use media_models::{
-    ApiKeyResponse, AuthUserInput, LoginError, LoginErrorVariant, LoginResult, PasswordUserInput,
+    ApiKeyResponse, AuthUserInput, LoginAttemptSubject, LoginError, LoginErrorVariant, LoginResult,
+    PasswordUserInput, RegisterResult, RegisterUserInput, UserSession,
 };
 use media_models::{UserDetailsError, UserDetailsErrorVariant};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    prelude::Uuid,
 };
 use supporting_service::SupportingService;
+use user_models::UserAuditLogInformation;
 
-pub async fn generate_auth_token(ss: &Arc<SupportingService>, user_id: String) -> Result<String> {
-    let session_id = session_service::create_session(ss, user_id, None, None).await?;
+use crate::{
+    audit_log_operations::{record_audit_event, record_failed_login, record_login},
+    ldap_operations::authenticate_ldap_user,
+    login_attempt_operations::{
+        clear_failed_login_attempts, login_blocked_until, record_failed_login_attempt,
+    },
+    user_management_operations::register_user,
+};
+
+pub async fn generate_auth_token(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    client: SessionClientDetails,
+) -> Result<String> {
+    let session_id = session_service::create_session(ss, user_id, None, None, client).await?;
     Ok(session_id)
 }
 
 pub async fn revoke_access_link(
     ss: &Arc<SupportingService>,
+    user_id: String,
     access_link_id: String,
 ) -> Result<bool> {
-    db_revoke_access_link(access_link_id, ss).await
+    db_revoke_access_link(access_link_id.clone(), ss).await?;
+    let information = UserAuditLogInformation {
+        entity_id: Some(access_link_id),
+        ..Default::default()
+    };
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::AccessLinkRevoked,
+        information,
+    )
+    .await?;
+    Ok(true)
 }
 
 pub async fn user_details(
@@ -40,7 +71,23 @@ pub async fn user_details(
             }));
         }
     };
-    let user = user_by_id(&session.user_id, ss).await?;
+    build_user_details(ss, &session.user_id, session.access_link_id).await
+}
+
+/// Details of the user a personal API key belongs to. API keys do not have a session.
+pub async fn api_key_user_details(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<UserDetailsResult> {
+    build_user_details(ss, user_id, None).await
+}
+
+async fn build_user_details(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    access_link_id: Option<String>,
+) -> Result<UserDetailsResult> {
+    let user = user_by_id(user_id, ss).await?;
     let details = UserDetails {
         id: user.id,
         lot: user.lot,
@@ -48,7 +95,8 @@ pub async fn user_details(
         preferences: user.preferences,
         is_disabled: user.is_disabled,
         oidc_issuer_id: user.oidc_issuer_id,
-        access_link_id: session.access_link_id,
+        has_password: user.password.is_some(),
+        access_link_id,
         extra_information: user.extra_information,
         times_two_factor_backup_codes_used: user.two_factor_information.as_ref().map(|info| {
             info.backup_codes
@@ -60,22 +108,88 @@ pub async fn user_details(
     Ok(UserDetailsResult::Ok(Box::new(details)))
 }
 
-pub async fn login_user(ss: &Arc<SupportingService>, input: AuthUserInput) -> Result<LoginResult> {
-    let filter = match input.clone() {
-        AuthUserInput::Oidc(input) => user::Column::OidcIssuerId.eq(input.issuer_id),
-        AuthUserInput::Password(input) => user::Column::Name.eq(input.username),
+pub async fn login_user(
+    ss: &Arc<SupportingService>,
+    input: AuthUserInput,
+    client: SessionClientDetails,
+) -> Result<LoginResult> {
+    let AuthUserInput::Password(PasswordUserInput { username, .. }) = &input else {
+        return authenticate_user(ss, input, client).await;
     };
-    let Some(user) = User::find().filter(filter).one(&ss.db).await? else {
-        return Ok(LoginResult::Error(LoginError {
-            error: LoginErrorVariant::UsernameDoesNotExist,
-        }));
+    let username = username.clone();
+    if login_blocked_until(ss, &username, &client).await?.is_some() {
+        return record_failed_login(
+            ss,
+            None,
+            Some(username),
+            &client,
+            LoginErrorVariant::AccountLocked,
+        )
+        .await;
+    }
+    let result = authenticate_user(ss, input, client.clone()).await?;
+    match &result {
+        LoginResult::Error(LoginError {
+            error: LoginErrorVariant::CredentialsMismatch | LoginErrorVariant::UsernameDoesNotExist,
+        }) => record_failed_login_attempt(ss, &username, &client).await?,
+        LoginResult::Ok(_) | LoginResult::TwoFactorRequired(_) => {
+            clear_failed_login_attempts(ss, LoginAttemptSubject::Username(username)).await?;
+        }
+        LoginResult::Error(_) => {}
+    }
+    Ok(result)
+}
+
+async fn authenticate_user(
+    ss: &Arc<SupportingService>,
+    input: AuthUserInput,
+    client: SessionClientDetails,
+) -> Result<LoginResult> {
+    let ldap_user = match &input {
+        AuthUserInput::Password(PasswordUserInput { username, password })
+            if ss.config.server.ldap.is_enabled() =>
+        {
+            authenticate_ldap_user(ss, username, password).await?
+        }
+        _ => None,
+    };
+    let username = match &input {
+        AuthUserInput::Password(input) => Some(input.username.clone()),
+        AuthUserInput::Oidc(_) => None,
+    };
+    let is_ldap_user = ldap_user.is_some();
+    let user = match ldap_user {
+        Some(user) => user,
+        None => {
+            let filter = match input.clone() {
+                AuthUserInput::Oidc(input) => user::Column::OidcIssuerId.eq(input.issuer_id),
+                AuthUserInput::Password(input) => user::Column::Name.eq(input.username),
+            };
+            let Some(user) = User::find().filter(filter).one(&ss.db).await? else {
+                return record_failed_login(
+                    ss,
+                    None,
+                    username,
+                    &client,
+                    LoginErrorVariant::UsernameDoesNotExist,
+                )
+                .await;
+            };
+            user
+        }
     };
     if user.is_disabled.unwrap_or_default() {
-        return Ok(LoginResult::Error(LoginError {
-            error: LoginErrorVariant::AccountDisabled,
-        }));
+        return record_failed_login(
+            ss,
+            Some(user.id),
+            username,
+            &client,
+            LoginErrorVariant::AccountDisabled,
+        )
+        .await;
     }
     if ss.config.users.validate_password
+        && !is_ldap_user
         && let AuthUserInput::Password(PasswordUserInput { password, .. }) = input
     {
         if let Some(hashed_password) = &user.password {
@@ -85,14 +199,24 @@ pub async fn login_user(ss: &Arc<SupportingService>, input: AuthUserInput) -> Re
                 .verify_password(password.as_bytes(), &parsed_hash)
                 .is_err()
             {
-                return Ok(LoginResult::Error(LoginError {
-                    error: LoginErrorVariant::CredentialsMismatch,
-                }));
+                return record_failed_login(
+                    ss,
+                    Some(user.id),
+                    username,
+                    &client,
+                    LoginErrorVariant::CredentialsMismatch,
+                )
+                .await;
             }
         } else {
-            return Ok(LoginResult::Error(LoginError {
-                error: LoginErrorVariant::IncorrectProviderChosen,
-            }));
+            return record_failed_login(
+                ss,
+                Some(user.id),
+                username,
+                &client,
+                LoginErrorVariant::IncorrectProviderChosen,
+            )
+            .await;
         }
     }
     if user.two_factor_information.is_some() && ss.config.users.validate_password {
@@ -100,7 +224,70 @@ pub async fn login_user(ss: &Arc<SupportingService>, input: AuthUserInput) -> Re
             id: user.id.clone(),
         }));
     }
-    let session_id = generate_auth_token(ss, user.id.clone()).await?;
+    record_login(ss, user.id.clone(), &client).await?;
+    let session_id = generate_auth_token(ss, user.id.clone(), client).await?;
+    let mut user = user.into_active_model();
+    user.last_login_on = ActiveValue::Set(Some(Utc::now()));
+    user.update(&ss.db).await?;
+    Ok(LoginResult::Ok(ApiKeyResponse {
+        api_key: session_id,
+    }))
+}
+
+/// Log in the user whose name was sent by a trusted reverse proxy. Users that do not exist
+/// yet are created when registration is allowed.
+pub async fn login_user_via_proxy(
+    ss: &Arc<SupportingService>,
+    proxy_username: Option<String>,
+    client: SessionClientDetails,
+) -> Result<LoginResult> {
+    let Some(username) = proxy_username else {
+        bail!("Request was not authenticated by a trusted proxy");
+    };
+    let user = match User::find()
+        .filter(user::Column::Name.eq(&username))
+        .one(&ss.db)
+        .await?
+    {
+        Some(user) => user,
+        None => {
+            if !ss.config.users.allow_registration {
+                return record_failed_login(
+                    ss,
+                    None,
+                    Some(username),
+                    &client,
+                    LoginErrorVariant::UsernameDoesNotExist,
+                )
+                .await;
+            }
+            let input = RegisterUserInput {
+                data: AuthUserInput::Password(PasswordUserInput {
+                    username,
+                    password: generate_session_id(None),
+                }),
+                lot: None,
+                user_id: None,
+                admin_access_token: None,
+            };
+            let RegisterResult::Ok(registered) = register_user(ss, None, input).await? else {
+                bail!("Could not create user for the proxy authenticated username");
+            };
+            user_by_id(&registered.id, ss).await?
+        }
+    };
+    if user.is_disabled.unwrap_or_default() {
+        return record_failed_login(
+            ss,
+            Some(user.id),
+            Some(user.name),
+            &client,
+            LoginErrorVariant::AccountDisabled,
+        )
+        .await;
+    }
+    record_login(ss, user.id.clone(), &client).await?;
+    let session_id = generate_auth_token(ss, user.id.clone(), client).await?;
     let mut user = user.into_active_model();
     user.last_login_on = ActiveValue::Set(Some(Utc::now()));
     user.update(&ss.db).await?;
@@ -113,3 +300,46 @@ pub async fn logout_user(ss: &Arc<SupportingService>, session_id: String) -> Res
     session_service::invalidate_session(ss, &session_id).await?;
     Ok(true)
 }
+
+pub async fn user_sessions(
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+    current_session_id: Option<String>,
+) -> Result<Vec<UserSession>> {
+    let sessions = session_service::user_sessions(ss, user_id)
+        .await?
+        .into_iter()
+        .map(|(id, session_id, session)| UserSession {
+            id,
+            ip_address: session.ip_address,
+            user_agent: session.user_agent,
+            created_on: session.created_on,
+            last_seen_on: session.last_seen_on,
+            access_link_id: session.access_link_id,
+            is_current: current_session_id.as_ref() == Some(&session_id),
+        })
+        .collect();
+    Ok(sessions)
+}
+
+pub async fn revoke_session(
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+    session_id: Uuid,
+) -> Result<bool> {
+    let sessions = session_service::user_sessions(ss, user_id).await?;
+    if !sessions.iter().any(|(id, _, _)| *id == session_id) {
+        bail!("Session does not exist");
+    }
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ById(session_id)).await?;
+    Ok(true)
+}
+
+pub async fn revoke_all_other_sessions(
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+    current_session_id: &str,
+) -> Result<bool> {
+    session_service::invalidate_user_sessions(ss, user_id, Some(current_session_id)).await?;
+    Ok(true)
+}
@@ -1,15 +1,20 @@
 use std::sync::Arc;
 
 use anyhow::{Result, anyhow, bail};
+use argon2::{Argon2, PasswordHasher, password_hash::SaltString};
 use database_models::{integration, prelude::Integration};
 use database_utils::server_key_validation_guard;
 use dependent_core_utils::is_server_key_validated;
-use enum_models::{IntegrationLot, IntegrationProvider};
-use media_models::CreateOrUpdateUserIntegrationInput;
+use enum_models::{IntegrationLot, IntegrationProvider, UserAuditEventLot};
+use md5::{Digest, Md5};
+use media_models::{CreateOrUpdateUserIntegrationInput, IntegrationProviderSpecifics};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder,
 };
 use supporting_service::SupportingService;
+use user_models::UserAuditLogInformation;
+
+use crate::audit_log_operations::record_audit_event;
 
 pub async fn delete_user_integration(
     ss: &Arc<SupportingService>,
@@ -23,10 +28,49 @@ pub async fn delete_user_integration(
     if integration.user_id != user_id {
         bail!("Integration does not belong to the user");
     }
+    let information = UserAuditLogInformation {
+        entity_id: Some(integration.id.clone()),
+        ..Default::default()
+    };
     integration.delete(&ss.db).await?;
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::IntegrationDeleted,
+        information,
+    )
+    .await?;
     Ok(true)
 }
 
+/// KOReader authenticates with the MD5 hash of the password, so that is what gets hashed
+/// and stored. The password itself is never saved.
+fn prepare_koreader_sync_specifics(
+    specifics: Option<IntegrationProviderSpecifics>,
+    existing: Option<IntegrationProviderSpecifics>,
+) -> Result<IntegrationProviderSpecifics> {
+    let mut specifics = specifics.unwrap_or_default();
+    specifics.koreader_sync_password_hash = match specifics.koreader_sync_password.take() {
+        Some(password) if !password.is_empty() => {
+            let key = format!("{:x}", Md5::digest(password.as_bytes()));
+            let hash = Argon2::default()
+                .hash_password(key.as_bytes(), &SaltString::generate())
+                .map_err(|e| anyhow!("Could not hash the password: {e}"))?;
+            Some(hash.to_string())
+        }
+        _ => existing.and_then(|e| e.koreader_sync_password_hash),
+    };
+    if specifics
+        .koreader_sync_username
+        .as_deref()
+        .is_none_or(str::is_empty)
+        || specifics.koreader_sync_password_hash.is_none()
+    {
+        bail!("KOReader sync integrations need a username and password");
+    }
+    Ok(specifics)
+}
+
 pub async fn create_or_update_user_integration(
     ss: &Arc<SupportingService>,
     user_id: String,
@@ -59,10 +103,16 @@ pub async fn create_or_update_user_integration(
     if input.minimum_progress > input.maximum_progress {
         bail!("Minimum progress cannot be greater than maximum progress");
     }
+    let event_lot = match input.integration_id {
+        None => UserAuditEventLot::IntegrationCreated,
+        Some(_) => UserAuditEventLot::IntegrationUpdated,
+    };
+    let mut existing_provider = None;
+    let mut existing_specifics = None;
     let mut to_save = match input.integration_id {
         None => integration::ActiveModel {
             id: ActiveValue::NotSet,
-            user_id: ActiveValue::Set(user_id),
+            user_id: ActiveValue::Set(user_id.clone()),
             ..Default::default()
         },
         Some(id) => {
@@ -73,6 +123,8 @@ pub async fn create_or_update_user_integration(
             if existing.user_id != user_id {
                 bail!("Integration does not belong to the user");
             }
+            existing_provider = Some(existing.provider);
+            existing_specifics = existing.provider_specifics.clone();
             existing.into()
         }
     };
@@ -83,9 +135,21 @@ pub async fn create_or_update_user_integration(
     to_save.extra_settings = ActiveValue::Set(input.extra_settings);
     to_save.minimum_progress = ActiveValue::Set(input.minimum_progress);
     to_save.maximum_progress = ActiveValue::Set(input.maximum_progress);
-    to_save.provider_specifics = ActiveValue::Set(input.provider_specifics);
+    let mut provider_specifics = input.provider_specifics;
+    if input.provider.or(existing_provider) == Some(IntegrationProvider::KoreaderSync) {
+        provider_specifics = Some(prepare_koreader_sync_specifics(
+            provider_specifics,
+            existing_specifics,
+        )?);
+    }
+    to_save.provider_specifics = ActiveValue::Set(provider_specifics);
     to_save.sync_to_owned_collection = ActiveValue::Set(input.sync_to_owned_collection);
-    to_save.save(&ss.db).await?;
+    let saved = to_save.save(&ss.db).await?;
+    let information = UserAuditLogInformation {
+        entity_id: saved.id.try_as_ref().cloned(),
+        ..Default::default()
+    };
+    record_audit_event(ss, Some(user_id), event_lot, information).await?;
     Ok(true)
 }
 
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use common_utils::{generate_session_id, ryot_log};
+use database_models::{prelude::User, user};
+use database_utils::user_by_id;
+use enum_models::UserLot;
+use ldap3::{LdapConnAsync, LdapConnSettings, Scope, SearchEntry, dn_escape, ldap_escape};
+use media_models::{AuthUserInput, PasswordUserInput, RegisterResult, RegisterUserInput};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    prelude::Expr,
+};
+use supporting_service::SupportingService;
+
+use crate::user_management_operations::register_user;
+
+struct LdapAccount {
+    is_admin: bool,
+}
+
+/// Bind as the user and look up their entry. Returns `None` when the credentials are
+/// rejected or the entry does not match the configured search filter.
+async fn bind_ldap_user(
+    ss: &Arc<SupportingService>,
+    username: &str,
+    password: &str,
+) -> Result<Option<LdapAccount>> {
+    let config = &ss.config.server.ldap;
+    let settings = LdapConnSettings::new().set_starttls(config.starttls);
+    let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &config.url).await?;
+    ldap3::drive!(conn);
+
+    let bind_dn = config
+        .bind_dn_template
+        .replace("{username}", &dn_escape(username));
+    if ldap
+        .simple_bind(&bind_dn, password)
+        .await?
+        .success()
+        .is_err()
+    {
+        ldap.unbind().await.ok();
+        return Ok(None);
+    }
+
+    let filter = config
+        .search_filter
+        .replace("{username}", &ldap_escape(username));
+    let (base, scope) = match config.base_dn.is_empty() {
+        true => (bind_dn.as_str(), Scope::Base),
+        false => (config.base_dn.as_str(), Scope::Subtree),
+    };
+    let (entries, _) = ldap
+        .search(base, scope, &filter, vec![config.group_attribute.as_str()])
+        .await?
+        .success()?;
+    ldap.unbind().await.ok();
+
+    let Some(entry) = entries.into_iter().next() else {
+        return Ok(None);
+    };
+    let entry = SearchEntry::construct(entry);
+    let is_admin = !config.admin_group_dn.is_empty()
+        && entry
+            .attrs
+            .get(&config.group_attribute)
+            .is_some_and(|groups| {
+                groups
+                    .iter()
+                    .any(|g| g.eq_ignore_ascii_case(&config.admin_group_dn))
+            });
+    Ok(Some(LdapAccount { is_admin }))
+}
+
+/// Authenticate the user against the configured LDAP server. Users are created the first
+/// time they login and their lot is kept in sync with the admin group on every login. Only
+/// accounts that were provisioned by LDAP are used, so a directory entry can never take
+/// over a local account that happens to have the same name.
+pub async fn authenticate_ldap_user(
+    ss: &Arc<SupportingService>,
+    username: &str,
+    password: &str,
+) -> Result<Option<user::Model>> {
+    if password.is_empty() {
+        return Ok(None);
+    }
+    let account = match bind_ldap_user(ss, username, password).await {
+        Ok(Some(account)) => account,
+        Ok(None) => return Ok(None),
+        Err(e) => {
+            ryot_log!(warn, "LDAP authentication failed for {}: {:?}", username, e);
+            return Ok(None);
+        }
+    };
+    let lot = match account.is_admin {
+        true => UserLot::Admin,
+        false => UserLot::Normal,
+    };
+    let user = match User::find()
+        .filter(Expr::cust_with_values(
+            "extra_information ->> 'ldap_username' = $1",
+            [username],
+        ))
+        .one(&ss.db)
+        .await?
+    {
+        Some(user) => user,
+        None => {
+            if User::find()
+                .filter(user::Column::Name.eq(username))
+                .one(&ss.db)
+                .await?
+                .is_some()
+            {
+                ryot_log!(
+                    warn,
+                    "Not logging in LDAP user {} since a local user with that name exists",
+                    username
+                );
+                return Ok(None);
+            }
+            let input = RegisterUserInput {
+                data: AuthUserInput::Password(PasswordUserInput {
+                    username: username.to_owned(),
+                    password: generate_session_id(None),
+                }),
+                lot: Some(lot),
+                user_id: None,
+                admin_access_token: Some(ss.config.server.admin_access_token.clone()),
+            };
+            let RegisterResult::Ok(registered) = register_user(ss, None, input).await? else {
+                bail!("Could not create user for the LDAP authenticated username");
+            };
+            let user = user_by_id(&registered.id, ss).await?;
+            let mut extra_information = user.extra_information.clone().unwrap_or_default();
+            extra_information.ldap_username = Some(username.to_owned());
+            let mut user = user.into_active_model();
+            user.extra_information = ActiveValue::Set(Some(extra_information));
+            let user = user.update(&ss.db).await?;
+            ryot_log!(debug, "Provisioned LDAP user {}", username);
+            return Ok(Some(user));
+        }
+    };
+    if ss.config.server.ldap.admin_group_dn.is_empty() || user.lot == lot {
+        return Ok(Some(user));
+    }
+    let mut user = user.into_active_model();
+    user.lot = ActiveValue::Set(lot);
+    let user = user.update(&ss.db).await?;
+    Ok(Some(user))
+}
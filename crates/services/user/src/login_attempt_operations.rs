@@ -0,0 +1,139 @@
+use std::{net::IpAddr, sync::Arc};
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use common_utils::ryot_log;
+use database_models::{prelude::User, user};
+use database_utils::admin_account_guard;
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput, SessionClientDetails,
+    UserLoginFailedAttemptsCacheValue,
+};
+use dependent_notification_utils::send_notification_for_user;
+use enum_models::UserNotificationContent;
+use media_models::LoginAttemptSubject;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, prelude::DateTimeUtc};
+use supporting_service::SupportingService;
+
+fn get_subjects(username: &str, client: &SessionClientDetails) -> Vec<LoginAttemptSubject> {
+    let mut subjects = vec![LoginAttemptSubject::Username(username.to_owned())];
+    // A loopback address belongs to the frontend or a local reverse proxy which is not
+    // trusted to forward the address of the client. Locking it out would lock out everyone.
+    if let Some(ip_address) = &client.ip_address
+        && !ip_address
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.to_canonical().is_loopback())
+    {
+        subjects.push(LoginAttemptSubject::IpAddress(ip_address.clone()));
+    }
+    subjects
+}
+
+async fn get_failed_attempts(
+    ss: &Arc<SupportingService>,
+    subject: LoginAttemptSubject,
+) -> Option<(uuid::Uuid, UserLoginFailedAttemptsCacheValue)> {
+    cache_service::get_value::<UserLoginFailedAttemptsCacheValue>(
+        ss,
+        ApplicationCacheKey::UserLoginFailedAttempts(subject),
+    )
+    .await
+}
+
+/// Get the time until which logins for the username or from the client are blocked.
+pub async fn login_blocked_until(
+    ss: &Arc<SupportingService>,
+    username: &str,
+    client: &SessionClientDetails,
+) -> Result<Option<DateTimeUtc>> {
+    let now = Utc::now();
+    let mut blocked_until = None;
+    for subject in get_subjects(username, client) {
+        if let Some((_, attempts)) = get_failed_attempts(ss, subject).await
+            && attempts.blocked_until > now
+        {
+            blocked_until = blocked_until.max(Some(attempts.blocked_until));
+        }
+    }
+    Ok(blocked_until)
+}
+
+/// Every failed attempt doubles the time before the next one is allowed. Once the maximum
+/// number of attempts is reached, logins are locked for the configured duration.
+pub async fn record_failed_login_attempt(
+    ss: &Arc<SupportingService>,
+    username: &str,
+    client: &SessionClientDetails,
+) -> Result<()> {
+    let config = &ss.config.users;
+    let lockout = Duration::minutes(config.login_lockout_duration_minutes);
+    for subject in get_subjects(username, client) {
+        let failed_attempts = get_failed_attempts(ss, subject.clone())
+            .await
+            .map(|(_, attempts)| attempts.failed_attempts)
+            .unwrap_or_default()
+            + 1;
+        let is_locked = failed_attempts >= config.login_max_failed_attempts;
+        let backoff = match is_locked {
+            true => lockout,
+            false => 2_i64
+                .checked_pow(failed_attempts - 1)
+                .and_then(Duration::try_seconds)
+                .map_or(lockout, |backoff| backoff.min(lockout)),
+        };
+        cache_service::set_key(
+            ss,
+            ApplicationCacheKey::UserLoginFailedAttempts(subject.clone()),
+            ApplicationCacheValue::UserLoginFailedAttempts(UserLoginFailedAttemptsCacheValue {
+                failed_attempts,
+                blocked_until: Utc::now() + backoff,
+            }),
+        )
+        .await?;
+        if failed_attempts != config.login_max_failed_attempts {
+            continue;
+        }
+        ryot_log!(debug, "Locking out logins for {:?}", subject);
+        let LoginAttemptSubject::Username(username) = subject else {
+            continue;
+        };
+        let Some(user) = User::find()
+            .filter(user::Column::Name.eq(username))
+            .one(&ss.db)
+            .await?
+        else {
+            continue;
+        };
+        send_notification_for_user(
+            &user.id,
+            ss,
+            UserNotificationContent::AccountLockedDueToFailedLogins {
+                failed_attempts,
+                ip_address: client.ip_address.clone(),
+                locked_for_minutes: config.login_lockout_duration_minutes,
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+pub async fn clear_failed_login_attempts(
+    ss: &Arc<SupportingService>,
+    subject: LoginAttemptSubject,
+) -> Result<bool> {
+    let Some((cache_id, _)) = get_failed_attempts(ss, subject).await else {
+        return Ok(false);
+    };
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
+    Ok(true)
+}
+
+pub async fn clear_login_lockout(
+    ss: &Arc<SupportingService>,
+    admin_user_id: String,
+    subject: LoginAttemptSubject,
+) -> Result<bool> {
+    admin_account_guard(&admin_user_id, ss).await?;
+    clear_failed_login_attempts(ss, subject).await
+}
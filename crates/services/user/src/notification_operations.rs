@@ -4,7 +4,7 @@ use anyhow::{Result, anyhow, bail};
 use database_models::{notification_platform, prelude::NotificationPlatform};
 use enum_models::{NotificationPlatformLot, UserNotificationContentDiscriminants};
 use media_models::{CreateUserNotificationPlatformInput, UpdateUserNotificationPlatformInput};
-use notification_service::send_notification;
+use notification_service::{NotificationEvent, send_notification, validate_webhook_platform};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable, ModelTrait,
     QueryFilter, QueryOrder,
@@ -32,6 +32,11 @@ pub async fn update_user_notification_platform(
     if let Some(e) = input.configured_events {
         db_notification.configured_events = ActiveValue::Set(e);
     }
+    if input.clear_quiet_hours.unwrap_or_default() {
+        db_notification.quiet_hours = ActiveValue::Set(None);
+    } else if let Some(q) = input.quiet_hours {
+        db_notification.quiet_hours = ActiveValue::Set(Some(q));
+    }
     db_notification.update(&ss.db).await?;
     Ok(true)
 }
@@ -66,8 +71,13 @@ pub async fn test_user_notification_platforms(
         .all(&ss.db)
         .await?;
     for platform in notifications {
-        let msg = format!("This is a test notification for platform: {}", platform.lot);
-        send_notification(&msg, &ss.config, platform.platform_specifics)
+        let notification = NotificationEvent {
+            url: None,
+            event: "TestNotification".to_owned(),
+            data: serde_json::json!({ "platform": platform.lot }),
+            message: format!("This is a test notification for platform: {}", platform.lot),
+        };
+        send_notification(&notification, &ss.config, platform.platform_specifics)
             .await
             .trace_ok();
     }
@@ -113,9 +123,23 @@ pub async fn create_user_notification_platform(
             bot_token: input.api_token.unwrap(),
             chat_id: input.chat_id.unwrap(),
         },
+        NotificationPlatformLot::Matrix => NotificationPlatformSpecifics::Matrix {
+            base_url: input.base_url.unwrap(),
+            room_id: input.chat_id.unwrap(),
+            access_token: input.api_token.unwrap(),
+        },
         NotificationPlatformLot::Email => NotificationPlatformSpecifics::Email {
             email: input.api_token.unwrap(),
         },
+        NotificationPlatformLot::Webhook => {
+            validate_webhook_platform(input.headers.as_deref(), input.body_template.as_deref())?;
+            NotificationPlatformSpecifics::Webhook {
+                headers: input.headers,
+                secret: input.api_token,
+                url: input.base_url.unwrap(),
+                body_template: input.body_template,
+            }
+        }
     };
     let description = match &specifics {
         NotificationPlatformSpecifics::Email { email } => email.to_owned(),
@@ -147,6 +171,14 @@ pub async fn create_user_notification_platform(
         NotificationPlatformSpecifics::Telegram { chat_id, .. } => {
             format!("Chat ID: {chat_id}")
         }
+        NotificationPlatformSpecifics::Matrix {
+            base_url, room_id, ..
+        } => {
+            format!("Homeserver: {base_url}, Room ID: {room_id}")
+        }
+        NotificationPlatformSpecifics::Webhook { url, .. } => {
+            format!("URL: {url}")
+        }
     };
     let notification = notification_platform::ActiveModel {
         lot: ActiveValue::Set(input.lot),
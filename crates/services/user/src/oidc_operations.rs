@@ -1,13 +1,24 @@
 use std::sync::Arc;
 
 use anyhow::{Result, anyhow, bail};
-use media_models::OidcTokenOutput;
-use oidc_utils::create_oidc_client;
+use config_definition::OidcConfig;
+use database_models::{prelude::User, user};
+use database_utils::user_by_id;
+use enum_models::UserLot;
+use media_models::{
+    AuthUserInput, OidcTokenOutput, OidcUserInput, RegisterResult, RegisterUserInput,
+};
+use oidc_utils::{OidcAdditionalClaims, create_oidc_client};
 use openidconnect::{
     AuthorizationCode, CsrfToken, Nonce, Scope, TokenResponse, core::CoreAuthenticationFlow,
 };
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+};
 use supporting_service::SupportingService;
 
+use crate::user_management_operations::register_user;
+
 fn empty_nonce_verifier(_nonce: Option<&Nonce>) -> Result<(), String> {
     Ok(())
 }
@@ -27,7 +38,10 @@ pub async fn get_oidc_redirect_url(ss: &Arc<SupportingService>) -> Result<String
     Ok(authorize_url.to_string())
 }
 
-pub async fn get_oidc_token(ss: &Arc<SupportingService>, code: String) -> Result<OidcTokenOutput> {
+async fn exchange_oidc_code(
+    ss: &Arc<SupportingService>,
+    code: String,
+) -> Result<(OidcTokenOutput, OidcAdditionalClaims)> {
     let Some((http, client)) = create_oidc_client(&ss.config).await else {
         bail!("OIDC client not configured");
     };
@@ -35,12 +49,132 @@ pub async fn get_oidc_token(ss: &Arc<SupportingService>, code: String) -> Result
         .exchange_code(AuthorizationCode::new(code))?
         .request_async(&http)
         .await?;
-    let id_token = token.id_token().unwrap();
+    let id_token = token
+        .id_token()
+        .ok_or_else(|| anyhow!("ID token not found in OIDC response"))?;
     let claims = id_token.claims(&client.id_token_verifier(), empty_nonce_verifier)?;
     let subject = claims.subject().to_string();
     let email = claims
         .email()
         .map(|e| e.to_string())
         .ok_or_else(|| anyhow!("Email not found in OIDC token claims"))?;
-    Ok(OidcTokenOutput { subject, email })
+    Ok((
+        OidcTokenOutput { subject, email },
+        claims.additional_claims().clone(),
+    ))
+}
+
+/// The changes to make to the user based on the claims of their OIDC token.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct OidcClaimMapping {
+    pub(crate) is_admin: Option<bool>,
+    pub(crate) is_disabled: Option<bool>,
+}
+
+pub(crate) fn get_claim_mapping(
+    config: &OidcConfig,
+    claims: &OidcAdditionalClaims,
+) -> OidcClaimMapping {
+    let groups = claims.string_values(&config.groups_claim);
+    let is_admin = (!config.admin_group.is_empty()).then(|| groups.contains(&config.admin_group));
+    let is_disabled =
+        (!config.disabled_group.is_empty()).then(|| groups.contains(&config.disabled_group));
+    OidcClaimMapping {
+        is_admin,
+        is_disabled,
+    }
+}
+
+/// Update the lot and disabled status of the user based on the groups in their OIDC token.
+async fn apply_oidc_claim_mapping(
+    ss: &Arc<SupportingService>,
+    user: user::Model,
+    mapping: OidcClaimMapping,
+) -> Result<()> {
+    let mut user = user.into_active_model();
+    if let Some(is_admin) = mapping.is_admin {
+        user.lot = ActiveValue::Set(match is_admin {
+            true => UserLot::Admin,
+            false => UserLot::Normal,
+        });
+    }
+    if let Some(is_disabled) = mapping.is_disabled {
+        user.is_disabled = ActiveValue::Set(Some(is_disabled));
+    }
+    user.update(&ss.db).await?;
+    Ok(())
+}
+
+pub async fn get_oidc_token(ss: &Arc<SupportingService>, code: String) -> Result<OidcTokenOutput> {
+    let (output, claims) = exchange_oidc_code(ss, code).await?;
+    let mapping = get_claim_mapping(&ss.config.server.oidc, &claims);
+    if mapping.is_admin.is_none() && mapping.is_disabled.is_none() {
+        return Ok(output);
+    }
+    let existing_user = User::find()
+        .filter(user::Column::OidcIssuerId.eq(&output.subject))
+        .one(&ss.db)
+        .await?;
+    let user = match existing_user {
+        Some(user) => user,
+        None => {
+            // The mapping has to be in place before the first login, so new users are
+            // registered right away instead of waiting for the frontend to do it.
+            let input = RegisterUserInput {
+                data: AuthUserInput::Oidc(OidcUserInput {
+                    email: output.email.clone(),
+                    issuer_id: output.subject.clone(),
+                }),
+                lot: None,
+                user_id: None,
+                admin_access_token: None,
+            };
+            let RegisterResult::Ok(registered) = register_user(ss, None, input).await? else {
+                // The frontend registers the user again and shows why it was not possible.
+                return Ok(output);
+            };
+            user_by_id(&registered.id, ss).await?
+        }
+    };
+    apply_oidc_claim_mapping(ss, user, mapping).await?;
+    Ok(output)
+}
+
+/// Link the OIDC identity of the code to an existing user so that they can login with either.
+pub async fn link_oidc_identity(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    code: String,
+) -> Result<bool> {
+    let user = user_by_id(&user_id, ss).await?;
+    if user.oidc_issuer_id.is_some() {
+        bail!("An OIDC identity is already linked to this account");
+    }
+    let (output, claims) = exchange_oidc_code(ss, code).await?;
+    let linked_elsewhere = User::find()
+        .filter(user::Column::OidcIssuerId.eq(&output.subject))
+        .one(&ss.db)
+        .await?;
+    if linked_elsewhere.is_some() {
+        bail!("This OIDC identity is already linked to another account");
+    }
+    let mut user = user.into_active_model();
+    user.oidc_issuer_id = ActiveValue::Set(Some(output.subject));
+    let user = user.update(&ss.db).await?;
+    apply_oidc_claim_mapping(ss, user, get_claim_mapping(ss, &claims)).await?;
+    Ok(true)
+}
+
+pub async fn unlink_oidc_identity(ss: &Arc<SupportingService>, user_id: String) -> Result<bool> {
+    let user = user_by_id(&user_id, ss).await?;
+    if user.oidc_issuer_id.is_none() {
+        bail!("No OIDC identity is linked to this account");
+    }
+    if user.password.is_none() {
+        bail!("Account does not have a password to login with after unlinking");
+    }
+    let mut user = user.into_active_model();
+    user.oidc_issuer_id = ActiveValue::Set(None);
+    user.update(&ss.db).await?;
+    Ok(true)
 }
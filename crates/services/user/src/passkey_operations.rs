@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use chrono::Utc;
+use common_models::{StringIdObject, UserLevelCacheKey};
+use common_utils::generate_session_id;
+use data_encoding::BASE64URL_NOPAD;
+use database_models::{
+    prelude::{User, UserPasskey},
+    user_passkey,
+};
+use database_utils::user_by_id;
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput, SessionClientDetails,
+    UserPasskeyAuthenticationCacheValue, UserPasskeyRegistrationCacheValue,
+};
+use media_models::{
+    ApiKeyResponse, FinishPasskeyAuthenticationInput, FinishPasskeyRegistrationInput,
+    LoginErrorVariant, LoginResult, StartPasskeyAuthenticationResponse,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, ModelTrait,
+    QueryFilter, QueryOrder,
+};
+use supporting_service::SupportingService;
+use uuid::Uuid;
+use webauthn_rs::{
+    Webauthn, WebauthnBuilder,
+    prelude::{
+        AuthenticationResult, DiscoverableAuthentication, DiscoverableKey, Passkey,
+        PasskeyRegistration, PublicKeyCredential, RegisterPublicKeyCredential, Url,
+    },
+};
+
+use crate::{
+    audit_log_operations::{record_failed_login, record_login},
+    authentication_operations::generate_auth_token,
+};
+
+/// The relying party is the frontend since that is where the browser runs the ceremony.
+fn get_webauthn(ss: &Arc<SupportingService>) -> Result<Webauthn> {
+    build_webauthn(&ss.config.frontend.url)
+}
+
+pub(crate) fn build_webauthn(frontend_url: &str) -> Result<Webauthn> {
+    let origin = Url::parse(frontend_url)?;
+    let rp_id = origin
+        .host_str()
+        .ok_or_else(|| anyhow!("Frontend URL does not have a host"))?
+        .to_owned();
+    let webauthn = WebauthnBuilder::new(&rp_id, &origin)?
+        .rp_name("Ryot")
+        .build()?;
+    Ok(webauthn)
+}
+
+/// WebAuthn needs a UUID to identify the user, so derive a stable one from the user id.
+pub(crate) fn get_user_handle(user_id: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, user_id.as_bytes())
+}
+
+fn get_credential_id(passkey: &Passkey) -> String {
+    BASE64URL_NOPAD.encode(passkey.cred_id())
+}
+
+fn parse_passkey(model: &user_passkey::Model) -> Result<Passkey> {
+    Ok(serde_json::from_value(model.credential.clone())?)
+}
+
+pub async fn user_passkeys(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<Vec<user_passkey::Model>> {
+    let passkeys = UserPasskey::find()
+        .filter(user_passkey::Column::UserId.eq(user_id))
+        .order_by_desc(user_passkey::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(passkeys)
+}
+
+pub async fn start_passkey_registration(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+) -> Result<serde_json::Value> {
+    let webauthn = get_webauthn(ss)?;
+    let user = user_by_id(&user_id, ss).await?;
+    let exclude_credentials = user_passkeys(ss, &user_id)
+        .await?
+        .iter()
+        .filter_map(|p| parse_passkey(p).ok())
+        .map(|p| p.cred_id().clone())
+        .collect::<Vec<_>>();
+    let (options, state) = webauthn.start_passkey_registration(
+        get_user_handle(&user.id),
+        &user.name,
+        &user.name,
+        Some(exclude_credentials),
+    )?;
+    let cache_key =
+        ApplicationCacheKey::UserPasskeyRegistration(UserLevelCacheKey { input: (), user_id });
+    let cache_value =
+        ApplicationCacheValue::UserPasskeyRegistration(UserPasskeyRegistrationCacheValue {
+            state: serde_json::to_string(&state)?,
+        });
+    cache_service::set_key(ss, cache_key, cache_value).await?;
+    Ok(serde_json::to_value(options)?)
+}
+
+pub async fn finish_passkey_registration(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: FinishPasskeyRegistrationInput,
+) -> Result<StringIdObject> {
+    let cache_key = ApplicationCacheKey::UserPasskeyRegistration(UserLevelCacheKey {
+        input: (),
+        user_id: user_id.clone(),
+    });
+    let Some((cache_id, registration)) =
+        cache_service::get_value::<UserPasskeyRegistrationCacheValue>(ss, cache_key).await
+    else {
+        bail!("Passkey registration not initiated or expired");
+    };
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
+    let state = serde_json::from_str::<PasskeyRegistration>(&registration.state)?;
+    let credential = serde_json::from_value::<RegisterPublicKeyCredential>(input.credential)?;
+    let passkey = get_webauthn(ss)?.finish_passkey_registration(&credential, &state)?;
+    let to_insert = user_passkey::ActiveModel {
+        user_id: ActiveValue::Set(user_id),
+        name: ActiveValue::Set(input.name),
+        credential_id: ActiveValue::Set(get_credential_id(&passkey)),
+        credential: ActiveValue::Set(serde_json::to_value(&passkey)?),
+        ..Default::default()
+    };
+    let inserted = to_insert.insert(&ss.db).await?;
+    Ok(StringIdObject { id: inserted.id })
+}
+
+async fn get_user_passkey(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    passkey_id: String,
+) -> Result<user_passkey::Model> {
+    UserPasskey::find_by_id(passkey_id)
+        .filter(user_passkey::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+        .ok_or_else(|| anyhow!("Passkey does not exist"))
+}
+
+pub async fn update_user_passkey(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    passkey_id: String,
+    name: String,
+) -> Result<bool> {
+    let passkey = get_user_passkey(ss, user_id, passkey_id).await?;
+    let mut passkey = passkey.into_active_model();
+    passkey.name = ActiveValue::Set(name);
+    passkey.update(&ss.db).await?;
+    Ok(true)
+}
+
+pub async fn delete_user_passkey(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    passkey_id: String,
+) -> Result<bool> {
+    let passkey = get_user_passkey(ss, user_id, passkey_id).await?;
+    passkey.delete(&ss.db).await?;
+    Ok(true)
+}
+
+/// Start a passkey login. When a user id is passed, the passkey acts as the second factor
+/// after a password login and has to belong to that user. Either way the browser is asked
+/// for any discoverable credential, so the response does not reveal whether the user exists
+/// or has registered passkeys.
+pub async fn start_passkey_authentication(
+    ss: &Arc<SupportingService>,
+    user_id: Option<String>,
+) -> Result<StartPasskeyAuthenticationResponse> {
+    let (options, state) = get_webauthn(ss)?.start_discoverable_authentication()?;
+    let challenge_id = generate_session_id(None);
+    let cache_key = ApplicationCacheKey::UserPasskeyAuthentication(challenge_id.clone());
+    let cache_value =
+        ApplicationCacheValue::UserPasskeyAuthentication(UserPasskeyAuthenticationCacheValue {
+            user_id,
+            state: serde_json::to_string(&state)?,
+        });
+    cache_service::set_key(ss, cache_key, cache_value).await?;
+    Ok(StartPasskeyAuthenticationResponse {
+        challenge_id,
+        options: serde_json::to_value(options)?,
+    })
+}
+
+/// Verify the credential returned by the browser against the passkey it was created with.
+/// The passkey has to belong to the expected user when it is used as the second factor.
+pub(crate) fn verify_passkey_authentication(
+    webauthn: &Webauthn,
+    state: DiscoverableAuthentication,
+    credential: &PublicKeyCredential,
+    passkey: &Passkey,
+    owner_id: &str,
+    expected_user_id: Option<&str>,
+) -> Result<AuthenticationResult> {
+    if expected_user_id.is_some_and(|user_id| user_id != owner_id) {
+        bail!("Passkey does not belong to the user");
+    }
+    let (user_handle, _) = webauthn.identify_discoverable_authentication(credential)?;
+    if user_handle != get_user_handle(owner_id) {
+        bail!("Passkey does not belong to the user");
+    }
+    let result = webauthn.finish_discoverable_authentication(
+        credential,
+        state,
+        &[DiscoverableKey::from(passkey)],
+    )?;
+    Ok(result)
+}
+
+pub async fn finish_passkey_authentication(
+    ss: &Arc<SupportingService>,
+    input: FinishPasskeyAuthenticationInput,
+    client: SessionClientDetails,
+) -> Result<LoginResult> {
+    let cache_key = ApplicationCacheKey::UserPasskeyAuthentication(input.challenge_id);
+    let Some((cache_id, authentication)) =
+        cache_service::get_value::<UserPasskeyAuthenticationCacheValue>(ss, cache_key).await
+    else {
+        bail!("Passkey authentication not initiated or expired");
+    };
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
+
+    let webauthn = get_webauthn(ss)?;
+    let credential = serde_json::from_value::<PublicKeyCredential>(input.credential)?;
+    let credential_id = BASE64URL_NOPAD.encode(credential.get_credential_id());
+    let Some((model, Some(user))) = UserPasskey::find()
+        .filter(user_passkey::Column::CredentialId.eq(credential_id))
+        .find_also_related(User)
+        .one(&ss.db)
+        .await?
+    else {
+        return record_failed_login(
+            ss,
+            authentication.user_id,
+            None,
+            &client,
+            LoginErrorVariant::CredentialsMismatch,
+        )
+        .await;
+    };
+    let mut passkey = parse_passkey(&model)?;
+    let state = serde_json::from_str::<DiscoverableAuthentication>(&authentication.state)?;
+    let Ok(result) = verify_passkey_authentication(
+        &webauthn,
+        state,
+        &credential,
+        &passkey,
+        &user.id,
+        authentication.user_id.as_deref(),
+    ) else {
+        return record_failed_login(
+            ss,
+            Some(user.id),
+            Some(user.name),
+            &client,
+            LoginErrorVariant::CredentialsMismatch,
+        )
+        .await;
+    };
+    if user.is_disabled.unwrap_or_default() {
+        return record_failed_login(
+            ss,
+            Some(user.id),
+            Some(user.name),
+            &client,
+            LoginErrorVariant::AccountDisabled,
+        )
+        .await;
+    }
+
+    passkey.update_credential(&result);
+    let mut model = model.into_active_model();
+    model.last_used_on = ActiveValue::Set(Some(Utc::now()));
+    model.credential = ActiveValue::Set(serde_json::to_value(&passkey)?);
+    model.update(&ss.db).await?;
+
+    record_login(ss, user.id.clone(), &client).await?;
+    let session_id = generate_auth_token(ss, user.id.clone(), client).await?;
+    let mut user = user.into_active_model();
+    user.last_login_on = ActiveValue::Set(Some(Utc::now()));
+    user.update(&ss.db).await?;
+    Ok(LoginResult::Ok(ApiKeyResponse {
+        api_key: session_id,
+    }))
+}
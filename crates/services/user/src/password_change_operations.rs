@@ -20,7 +20,7 @@ pub async fn generate_password_change_session(
 ) -> Result<String> {
     let user = user_by_id(&user_id, ss).await?;
 
-    if user.oidc_issuer_id.is_some() {
+    if user.oidc_issuer_id.is_some() && user.password.is_none() {
         bail!("Password change not available for OIDC users");
     }
 
@@ -39,6 +39,8 @@ pub async fn set_password_via_session(
     ss: &Arc<SupportingService>,
     session_id: String,
     password: String,
+    revoke_other_sessions: bool,
+    current_session_id: Option<String>,
 ) -> Result<bool> {
     let cache_key = ApplicationCacheKey::UserPasswordChangeSession(session_id);
 
@@ -52,9 +54,13 @@ pub async fn set_password_via_session(
 
     let mut user_active = user.into_active_model();
     user_active.password = ActiveValue::Set(Some(password));
-    user_active.update(&ss.db).await?;
+    let user = user_active.update(&ss.db).await?;
 
     cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
+    if revoke_other_sessions {
+        session_service::invalidate_user_sessions(ss, &user.id, current_session_id.as_deref())
+            .await?;
+    }
     Ok(true)
 }
 
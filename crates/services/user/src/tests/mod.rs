@@ -0,0 +1,2 @@
+mod oidc;
+mod passkey;
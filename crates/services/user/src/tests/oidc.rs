@@ -0,0 +1,88 @@
+use config_definition::OidcConfig;
+use oidc_utils::OidcAdditionalClaims;
+use rstest::rstest;
+use serde_json::json;
+
+use crate::oidc_operations::{OidcClaimMapping, get_claim_mapping};
+
+fn config(admin_group: &str, disabled_group: &str) -> OidcConfig {
+    OidcConfig {
+        client_id: "ryot".to_owned(),
+        client_secret: "secret".to_owned(),
+        issuer_url: "https://auth.example.com".to_owned(),
+        groups_claim: "groups".to_owned(),
+        admin_group: admin_group.to_owned(),
+        disabled_group: disabled_group.to_owned(),
+    }
+}
+
+fn claims(value: serde_json::Value) -> OidcAdditionalClaims {
+    serde_json::from_value(value).unwrap()
+}
+
+#[rstest]
+#[case(json!({ "groups": "admins" }), vec!["admins"])]
+#[case(json!({ "groups": ["admins", "users"] }), vec!["admins", "users"])]
+#[case(json!({ "groups": ["admins", 1, null, "users"] }), vec!["admins", "users"])]
+#[case(json!({ "groups": [] }), vec![])]
+#[case(json!({ "groups": 42 }), vec![])]
+#[case(json!({ "roles": ["admins"] }), vec![])]
+fn test_string_values(#[case] value: serde_json::Value, #[case] expected: Vec<&str>) {
+    assert_eq!(claims(value).string_values("groups"), expected);
+}
+
+#[test]
+fn test_nothing_is_mapped_without_groups_configured() {
+    let mapping = get_claim_mapping(&config("", ""), &claims(json!({ "groups": ["admins"] })));
+    assert_eq!(
+        mapping,
+        OidcClaimMapping {
+            is_admin: None,
+            is_disabled: None,
+        }
+    );
+}
+
+#[rstest]
+#[case(json!({ "groups": ["admins"] }), Some(true), Some(false))]
+#[case(json!({ "groups": ["users"] }), Some(false), Some(false))]
+#[case(json!({ "groups": ["admins", "disabled"] }), Some(true), Some(true))]
+#[case(json!({ "groups": "disabled" }), Some(false), Some(true))]
+#[case(json!({}), Some(false), Some(false))]
+fn test_claim_mapping(
+    #[case] value: serde_json::Value,
+    #[case] is_admin: Option<bool>,
+    #[case] is_disabled: Option<bool>,
+) {
+    let mapping = get_claim_mapping(&config("admins", "disabled"), &claims(value));
+    assert_eq!(
+        mapping,
+        OidcClaimMapping {
+            is_admin,
+            is_disabled,
+        }
+    );
+}
+
+#[test]
+fn test_removing_the_disabled_group_enables_the_user() {
+    let config = config("", "disabled");
+    let disabled = get_claim_mapping(&config, &claims(json!({ "groups": ["disabled"] })));
+    let enabled = get_claim_mapping(&config, &claims(json!({ "groups": ["users"] })));
+    assert_eq!(disabled.is_disabled, Some(true));
+    assert_eq!(enabled.is_disabled, Some(false));
+    assert_eq!(enabled.is_admin, None);
+}
+
+#[test]
+fn test_groups_are_read_from_the_configured_claim() {
+    let config = OidcConfig {
+        groups_claim: "roles".to_owned(),
+        ..config("admins", "")
+    };
+    let mapping = get_claim_mapping(
+        &config,
+        &claims(json!({ "groups": ["users"], "roles": ["admins"] })),
+    );
+    assert_eq!(mapping.is_admin, Some(true));
+}
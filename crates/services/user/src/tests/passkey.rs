@@ -0,0 +1,147 @@
+use webauthn_authenticator_rs::{WebauthnAuthenticator, softpasskey::SoftPasskey};
+use webauthn_rs::prelude::{
+    DiscoverableAuthentication, Passkey, PublicKeyCredential, Url, Webauthn,
+};
+
+use crate::passkey_operations::{build_webauthn, get_user_handle, verify_passkey_authentication};
+
+const USER_ID: &str = "usr_passkey_owner";
+const OTHER_USER_ID: &str = "usr_someone_else";
+const FRONTEND_URL: &str = "https://ryot.example.com";
+
+struct Ceremony {
+    origin: Url,
+    webauthn: Webauthn,
+    authenticator: WebauthnAuthenticator<SoftPasskey>,
+}
+
+impl Ceremony {
+    fn new() -> Self {
+        Self {
+            origin: Url::parse(FRONTEND_URL).unwrap(),
+            webauthn: build_webauthn(FRONTEND_URL).unwrap(),
+            authenticator: WebauthnAuthenticator::new(SoftPasskey::new(true)),
+        }
+    }
+
+    fn register(&mut self, user_id: &str) -> Passkey {
+        let (options, state) = self
+            .webauthn
+            .start_passkey_registration(get_user_handle(user_id), user_id, user_id, None)
+            .unwrap();
+        let credential = self
+            .authenticator
+            .do_registration(self.origin.clone(), options)
+            .unwrap();
+        self.webauthn
+            .finish_passkey_registration(&credential, &state)
+            .unwrap()
+    }
+
+    /// The software authenticator can not store resident keys, so it is told which
+    /// credential to use and the user handle is filled in the way a browser would.
+    fn authenticate(
+        &mut self,
+        passkey: &Passkey,
+        user_id: &str,
+    ) -> (DiscoverableAuthentication, PublicKeyCredential) {
+        let (mut options, state) = self.webauthn.start_discoverable_authentication().unwrap();
+        assert!(options.public_key.allow_credentials.is_empty());
+        let (known_options, _) = self
+            .webauthn
+            .start_passkey_authentication(std::slice::from_ref(passkey))
+            .unwrap();
+        options.public_key.allow_credentials = known_options.public_key.allow_credentials;
+        let mut credential = self
+            .authenticator
+            .do_authentication(self.origin.clone(), options)
+            .unwrap();
+        credential.response.user_handle = Some(get_user_handle(user_id).as_bytes().to_vec().into());
+        (state, credential)
+    }
+}
+
+#[test]
+fn test_passkey_primary_login() {
+    let mut ceremony = Ceremony::new();
+    let passkey = ceremony.register(USER_ID);
+    let (state, credential) = ceremony.authenticate(&passkey, USER_ID);
+    let result = verify_passkey_authentication(
+        &ceremony.webauthn,
+        state,
+        &credential,
+        &passkey,
+        USER_ID,
+        None,
+    )
+    .unwrap();
+    assert!(result.user_verified());
+    assert_eq!(result.cred_id(), passkey.cred_id());
+}
+
+#[test]
+fn test_passkey_second_factor() {
+    let mut ceremony = Ceremony::new();
+    let passkey = ceremony.register(USER_ID);
+    let (state, credential) = ceremony.authenticate(&passkey, USER_ID);
+    let result = verify_passkey_authentication(
+        &ceremony.webauthn,
+        state,
+        &credential,
+        &passkey,
+        USER_ID,
+        Some(USER_ID),
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_passkey_second_factor_of_other_user() {
+    let mut ceremony = Ceremony::new();
+    let passkey = ceremony.register(USER_ID);
+    let (state, credential) = ceremony.authenticate(&passkey, USER_ID);
+    let result = verify_passkey_authentication(
+        &ceremony.webauthn,
+        state,
+        &credential,
+        &passkey,
+        USER_ID,
+        Some(OTHER_USER_ID),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_passkey_with_user_handle_of_other_user() {
+    let mut ceremony = Ceremony::new();
+    let passkey = ceremony.register(USER_ID);
+    let (state, credential) = ceremony.authenticate(&passkey, OTHER_USER_ID);
+    let result = verify_passkey_authentication(
+        &ceremony.webauthn,
+        state,
+        &credential,
+        &passkey,
+        USER_ID,
+        None,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_passkey_from_other_authenticator() {
+    let mut ceremony = Ceremony::new();
+    let passkey = ceremony.register(USER_ID);
+    let mut other = Ceremony::new();
+    let other_passkey = other.register(USER_ID);
+    let (_, credential) = other.authenticate(&other_passkey, USER_ID);
+    let (state, _) = ceremony.authenticate(&passkey, USER_ID);
+    let result = verify_passkey_authentication(
+        &ceremony.webauthn,
+        state,
+        &credential,
+        &passkey,
+        USER_ID,
+        None,
+    );
+    assert!(result.is_err());
+}
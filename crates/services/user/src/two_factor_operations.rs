@@ -16,8 +16,9 @@ use data_encoding::{BASE32, BASE64};
 use database_utils::user_by_id;
 use dependent_models::{
     ApplicationCacheKey, ApplicationCacheValue, EmptyCacheValue, ExpireCacheKeyInput,
-    UserTwoFactorSetupCacheValue,
+    SessionClientDetails, UserTwoFactorSetupCacheValue,
 };
+use enum_models::UserAuditEventLot;
 use media_models::{
     ApiKeyResponse, UserTwoFactorBackupCodesResponse, UserTwoFactorInitiateResponse,
     UserTwoFactorSetupInput, UserTwoFactorVerifyInput, UserTwoFactorVerifyMethod,
@@ -29,9 +30,14 @@ use subtle::ConstantTimeEq;
 use supporting_service::SupportingService;
 use tokio::time::sleep;
 use totp_lite::{DEFAULT_STEP, Sha1, totp_custom};
-use user_models::{UserTwoFactorInformation, UserTwoFactorInformationBackupCode};
+use user_models::{
+    UserAuditLogInformation, UserTwoFactorInformation, UserTwoFactorInformationBackupCode,
+};
 
-use crate::authentication_operations::generate_auth_token;
+use crate::{
+    audit_log_operations::{client_information, record_audit_event, record_login},
+    authentication_operations::generate_auth_token,
+};
 
 const TOTP_CODE_DIGITS: u32 = 6;
 const BACKUP_CODE_LENGTH: usize = 8;
@@ -60,6 +66,7 @@ where
 pub async fn verify_two_factor(
     ss: &Arc<SupportingService>,
     input: UserTwoFactorVerifyInput,
+    client: SessionClientDetails,
 ) -> Result<VerifyTwoFactorResult> {
     let rate_limit_key = ApplicationCacheKey::UserTwoFactorRateLimit(UserLevelCacheKey {
         input: (),
@@ -113,6 +120,17 @@ pub async fn verify_two_factor(
     .await?;
 
     if !verification_result {
+        let information = UserAuditLogInformation {
+            reason: Some(format!("{:?}", VerifyTwoFactorErrorVariant::Invalid)),
+            ..client_information(&client)
+        };
+        record_audit_event(
+            ss,
+            Some(user.id),
+            UserAuditEventLot::LoginFailed,
+            information,
+        )
+        .await?;
         return Ok(VerifyTwoFactorResult::Error(VerifyTwoFactorError {
             error: VerifyTwoFactorErrorVariant::Invalid,
         }));
@@ -122,7 +140,8 @@ pub async fn verify_two_factor(
         mark_backup_code_as_used(&input.user_id, &input.code, ss).await?;
     }
 
-    let session_id = generate_auth_token(ss, input.user_id.clone()).await?;
+    record_login(ss, input.user_id.clone(), &client).await?;
+    let session_id = generate_auth_token(ss, input.user_id.clone(), client).await?;
     let mut user = user.into_active_model();
     user.last_login_on = ActiveValue::Set(Some(Utc::now()));
     user.update(&ss.db).await?;
@@ -207,6 +226,14 @@ pub async fn complete_two_factor_setup(
 
     cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
 
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::TwoFactorEnabled,
+        Default::default(),
+    )
+    .await?;
+
     Ok(UserTwoFactorBackupCodesResponse { backup_codes })
 }
 
@@ -221,6 +248,14 @@ pub async fn disable_two_factor(ss: &Arc<SupportingService>, user_id: String) ->
     user_active.two_factor_information = ActiveValue::Set(None);
     user_active.update(&ss.db).await?;
 
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::TwoFactorDisabled,
+        Default::default(),
+    )
+    .await?;
+
     Ok(true)
 }
 
@@ -242,6 +277,14 @@ pub async fn regenerate_two_factor_backup_codes(
     user_active.two_factor_information = ActiveValue::Set(Some(two_factor_info));
     user_active.update(&ss.db).await?;
 
+    record_audit_event(
+        ss,
+        Some(user_id),
+        UserAuditEventLot::TwoFactorBackupCodesRegenerated,
+        Default::default(),
+    )
+    .await?;
+
     Ok(UserTwoFactorBackupCodesResponse { backup_codes })
 }
 
@@ -8,7 +8,7 @@ use database_utils::{admin_account_guard, deploy_job_to_calculate_user_activitie
 use dependent_collection_utils::create_or_update_collection;
 use dependent_models::ExpireCacheKeyInput;
 use enum_meta::Meta;
-use enum_models::UserLot;
+use enum_models::{UserAuditEventLot, UserLot};
 use futures::try_join;
 use media_models::{
     AuthUserInput, CreateOrUpdateCollectionInput, OidcUserInput, PasswordUserInput, RegisterError,
@@ -21,9 +21,10 @@ use sea_orm::{
 };
 use sea_orm::{IntoActiveModel, Iterable};
 use supporting_service::SupportingService;
-use user_models::{UpdateUserInput, UserPreferences};
+use user_models::{UpdateUserInput, UserAuditLogInformation, UserPreferences};
 
 use crate::{
+    audit_log_operations::record_audit_event,
     password_change_operations::{build_password_change_url, generate_password_change_session},
     user_data_operations::users_list,
 };
@@ -82,7 +83,19 @@ pub async fn delete_user(
     if admin_count == 1 && u.lot == UserLot::Admin {
         return Ok(false);
     }
+    let information = UserAuditLogInformation {
+        entity_id: Some(u.id.clone()),
+        username: Some(u.name.clone()),
+        ..Default::default()
+    };
     u.delete(&ss.db).await?;
+    record_audit_event(
+        ss,
+        Some(admin_user_id),
+        UserAuditEventLot::UserDeleted,
+        information,
+    )
+    .await?;
     Ok(true)
 }
 
@@ -8,7 +8,10 @@ use common_models::{BackendError, PersonSourceSpecifics};
 use common_utils::ryot_log;
 use database_models::metadata_group::MetadataGroupWithoutId;
 use database_utils::check_token;
-use dependent_models::{MetadataSearchSourceSpecifics, PersonDetails, SearchResults};
+use dependent_models::{
+    MetadataSearchSourceSpecifics, PersonDetails, SearchResults, SessionClientDetails,
+};
+use enum_models::UserApiKeyScope;
 use media_models::{
     EntityTranslationDetails, MetadataDetails, MetadataGroupSearchItem, MetadataSearchItem,
     PartialMetadataWithoutId, PeopleSearchItem, PodcastTranslationExtraInformation,
@@ -124,6 +127,15 @@ pub trait GraphqlDependencyInjector {
         false
     }
 
+    /// The scope a personal API key needs to call this resolver. Keys with the admin
+    /// scope can call everything.
+    fn api_key_scope(&self) -> UserApiKeyScope {
+        match self.is_mutation() {
+            true => UserApiKeyScope::Admin,
+            false => UserApiKeyScope::ReadMedia,
+        }
+    }
+
     fn user_session_id_from_ctx(&self, ctx: &Context<'_>) -> GraphqlResult<String> {
         let auth_ctx = ctx.data_unchecked::<AuthContext>();
         auth_ctx
@@ -132,10 +144,31 @@ pub trait GraphqlDependencyInjector {
             .ok_or_else(|| Error::new(BackendError::NoSessionId.to_string()))
     }
 
+    fn session_client_from_ctx(&self, ctx: &Context<'_>) -> SessionClientDetails {
+        ctx.data_unchecked::<AuthContext>().client.clone()
+    }
+
+    fn proxy_username_from_ctx(&self, ctx: &Context<'_>) -> Option<String> {
+        ctx.data_unchecked::<AuthContext>().proxy_username.clone()
+    }
+
+    fn check_api_key_scope(&self, ctx: &Context<'_>) -> GraphqlResult<()> {
+        let auth_ctx = ctx.data_unchecked::<AuthContext>();
+        if let Some(scopes) = &auth_ctx.api_key_scopes {
+            let required = self.api_key_scope();
+            if !scopes.contains(&UserApiKeyScope::Admin) && !scopes.contains(&required) {
+                return Err(Error::new(BackendError::ApiKeyScopeNotAllowed.to_string()));
+            }
+        }
+        Ok(())
+    }
+
     async fn user_id_from_ctx(&self, ctx: &Context<'_>) -> GraphqlResult<String> {
         let auth_ctx = ctx.data_unchecked::<AuthContext>();
         let ss = ctx.data_unchecked::<Arc<SupportingService>>();
-        if let Some(session_id) = &auth_ctx.session_id {
+        if auth_ctx.api_key_scopes.is_some() {
+            self.check_api_key_scope(ctx)?;
+        } else if let Some(session_id) = &auth_ctx.session_id {
             check_token(session_id, self.is_mutation(), ss).await?;
         }
         auth_ctx
@@ -1,12 +1,22 @@
-use std::sync::Arc;
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 
 use anyhow::Result;
 use axum::{
     Extension, RequestPartsExt,
-    extract::FromRequestParts,
-    http::{StatusCode, header::AUTHORIZATION, request::Parts},
+    extract::{ConnectInfo, FromRequestParts},
+    http::{
+        StatusCode,
+        header::{AUTHORIZATION, USER_AGENT},
+        request::Parts,
+    },
 };
 use chrono::{NaiveDate, NaiveDateTime, Utc};
+use dependent_models::SessionClientDetails;
+use enum_models::UserApiKeyScope;
+use ipnet::IpNet;
 use media_models::{
     GraphqlSortOrder, PodcastEpisode, PodcastSpecifics, ReviewItem, ShowEpisode, ShowSeason,
     ShowSpecifics,
@@ -15,10 +25,18 @@ use rust_decimal::Decimal;
 use sea_orm::Order;
 use supporting_service::SupportingService;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Default)]
 pub struct AuthContext {
     pub user_id: Option<String>,
     pub session_id: Option<String>,
+    /// Set when the request was authenticated with a personal API key.
+    pub api_key_scopes: Option<Vec<UserApiKeyScope>>,
+    pub client: SessionClientDetails,
+    /// The username sent by a trusted reverse proxy, if any.
+    pub proxy_username: Option<String>,
 }
 
 impl<S> FromRequestParts<S> for AuthContext
@@ -28,16 +46,57 @@ where
     type Rejection = (StatusCode, &'static str);
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|h| h.to_str().ok())
+                .map(|s| s.trim().to_owned())
+                .filter(|s| !s.is_empty())
+        };
+        let peer_ip = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(peer)| peer.ip());
+        let ss = parts.extensions.get::<Arc<SupportingService>>().cloned();
+        let trusted_proxy_cidrs = ss
+            .as_ref()
+            .map(|ss| ss.config.users.trusted_proxy_cidrs.clone())
+            .unwrap_or_default();
+        let proxy_ip = proxy_address(peer_ip, header("x-forwarded-for").as_deref());
+        let from_trusted_proxy = is_trusted_proxy_address(&trusted_proxy_cidrs, proxy_ip);
+
+        // Forwarded headers can be set by anyone, so they are only used when the request
+        // comes from one of the trusted reverse proxies.
+        let forwarded_ip = from_trusted_proxy
+            .then(|| {
+                header("x-forwarded-for")
+                    .and_then(|s| forwarded_client_ip(&s, &trusted_proxy_cidrs))
+                    .or_else(|| header("x-real-ip").and_then(|s| s.parse().ok()))
+            })
+            .flatten();
         let mut ctx = AuthContext {
+            client: SessionClientDetails {
+                user_agent: header(USER_AGENT.as_str()),
+                ip_address: forwarded_ip.or(proxy_ip).map(|ip| ip.to_string()),
+            },
             ..Default::default()
         };
 
+        if let Some(ss) = &ss
+            && !ss.config.users.trusted_proxy_header.is_empty()
+            && from_trusted_proxy
+        {
+            ctx.proxy_username = header(&ss.config.users.trusted_proxy_header);
+        }
+
+        let mut token = None;
         if let Some(h) = parts.headers.get(AUTHORIZATION) {
-            ctx.session_id = h.to_str().map(|s| s.replace("Bearer ", "")).ok();
+            token = h.to_str().map(|s| s.replace("Bearer ", "")).ok();
         } else if let Some(h) = parts.headers.get("x-auth-token") {
-            ctx.session_id = h.to_str().map(String::from).ok();
+            token = h.to_str().map(String::from).ok();
         }
-        if let Some(session_id) = ctx.session_id.as_ref() {
+        if let Some(token) = token {
             let Extension(ss) = parts
                 .extract::<Extension<Arc<SupportingService>>>()
                 .await
@@ -48,8 +107,26 @@ where
                     )
                 })?;
 
-            if let Ok(Some(session)) = session_service::validate_session(&ss, session_id).await {
-                ctx.user_id = Some(session.user_id);
+            if token.starts_with(session_service::API_KEY_PREFIX) {
+                if let Ok(Some((user_id, scopes))) =
+                    session_service::validate_api_key(&ss, &token).await
+                {
+                    ctx.user_id = Some(user_id);
+                    ctx.api_key_scopes = Some(scopes);
+                }
+            } else {
+                if let Ok(Some(session)) = session_service::validate_session(&ss, &token).await {
+                    ctx.user_id = Some(session.user_id.clone());
+                    session_service::record_session_activity(
+                        &ss,
+                        &token,
+                        session,
+                        ctx.client.clone(),
+                    )
+                    .await
+                    .ok();
+                }
+                ctx.session_id = Some(token);
             }
         }
 
@@ -57,6 +134,50 @@ where
     }
 }
 
+/// The bundled proxy connects from a loopback address and relays the requests of every
+/// client. It replaces the `X-Forwarded-For` header of the requests it receives, so for
+/// requests relayed by it, the address it received the request from is used instead.
+pub(crate) fn proxy_address(
+    peer_ip: Option<IpAddr>,
+    forwarded_for: Option<&str>,
+) -> Option<IpAddr> {
+    match peer_ip {
+        Some(ip) if ip.is_loopback() => forwarded_for
+            .and_then(|s| s.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok())
+            .or(peer_ip),
+        _ => peer_ip,
+    }
+}
+
+/// Loopback addresses belong to the bundled proxy, which relays the headers of every client,
+/// so they are never trusted even when they are part of the configured ranges.
+pub(crate) fn is_trusted_proxy_address(cidrs: &[String], ip: Option<IpAddr>) -> bool {
+    ip.is_some_and(|ip| !ip.is_loopback() && is_trusted_proxy(cidrs, ip))
+}
+
+fn is_trusted_proxy(cidrs: &[String], ip: IpAddr) -> bool {
+    cidrs
+        .iter()
+        .filter_map(|cidr| cidr.parse::<IpNet>().ok())
+        .any(|net| net.contains(&ip))
+}
+
+/// Every proxy appends the address it received the request from, so the client is the
+/// right-most address that does not belong to one of the trusted proxies.
+fn forwarded_client_ip(forwarded_for: &str, cidrs: &[String]) -> Option<IpAddr> {
+    let addresses = forwarded_for
+        .split(',')
+        .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>();
+    addresses
+        .iter()
+        .rev()
+        .find(|ip| !is_trusted_proxy(cidrs, **ip))
+        .or(addresses.first())
+        .copied()
+}
+
 pub fn get_current_time(timezone: &chrono_tz::Tz) -> NaiveDateTime {
     Utc::now().with_timezone(timezone).naive_local()
 }
@@ -0,0 +1,50 @@
+use std::net::IpAddr;
+
+use rstest::rstest;
+
+use crate::{is_trusted_proxy_address, proxy_address};
+
+const TRUSTED_PROXY_CIDRS: [&str; 2] = ["172.18.0.0/16", "127.0.0.1/32"];
+
+fn ip(address: &str) -> Option<IpAddr> {
+    address.parse().ok()
+}
+
+#[rstest]
+#[case("172.18.0.5", None, "172.18.0.5")]
+#[case("10.0.0.1", Some("172.18.0.5"), "10.0.0.1")]
+#[case("127.0.0.1", None, "127.0.0.1")]
+#[case("127.0.0.1", Some("172.18.0.5"), "172.18.0.5")]
+#[case("127.0.0.1", Some("172.18.0.5, 10.0.0.1"), "10.0.0.1")]
+#[case("::1", Some("10.0.0.1"), "10.0.0.1")]
+fn test_proxy_address(
+    #[case] peer: &str,
+    #[case] forwarded_for: Option<&str>,
+    #[case] expected: &str,
+) {
+    assert_eq!(proxy_address(ip(peer), forwarded_for), ip(expected));
+}
+
+#[rstest]
+#[case("172.18.0.5", true)]
+#[case("10.0.0.1", false)]
+#[case("127.0.0.1", false)]
+#[case("::1", false)]
+fn test_is_trusted_proxy_address(#[case] address: &str, #[case] expected: bool) {
+    let cidrs = TRUSTED_PROXY_CIDRS.map(String::from);
+    assert_eq!(is_trusted_proxy_address(&cidrs, ip(address)), expected);
+}
+
+#[test]
+fn test_bundled_proxy_relaying_a_trusted_proxy_is_trusted() {
+    let cidrs = TRUSTED_PROXY_CIDRS.map(String::from);
+    let address = proxy_address(ip("127.0.0.1"), Some("172.18.0.5"));
+    assert!(is_trusted_proxy_address(&cidrs, address));
+}
+
+#[test]
+fn test_bundled_proxy_relaying_a_local_client_is_not_trusted() {
+    let cidrs = TRUSTED_PROXY_CIDRS.map(String::from);
+    let address = proxy_address(ip("127.0.0.1"), Some("127.0.0.1"));
+    assert!(!is_trusted_proxy_address(&cidrs, address));
+}
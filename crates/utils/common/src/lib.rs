@@ -55,23 +55,25 @@ pub fn compute_next_page(page: u64, total_items: u64) -> Option<u64> {
     compute_next_page_with_size(page, total_items, PAGE_SIZE)
 }
 
-pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 13] = [
+pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 15] = [
     MediaSource::Vndb,
     MediaSource::Igdb,
     MediaSource::Tmdb,
     MediaSource::Tvdb,
+    MediaSource::Kitsu,
     MediaSource::Spotify,
     MediaSource::Anilist,
     MediaSource::Audible,
     MediaSource::Hardcover,
     MediaSource::GiantBomb,
+    MediaSource::ComicVine,
     MediaSource::MusicBrainz,
     MediaSource::Openlibrary,
     MediaSource::MangaUpdates,
     MediaSource::YoutubeMusic,
 ];
 
-pub const MEDIA_SOURCES_WITHOUT_RECOMMENDATIONS: [MediaSource; 7] = [
+pub const MEDIA_SOURCES_WITHOUT_RECOMMENDATIONS: [MediaSource; 11] = [
     MediaSource::Tvdb,
     MediaSource::Vndb,
     MediaSource::Itunes,
@@ -79,6 +81,10 @@ pub const MEDIA_SOURCES_WITHOUT_RECOMMENDATIONS: [MediaSource; 7] = [
     MediaSource::Spotify,
     MediaSource::MusicBrainz,
     MediaSource::GoogleBooks,
+    MediaSource::BoardGameGeek,
+    MediaSource::ComicVine,
+    MediaSource::PodcastIndex,
+    MediaSource::Rss,
 ];
 
 /// Logging macro that targets the "ryot" tracing target
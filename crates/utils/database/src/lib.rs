@@ -167,6 +167,7 @@ pub async fn entity_in_collections_with_details(
                 collection_id: membership.origin_collection_id.clone(),
                 created_on: membership.collection_to_entity_created_on,
                 information: membership.collection_to_entity_information,
+                added_by_user_id: membership.collection_to_entity_added_by_user_id,
                 last_updated_on: membership.collection_to_entity_last_updated_on,
             },
         })
@@ -21,9 +21,9 @@ use dependent_models::{ApplicationCacheKeyDiscriminants, ExpireCacheKeyInput};
 use enum_models::{EntityLot, MediaLot, SeenState};
 use futures::{TryStreamExt, try_join};
 use media_models::{
-    AudioBookSpecifics, BookSpecifics, ComicBookSpecifics, MovieSpecifics, MusicSpecifics,
-    PodcastSpecifics, SeenPodcastExtraInformation, SeenShowExtraInformation, ShowSpecifics,
-    VideoGameSpecifics, VisualNovelSpecifics,
+    AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicBookSpecifics, MovieSpecifics,
+    MusicSpecifics, PodcastSpecifics, SeenPodcastExtraInformation, SeenShowExtraInformation,
+    ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
 };
 use rust_decimal::{Decimal, dec, prelude::ToPrimitive};
 use sea_orm::{
@@ -55,6 +55,7 @@ pub async fn recalculate_user_activities_and_summary(
         video_game_specifics: Option<VideoGameSpecifics>,
         audio_book_specifics: Option<AudioBookSpecifics>,
         comic_book_specifics: Option<ComicBookSpecifics>,
+        board_game_specifics: Option<BoardGameSpecifics>,
         visual_novel_specifics: Option<VisualNovelSpecifics>,
         show_extra_information: Option<SeenShowExtraInformation>,
         podcast_extra_information: Option<SeenPodcastExtraInformation>,
@@ -191,6 +192,7 @@ pub async fn recalculate_user_activities_and_summary(
                 metadata::Column::PodcastSpecifics,
                 metadata::Column::AudioBookSpecifics,
                 metadata::Column::ComicBookSpecifics,
+                metadata::Column::BoardGameSpecifics,
                 metadata::Column::VideoGameSpecifics,
                 metadata::Column::VisualNovelSpecifics,
             ])
@@ -250,6 +252,18 @@ pub async fn recalculate_user_activities_and_summary(
                 if let Some(runtime) = visual_novel_extra.length {
                     activity.visual_novel_duration += runtime;
                 }
+            } else if let Some(board_game_extra) = seen.board_game_specifics {
+                match seen.manual_time_spent {
+                    Some(manual_time_spent) => {
+                        activity.board_game_duration +=
+                            (manual_time_spent / dec!(60)).to_i32().unwrap_or_default();
+                    }
+                    None => {
+                        if let Some(playing_time) = board_game_extra.playing_time {
+                            activity.board_game_duration += playing_time;
+                        }
+                    }
+                }
             } else if let Some(_video_game_extra) = seen.video_game_specifics
                 && let Some(manual_time_spent) = seen.manual_time_spent
             {
@@ -267,6 +281,7 @@ pub async fn recalculate_user_activities_and_summary(
                 MediaLot::VideoGame => activity.video_game_count += 1,
                 MediaLot::AudioBook => activity.audio_book_count += 1,
                 MediaLot::ComicBook => activity.comic_book_count += 1,
+                MediaLot::BoardGame => activity.board_game_count += 1,
                 MediaLot::VisualNovel => activity.visual_novel_count += 1,
             };
         }
@@ -443,6 +458,7 @@ pub async fn recalculate_user_activities_and_summary(
             + activity.comic_book_count
             + activity.video_game_count
             + activity.visual_novel_count
+            + activity.board_game_count
             + activity.metadata_collection_count;
         let total_count = total_metadata_count
             + activity.measurement_count
@@ -457,7 +473,8 @@ pub async fn recalculate_user_activities_and_summary(
             + activity.show_duration
             + activity.music_duration
             + activity.visual_novel_duration
-            + activity.video_game_duration;
+            + activity.video_game_duration
+            + activity.board_game_duration;
         activity.hour_records.sort_by_key(|hr| hr.hour);
         let mut model = activity.clone().into_active_model();
         model.id = ActiveValue::NotSet;
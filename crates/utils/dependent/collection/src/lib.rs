@@ -1,4 +1,7 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use anyhow::{Result, anyhow, bail};
 use background_models::{ApplicationJob, LpApplicationJob};
@@ -19,15 +22,15 @@ use dependent_utility_utils::{
     associate_user_with_entity, expire_entity_details_cache, expire_user_collection_contents_cache,
     expire_user_collections_list_cache,
 };
-use enum_models::EntityLot;
+use enum_models::{CollectionCollaboratorRole, EntityLot};
 use futures::try_join;
 use itertools::Itertools;
 use media_models::CreateOrUpdateCollectionInput;
 use rust_decimal::{Decimal, dec};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, FromQueryResult, IntoActiveModel,
-    Iterable, QueryFilter, QueryOrder, QuerySelect, TransactionTrait, prelude::Expr,
-    sea_query::OnConflict,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, FromQueryResult,
+    IntoActiveModel, Iterable, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+    prelude::Expr, sea_query::OnConflict,
 };
 use supporting_service::SupportingService;
 use uuid::Uuid;
@@ -39,6 +42,64 @@ struct CollectionEntityRank {
     entity_id: String,
 }
 
+async fn collection_member_ids(
+    collection_id: &String,
+    ss: &Arc<SupportingService>,
+) -> Result<Vec<String>> {
+    let member_ids = UserToEntity::find()
+        .select_only()
+        .column(user_to_entity::Column::UserId)
+        .filter(user_to_entity::Column::CollectionId.eq(collection_id))
+        .into_tuple::<String>()
+        .all(&ss.db)
+        .await?;
+    Ok(member_ids)
+}
+
+/// Changes made by one member of a shared collection need to be visible to all the others.
+async fn expire_collection_members_caches(
+    collection_id: &String,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    for member_id in collection_member_ids(collection_id, ss).await? {
+        try_join!(
+            expire_user_collections_list_cache(&member_id, ss),
+            expire_user_collection_contents_cache(&member_id, collection_id, ss),
+        )?;
+    }
+    Ok(())
+}
+
+/// Get the collection if the user is a member who is allowed to change its contents.
+pub async fn get_editable_collection(
+    user_id: &String,
+    collection_name: &String,
+    creator_user_id: &String,
+    ss: &Arc<SupportingService>,
+) -> Result<collection::Model> {
+    let Some(collection) = Collection::find()
+        .left_join(UserToEntity)
+        .filter(collection::Column::Name.eq(collection_name))
+        .filter(user_to_entity::Column::UserId.eq(creator_user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("Collection not found");
+    };
+    let membership = UserToEntity::find()
+        .filter(user_to_entity::Column::UserId.eq(user_id))
+        .filter(user_to_entity::Column::CollectionId.eq(&collection.id))
+        .one(&ss.db)
+        .await?;
+    match membership.map(|m| m.collection_collaborator_role) {
+        None => bail!("Collection not found"),
+        Some(Some(CollectionCollaboratorRole::Viewer)) => {
+            bail!("You are not allowed to edit this collection")
+        }
+        Some(_) => Ok(collection),
+    }
+}
+
 async fn add_single_entity_to_collection(
     user_id: &String,
     entity: &EntityToCollectionInput,
@@ -49,9 +110,17 @@ async fn add_single_entity_to_collection(
         .left_join(UserToEntity)
         .filter(user_to_entity::Column::UserId.eq(user_id))
         .filter(collection::Column::Name.eq(collection_name))
+        .filter(
+            Condition::any()
+                .add(user_to_entity::Column::CollectionCollaboratorRole.is_null())
+                .add(
+                    user_to_entity::Column::CollectionCollaboratorRole
+                        .ne(CollectionCollaboratorRole::Viewer),
+                ),
+        )
         .one(&ss.db)
         .await?
-        .unwrap();
+        .ok_or_else(|| anyhow!("Collection not found or you are not allowed to edit it"))?;
     let mut updated = collection.into_active_model();
     updated.last_updated_on = ActiveValue::Set(Utc::now());
     let collection = updated.update(&ss.db).await?;
@@ -85,6 +154,7 @@ async fn add_single_entity_to_collection(
                 rank: ActiveValue::Set(new_rank),
                 collection_id: ActiveValue::Set(collection.id.clone()),
                 information: ActiveValue::Set(entity.information.clone()),
+                added_by_user_id: ActiveValue::Set(Some(user_id.to_owned())),
                 ..Default::default()
             };
             let id = entity.entity_id.clone();
@@ -115,7 +185,7 @@ async fn add_single_entity_to_collection(
     };
     try_join!(
         associate_user_with_entity(user_id, &entity.entity_id, entity.entity_lot, ss),
-        expire_user_collection_contents_cache(user_id, &collection.id, ss),
+        expire_collection_members_caches(&collection.id, ss),
         ss.perform_application_job(ApplicationJob::Lp(
             LpApplicationJob::HandleEntityAddedToCollectionEvent(resp.id),
         ))
@@ -147,14 +217,19 @@ pub async fn create_or_update_collection(
         .one(&txn)
         .await?;
     let mut new_name = input.name.clone();
-    let mut collaborators_to_expire_cache: Option<HashSet<String>> = None;
+    let mut members_to_expire_cache: Option<HashSet<String>> = None;
     let created = match meta {
         Some(m) if input.update_id.is_none() => m.id,
         _ => {
             let id = match input.update_id {
                 None => ActiveValue::NotSet,
                 Some(i) => {
-                    let already = Collection::find_by_id(i.clone()).one(&txn).await?.unwrap();
+                    let Some(already) = Collection::find_by_id(i.clone()).one(&txn).await? else {
+                        bail!("Collection not found");
+                    };
+                    if &already.user_id != user_id {
+                        bail!("Only the owner of the collection can update it");
+                    }
                     if DefaultCollection::iter()
                         .map(|s| s.to_string())
                         .collect_vec()
@@ -184,17 +259,24 @@ pub async fn create_or_update_collection(
                 .exec(&txn)
                 .await?;
             ryot_log!(debug, "Deleted old user to entity: {:?}", result);
-            let mut collaborators = HashSet::from([user_id.to_owned()]);
-            if let Some(input_collaborators) = input.collaborators {
-                collaborators.extend(input_collaborators);
+            let mut members =
+                HashMap::from([(user_id.to_owned(), CollectionCollaboratorRole::Editor)]);
+            for collaborator in input.collaborators.unwrap_or_default() {
+                members.insert(collaborator, CollectionCollaboratorRole::Editor);
+            }
+            for viewer in input.viewers.unwrap_or_default() {
+                members
+                    .entry(viewer)
+                    .or_insert(CollectionCollaboratorRole::Viewer);
             }
-            ryot_log!(debug, "Collaborators: {:?}", collaborators);
-            collaborators_to_expire_cache = Some(collaborators.clone());
-            for c in collaborators {
+            ryot_log!(debug, "Members: {:?}", members);
+            members_to_expire_cache = Some(members.keys().cloned().collect());
+            for (c, role) in members {
                 UserToEntity::insert(user_to_entity::ActiveModel {
                     user_id: ActiveValue::Set(c.clone()),
                     last_updated_on: ActiveValue::Set(Utc::now()),
                     collection_id: ActiveValue::Set(Some(id.clone())),
+                    collection_collaborator_role: ActiveValue::Set(Some(role)),
                     collection_extra_information: match &c == user_id {
                         true => ActiveValue::Set(input.extra_information.clone()),
                         _ => Default::default(),
@@ -208,8 +290,9 @@ pub async fn create_or_update_collection(
                             Expr::col(user_to_entity::Column::CollectionId),
                         ])
                         .update_columns([
-                            user_to_entity::Column::CollectionExtraInformation,
                             user_to_entity::Column::LastUpdatedOn,
+                            user_to_entity::Column::CollectionExtraInformation,
+                            user_to_entity::Column::CollectionCollaboratorRole,
                         ])
                         .to_owned(),
                 )
@@ -221,8 +304,8 @@ pub async fn create_or_update_collection(
     };
     txn.commit().await?;
 
-    if let Some(collaborators) = collaborators_to_expire_cache {
-        for c in &collaborators {
+    if let Some(members) = members_to_expire_cache {
+        for c in &members {
             expire_user_collections_list_cache(c, ss).await?;
         }
     }
@@ -236,13 +319,14 @@ async fn remove_single_entity_from_collection(
     creator_user_id: &String,
     ss: &Arc<SupportingService>,
 ) -> Result<bool> {
-    let collect = Collection::find()
-        .left_join(UserToEntity)
-        .filter(collection::Column::Name.eq(collection_name))
-        .filter(user_to_entity::Column::UserId.eq(creator_user_id))
-        .one(&ss.db)
-        .await?
-        .unwrap();
+    let collect = get_editable_collection(user_id, collection_name, creator_user_id, ss).await?;
+    ryot_log!(
+        debug,
+        "User {} is removing {} from collection {}",
+        user_id,
+        entity.entity_id,
+        collect.id
+    );
     CollectionToEntity::delete_many()
         .filter(collection_to_entity::Column::CollectionId.eq(collect.id.clone()))
         .filter(collection_to_entity::Column::EntityId.eq(entity.entity_id.clone()))
@@ -255,8 +339,7 @@ async fn remove_single_entity_from_collection(
             .ok();
     }
     try_join!(
-        expire_user_collections_list_cache(user_id, ss),
-        expire_user_collection_contents_cache(user_id, &collect.id, ss),
+        expire_collection_members_caches(&collect.id, ss),
         expire_entity_details_cache(user_id, &entity.entity_id, entity.entity_lot, ss),
     )?;
     Ok(true)
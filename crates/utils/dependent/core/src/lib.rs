@@ -146,7 +146,12 @@ fn build_provider_language_information(
                 | MediaSource::Listennotes
                 | MediaSource::Openlibrary
                 | MediaSource::MangaUpdates
-                | MediaSource::Metron => vec![ProviderSupportedLanguageInformation {
+                | MediaSource::Kitsu
+                | MediaSource::Metron
+                | MediaSource::ComicVine
+                | MediaSource::PodcastIndex
+                | MediaSource::Rss
+                | MediaSource::BoardGameGeek => vec![ProviderSupportedLanguageInformation {
                     value: "us".to_owned(),
                     label: "us".to_owned(),
                 }],
@@ -270,6 +275,8 @@ pub async fn core_details(ss: &Arc<SupportingService>) -> Result<CoreDetails> {
                 smtp_enabled: ss.config.server.smtp.is_enabled(),
                 signup_allowed: ss.config.users.allow_registration,
                 is_demo_instance: ss.config.server.is_demo_instance,
+                proxy_auth_header: Some(ss.config.users.trusted_proxy_header.clone())
+                    .filter(|h| !h.is_empty()),
                 max_file_size_mb: ss.config.server.max_file_size_mb,
                 people_search_sources: PEOPLE_SEARCH_SOURCES.to_vec(),
                 local_auth_disabled: ss.config.users.disable_local_auth,
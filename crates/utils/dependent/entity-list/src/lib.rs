@@ -497,6 +497,13 @@ pub async fn user_collections_list(
                         user_to_entity::Column::CollectionExtraInformation,
                     )),
                 ),
+                (
+                    Expr::val("role"),
+                    Expr::col((
+                        user_to_entity::Entity,
+                        user_to_entity::Column::CollectionCollaboratorRole,
+                    )),
+                ),
             ]);
             let collaborators_subquery = Query::select()
                 .from(UserToEntity)
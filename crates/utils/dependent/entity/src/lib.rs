@@ -467,6 +467,8 @@ pub async fn update_metadata(
             meta.podcast_specifics = ActiveValue::Set(details.podcast_specifics);
             meta.audio_book_specifics = ActiveValue::Set(details.audio_book_specifics);
             meta.video_game_specifics = ActiveValue::Set(details.video_game_specifics);
+            meta.comic_book_specifics = ActiveValue::Set(details.comic_book_specifics);
+            meta.board_game_specifics = ActiveValue::Set(details.board_game_specifics);
             meta.external_identifiers = ActiveValue::Set(details.external_identifiers);
             meta.visual_novel_specifics = ActiveValue::Set(details.visual_novel_specifics);
             let metadata = meta.update(&ss.db).await?;
@@ -6,14 +6,20 @@ use common_models::DefaultCollection;
 use common_utils::ryot_log;
 use database_models::{
     collection_entity_membership, collection_to_entity, notification_platform,
-    prelude::{CollectionEntityMembership, CollectionToEntity, NotificationPlatform},
+    prelude::{
+        CollectionEntityMembership, CollectionToEntity, NotificationPlatform, QueuedNotification,
+    },
+    queued_notification,
 };
 use dependent_entity_utils::{update_metadata, update_metadata_group, update_person};
 use enum_models::{EntityLot, UserNotificationContent};
 use itertools::Itertools;
 use media_models::UpdateMediaEntityResult;
-use notification_service::send_notification;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, prelude::Expr};
+use notification_service::{NotificationEvent, send_notification};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder,
+    QuerySelect, prelude::Expr,
+};
 use supporting_service::SupportingService;
 use traits::TraceOk;
 use uuid::Uuid;
@@ -77,6 +83,44 @@ pub async fn get_users_monitoring_entity(
     )
 }
 
+fn get_notification_url(
+    change: &UserNotificationContent,
+    ss: &Arc<SupportingService>,
+) -> Option<String> {
+    match change.clone() {
+        UserNotificationContent::ReviewPosted {
+            entity_id,
+            entity_lot,
+            ..
+        } => Some(get_entity_details_frontend_url(
+            entity_id,
+            entity_lot,
+            Some("reviews"),
+            ss,
+        )),
+        UserNotificationContent::MetadataPublished {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataMovedFromCompletedToWatchlistCollection {
+            entity_id,
+            entity_lot,
+            ..
+        } => Some(get_entity_details_frontend_url(
+            entity_id, entity_lot, None, ss,
+        )),
+        UserNotificationContent::NewWorkoutCreated { workout_id, .. } => Some(
+            get_entity_details_frontend_url(workout_id, EntityLot::Workout, None, ss),
+        ),
+        UserNotificationContent::ExportCompleted { .. } => Some(format!(
+            "{}/settings/imports-and-exports",
+            ss.config.frontend.url
+        )),
+        _ => None,
+    }
+}
+
 async fn get_notification_message(
     change: UserNotificationContent,
     ss: &Arc<SupportingService>,
@@ -261,9 +305,41 @@ async fn get_notification_message(
                 entity_title, url
             ))
         }
+        UserNotificationContent::ExportCompleted { format } => Ok(format!(
+            "Your {} export has completed and can be downloaded from {}/settings/imports-and-exports",
+            format, ss.config.frontend.url
+        )),
+        UserNotificationContent::AccountLockedDueToFailedLogins {
+            failed_attempts,
+            locked_for_minutes,
+            ip_address,
+        } => Ok(format!(
+            "Your account has been locked for {} minutes after {} failed login attempts{}",
+            locked_for_minutes,
+            failed_attempts,
+            ip_address
+                .map(|ip| format!(" from {ip}"))
+                .unwrap_or_default()
+        )),
     }
 }
 
+async fn get_notification_event(
+    notification: &UserNotificationContent,
+    ss: &Arc<SupportingService>,
+) -> Result<NotificationEvent> {
+    let data = serde_json::to_value(notification)?
+        .as_object()
+        .and_then(|variant| variant.values().next().cloned())
+        .unwrap_or_default();
+    Ok(NotificationEvent {
+        data,
+        event: notification.to_string(),
+        url: get_notification_url(notification, ss),
+        message: get_notification_message(notification.clone(), ss).await?,
+    })
+}
+
 pub async fn send_notification_for_user(
     user_id: &String,
     ss: &Arc<SupportingService>,
@@ -278,10 +354,10 @@ pub async fn send_notification_for_user(
         )
         .all(&ss.db)
         .await?;
-    let msg = get_notification_message(notification.clone(), ss).await?;
+    let event = get_notification_event(&notification, ss).await?;
+    let now = Utc::now().with_timezone(&ss.timezone).time();
     for platform in notification_platforms {
-        let notification = notification.clone().into();
-        if !platform.configured_events.contains(&notification) {
+        if !platform.configured_events.contains(&(&notification).into()) {
             ryot_log!(
                 debug,
                 "Skipping sending notification to user: {} for platform: {} since it is not configured for this event",
@@ -290,13 +366,88 @@ pub async fn send_notification_for_user(
             );
             continue;
         }
-        if let Err(err) = send_notification(&msg, &ss.config, platform.platform_specifics).await {
+        if !notification.is_urgent()
+            && platform
+                .quiet_hours
+                .as_ref()
+                .is_some_and(|quiet_hours| quiet_hours.contains(now))
+        {
+            ryot_log!(
+                debug,
+                "Queueing notification for platform: {} since it is in quiet hours",
+                platform.id
+            );
+            queued_notification::ActiveModel {
+                notification_platform_id: ActiveValue::Set(platform.id),
+                content: ActiveValue::Set(notification.clone()),
+                ..Default::default()
+            }
+            .insert(&ss.db)
+            .await
+            .trace_ok();
+            continue;
+        }
+        if let Err(err) = send_notification(&event, &ss.config, platform.platform_specifics).await {
             ryot_log!(debug, "Error sending notification: {:?}", err);
         }
     }
     Ok(())
 }
 
+async fn send_queued_notification(
+    ss: &Arc<SupportingService>,
+    queued: &queued_notification::Model,
+    platform: notification_platform::Model,
+) -> Result<()> {
+    if platform.is_disabled.unwrap_or_default() {
+        return Ok(());
+    }
+    let event = get_notification_event(&queued.content, ss).await?;
+    send_notification(&event, &ss.config, platform.platform_specifics).await
+}
+
+/// Deliver notifications that were held back during quiet hours once the platform's quiet
+/// hours are over. A notification that can not be delivered is dropped so that it does not
+/// hold up the rest of the queue.
+pub async fn send_queued_notifications(ss: &Arc<SupportingService>) -> Result<()> {
+    let queued_notifications = QueuedNotification::find()
+        .find_also_related(NotificationPlatform)
+        .order_by_asc(queued_notification::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    let now = Utc::now().with_timezone(&ss.timezone).time();
+    for (queued, platform) in queued_notifications {
+        let Some(platform) = platform else {
+            continue;
+        };
+        if platform
+            .quiet_hours
+            .as_ref()
+            .is_some_and(|quiet_hours| quiet_hours.contains(now))
+        {
+            continue;
+        }
+        if let Err(err) = send_queued_notification(ss, &queued, platform).await {
+            ryot_log!(
+                warn,
+                "Error sending queued notification {}: {:?}",
+                queued.id,
+                err
+            );
+        }
+        let queued_id = queued.id;
+        if let Err(err) = queued.delete(&ss.db).await {
+            ryot_log!(
+                warn,
+                "Could not delete queued notification {}: {:?}",
+                queued_id,
+                err
+            );
+        }
+    }
+    Ok(())
+}
+
 pub async fn refresh_collection_to_entity_association(
     cte_id: &Uuid,
     ss: &Arc<SupportingService>,
@@ -19,7 +19,8 @@ use media_models::{
     MetadataProgressUpdateInput, MetadataProgressUpdateNewInProgressInput,
     MetadataProgressUpdateStartedAndFinishedOnDateInput,
     MetadataProgressUpdateStartedOrFinishedOnDateInput, SeenAnimeExtraInformation,
-    SeenMangaExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
+    SeenBoardGameExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraInformation,
+    SeenShowExtraInformation,
 };
 use rust_decimal::{Decimal, dec};
 use sea_orm::{
@@ -52,8 +53,10 @@ pub async fn commit_import_seen_item(
         show_episode_number: input.show_episode_number,
         anime_episode_number: input.anime_episode_number,
         manga_chapter_number: input.manga_chapter_number,
+        board_game_winner: input.board_game_winner,
         providers_consumed_on: input.providers_consumed_on,
         podcast_episode_number: input.podcast_episode_number,
+        board_game_player_count: input.board_game_player_count,
     };
     if is_import {
         let change_inner = match (input.started_on, input.ended_on) {
@@ -260,6 +263,7 @@ struct ExtraInformation {
     anime_ei: Option<SeenAnimeExtraInformation>,
     manga_ei: Option<SeenMangaExtraInformation>,
     podcast_ei: Option<SeenPodcastExtraInformation>,
+    board_game_ei: Option<SeenBoardGameExtraInformation>,
 }
 
 fn create_extra_information(
@@ -304,11 +308,20 @@ fn create_extra_information(
         _ => None,
     };
 
+    let board_game_ei = match media_lot {
+        MediaLot::BoardGame => Some(SeenBoardGameExtraInformation {
+            winner: payload.board_game_winner.clone(),
+            player_count: payload.board_game_player_count,
+        }),
+        _ => None,
+    };
+
     Ok(ExtraInformation {
         show_ei,
         anime_ei,
         manga_ei,
         podcast_ei,
+        board_game_ei,
     })
 }
 
@@ -338,6 +351,7 @@ async fn commit(input: CommitInput<'_>) -> Result<seen::Model> {
         anime_extra_information: ActiveValue::Set(extra_info.anime_ei),
         manga_extra_information: ActiveValue::Set(extra_info.manga_ei),
         podcast_extra_information: ActiveValue::Set(extra_info.podcast_ei),
+        board_game_extra_information: ActiveValue::Set(extra_info.board_game_ei),
         manual_time_spent: ActiveValue::Set(input.payload.manual_time_spent),
         providers_consumed_on: ActiveValue::Set(
             input.payload.providers_consumed_on.unwrap_or_default(),
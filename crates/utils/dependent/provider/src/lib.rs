@@ -3,12 +3,15 @@ use std::sync::Arc;
 use anilist_provider::{AnilistAnimeService, AnilistMangaService, NonMediaAnilistService};
 use anyhow::{Result, anyhow, bail};
 use audible_provider::AudibleService;
+use board_game_geek_provider::BoardGameGeekService;
+use comic_vine_provider::ComicVineService;
 use enum_models::{MediaLot, MediaSource};
 use giant_bomb_provider::GiantBombService;
 use google_books_provider::GoogleBooksService;
 use hardcover_provider::HardcoverService;
 use igdb_provider::IgdbService;
 use itunes_provider::ITunesService;
+use kitsu_provider::{KitsuAnimeService, KitsuMangaService, NonMediaKitsuService};
 use listennotes_provider::ListennotesService;
 use manga_updates_provider::MangaUpdatesService;
 use media_models::MetadataDetails;
@@ -16,6 +19,8 @@ use metron_provider::MetronService;
 use music_brainz_provider::MusicBrainzService;
 use myanimelist_provider::{MalAnimeService, MalMangaService, NonMediaMalService};
 use openlibrary_provider::OpenlibraryService;
+use podcast_index_provider::PodcastIndexService;
+use rss_provider::RssService;
 use spotify_provider::SpotifyService;
 use supporting_service::SupportingService;
 use tmdb_provider::{NonMediaTmdbService, TmdbMovieService, TmdbShowService};
@@ -67,6 +72,10 @@ pub async fn get_metadata_provider(
             Box::new(AudibleService::new(&ss.config.audio_books.audible).await?)
         }
         MediaSource::Listennotes => Box::new(ListennotesService::new(ss.clone()).await?),
+        MediaSource::PodcastIndex => {
+            Box::new(PodcastIndexService::new(&ss.config.podcasts.podcast_index).await?)
+        }
+        MediaSource::Rss => Box::new(RssService::new(ss.clone()).await?),
         MediaSource::Tvdb => match lot {
             MediaLot::Show => Box::new(TvdbShowService::new(ss.clone()).await?),
             MediaLot::Movie => Box::new(TvdbMovieService::new(ss.clone()).await?),
@@ -95,12 +104,23 @@ pub async fn get_metadata_provider(
             }
             _ => return err(),
         },
+        MediaSource::Kitsu => match lot {
+            MediaLot::Anime => Box::new(KitsuAnimeService::new().await?),
+            MediaLot::Manga => Box::new(KitsuMangaService::new().await?),
+            _ => return err(),
+        },
         MediaSource::Igdb => Box::new(IgdbService::new(ss.clone()).await?),
         MediaSource::GiantBomb => Box::new(GiantBombService::new(ss.clone()).await?),
         MediaSource::MangaUpdates => {
             Box::new(MangaUpdatesService::new(&ss.config.anime_and_manga.manga_updates).await?)
         }
         MediaSource::Metron => Box::new(MetronService::new(&ss.config.comic_books.metron).await?),
+        MediaSource::ComicVine => {
+            Box::new(ComicVineService::new(&ss.config.comic_books.comic_vine).await?)
+        }
+        MediaSource::BoardGameGeek => {
+            Box::new(BoardGameGeekService::new(&ss.config.board_games.board_game_geek).await?)
+        }
         MediaSource::MusicBrainz => Box::new(MusicBrainzService::new()?),
         MediaSource::Custom => return err(),
         MediaSource::Spotify => Box::new(SpotifyService::new(ss.clone()).await?),
@@ -125,17 +145,28 @@ pub async fn get_non_metadata_provider(
             Box::new(AudibleService::new(&ss.config.audio_books.audible).await?)
         }
         MediaSource::Listennotes => Box::new(ListennotesService::new(ss.clone()).await?),
+        MediaSource::PodcastIndex => {
+            Box::new(PodcastIndexService::new(&ss.config.podcasts.podcast_index).await?)
+        }
+        MediaSource::Rss => Box::new(RssService::new(ss.clone()).await?),
         MediaSource::Igdb => Box::new(IgdbService::new(ss.clone()).await?),
         MediaSource::GiantBomb => Box::new(GiantBombService::new(ss.clone()).await?),
         MediaSource::MangaUpdates => {
             Box::new(MangaUpdatesService::new(&ss.config.anime_and_manga.manga_updates).await?)
         }
         MediaSource::Metron => Box::new(MetronService::new(&ss.config.comic_books.metron).await?),
+        MediaSource::ComicVine => {
+            Box::new(ComicVineService::new(&ss.config.comic_books.comic_vine).await?)
+        }
+        MediaSource::BoardGameGeek => {
+            Box::new(BoardGameGeekService::new(&ss.config.board_games.board_game_geek).await?)
+        }
         MediaSource::Tmdb => Box::new(get_tmdb_non_media_service(ss).await?),
         MediaSource::Anilist => {
             Box::new(NonMediaAnilistService::new(&ss.config.anime_and_manga.anilist).await?)
         }
         MediaSource::Myanimelist => Box::new(NonMediaMalService::new().await?),
+        MediaSource::Kitsu => Box::new(NonMediaKitsuService::new().await?),
         MediaSource::MusicBrainz => Box::new(MusicBrainzService::new()?),
         MediaSource::Spotify => Box::new(SpotifyService::new(ss.clone()).await?),
         MediaSource::Custom => return err(),
@@ -1,18 +1,54 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use common_utils::{FRONTEND_OAUTH_ENDPOINT, ryot_log};
 use config_definition::AppConfig;
 use openidconnect::{
-    ClientId, ClientSecret, EmptyAdditionalClaims, EndpointMaybeSet, EndpointNotSet, EndpointSet,
-    IssuerUrl, RedirectUrl, StandardErrorResponse,
+    AdditionalClaims, Client, ClientId, ClientSecret, EmptyExtraTokenFields, EndpointMaybeSet,
+    EndpointNotSet, EndpointSet, IdTokenFields, IssuerUrl, RedirectUrl, StandardErrorResponse,
+    StandardTokenResponse,
     core::{
-        CoreAuthDisplay, CoreAuthPrompt, CoreClient, CoreErrorResponseType, CoreGenderClaim,
-        CoreJsonWebKey, CoreJweContentEncryptionAlgorithm, CoreProviderMetadata,
+        CoreAuthDisplay, CoreAuthPrompt, CoreErrorResponseType, CoreGenderClaim, CoreJsonWebKey,
+        CoreJweContentEncryptionAlgorithm, CoreJwsSigningAlgorithm, CoreProviderMetadata,
         CoreRevocableToken, CoreRevocationErrorResponse, CoreTokenIntrospectionResponse,
-        CoreTokenResponse,
+        CoreTokenType,
     },
     reqwest::{Client as ReqwestClient, ClientBuilder, redirect::Policy},
 };
+use serde::{Deserialize, Serialize};
+
+/// All the claims in the ID token that are not part of the OIDC standard.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OidcAdditionalClaims {
+    #[serde(flatten)]
+    pub claims: HashMap<String, serde_json::Value>,
+}
+
+impl AdditionalClaims for OidcAdditionalClaims {}
+
+pub type OidcTokenResponse = StandardTokenResponse<
+    IdTokenFields<
+        OidcAdditionalClaims,
+        EmptyExtraTokenFields,
+        CoreGenderClaim,
+        CoreJweContentEncryptionAlgorithm,
+        CoreJwsSigningAlgorithm,
+    >,
+    CoreTokenType,
+>;
+
+impl OidcAdditionalClaims {
+    /// Get the values of a claim which can either be a single string or a list of them.
+    pub fn string_values(&self, name: &str) -> Vec<String> {
+        match self.claims.get(name) {
+            Some(serde_json::Value::String(value)) => vec![value.clone()],
+            Some(serde_json::Value::Array(values)) => values
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
 
 pub type ApplicationOidcClient<
     HasAuthUrl = EndpointSet,
@@ -21,15 +57,15 @@ pub type ApplicationOidcClient<
     HasRevocationUrl = EndpointNotSet,
     HasTokenUrl = EndpointMaybeSet,
     HasUserInfoUrl = EndpointMaybeSet,
-> = openidconnect::Client<
-    EmptyAdditionalClaims,
+> = Client<
+    OidcAdditionalClaims,
     CoreAuthDisplay,
     CoreGenderClaim,
     CoreJweContentEncryptionAlgorithm,
     CoreJsonWebKey,
     CoreAuthPrompt,
     StandardErrorResponse<CoreErrorResponseType>,
-    CoreTokenResponse,
+    OidcTokenResponse,
     CoreTokenIntrospectionResponse,
     CoreRevocableToken,
     CoreRevocationErrorResponse,
@@ -78,7 +114,7 @@ pub async fn create_oidc_client(
             }
         };
 
-    let core_client = CoreClient::from_provider_metadata(
+    let core_client = Client::from_provider_metadata(
         provider_metadata,
         ClientId::new(config.server.oidc.client_id.clone()),
         match config.server.oidc.client_secret.clone() {
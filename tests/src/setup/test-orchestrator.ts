@@ -7,7 +7,12 @@ import { PostgreSqlContainer } from "@testcontainers/postgresql";
 import getPort from "get-port";
 import type { StartedNetwork, StartedTestContainer } from "testcontainers";
 import { GenericContainer, Network, Wait } from "testcontainers";
-import { TEST_ADMIN_ACCESS_TOKEN } from "../utils";
+import {
+	TEST_ADMIN_ACCESS_TOKEN,
+	TEST_LDAP_BASE_DN,
+	TEST_LDAP_USERS,
+	TEST_TRUSTED_PROXY_HEADER,
+} from "../utils";
 
 const setupProcessLogging = (process: ChildProcess) => {
 	process.stdout?.on("data", (data) => {
@@ -29,6 +34,7 @@ export interface StartedServices {
 	caddyProcess: ChildProcess;
 	backendProcess: ChildProcess;
 	frontendProcess: ChildProcess;
+	ldapContainer: StartedTestContainer;
 	minioContainer: StartedTestContainer;
 	pgContainer: StartedPostgreSqlContainer;
 }
@@ -40,6 +46,21 @@ const TEST_BUCKET_NAME = "test-bucket";
 const DB_USER = "test-user";
 const DB_PASSWORD = "test-password";
 const DB_NAME = "test-db";
+const LDAP_ADMIN_GROUP_DN = `cn=admins,ou=groups,${TEST_LDAP_BASE_DN}`;
+
+const ldapUserDn = (username: string) =>
+	`uid=${username},ou=people,${TEST_LDAP_BASE_DN}`;
+
+// The `memberof` overlay of the image maintains `memberOf` for `groupOfUniqueNames`.
+const LDAP_BOOTSTRAP_LDIF = [
+	`dn: ou=people,${TEST_LDAP_BASE_DN}\nobjectClass: organizationalUnit\nou: people`,
+	`dn: ou=groups,${TEST_LDAP_BASE_DN}\nobjectClass: organizationalUnit\nou: groups`,
+	...Object.values(TEST_LDAP_USERS).map(
+		({ username, password }) =>
+			`dn: ${ldapUserDn(username)}\nobjectClass: inetOrgPerson\nuid: ${username}\ncn: ${username}\nsn: ${username}\nuserPassword: ${password}`,
+	),
+	`dn: ${LDAP_ADMIN_GROUP_DN}\nobjectClass: groupOfUniqueNames\ncn: admins\nuniqueMember: ${ldapUserDn(TEST_LDAP_USERS.admin.username)}`,
+].join("\n\n");
 
 async function createMinioBucket(endpoint: string) {
 	const s3Client = new S3Client({
@@ -175,6 +196,7 @@ async function startBackendProcess(
 	dbUrl: string,
 	backendPort: number,
 	minioEndpoint: string,
+	ldapUrl: string,
 ): Promise<ChildProcess> {
 	return new Promise((resolve) => {
 		console.log(
@@ -188,6 +210,12 @@ async function startBackendProcess(
 			FILE_STORAGE_S3_ACCESS_KEY_ID: MINIO_ACCESS_KEY,
 			SERVER_ADMIN_ACCESS_TOKEN: TEST_ADMIN_ACCESS_TOKEN,
 			FILE_STORAGE_S3_SECRET_ACCESS_KEY: MINIO_SECRET_KEY,
+			SERVER_LDAP_URL: ldapUrl,
+			SERVER_LDAP_ADMIN_GROUP_DN: LDAP_ADMIN_GROUP_DN,
+			SERVER_LDAP_BASE_DN: `ou=people,${TEST_LDAP_BASE_DN}`,
+			SERVER_LDAP_BIND_DN_TEMPLATE: ldapUserDn("{username}"),
+			USERS_TRUSTED_PROXY_CIDRS: "127.0.0.1/32",
+			USERS_TRUSTED_PROXY_HEADER: TEST_TRUSTED_PROXY_HEADER,
 		};
 
 		const backendProcess = spawn(
@@ -215,7 +243,7 @@ export async function startAllServices() {
 	const network = await new Network().start();
 
 	console.log("[Orchestrator] Starting containers in parallel...");
-	const [pgContainer, minioContainer] = await Promise.all([
+	const [pgContainer, minioContainer, ldapContainer] = await Promise.all([
 		new PostgreSqlContainer("postgres:16-alpine")
 			.withDatabase(DB_NAME)
 			.withUsername(DB_USER)
@@ -237,14 +265,32 @@ export async function startAllServices() {
 			.withExposedPorts(9000, 9090)
 			.withWaitStrategy(Wait.forHttp("/minio/health/live", 9000))
 			.start(),
+		new GenericContainer("osixia/openldap:1.5.0")
+			.withEnvironment({ LDAP_DOMAIN: "example.org" })
+			.withCopyContentToContainer([
+				{
+					content: LDAP_BOOTSTRAP_LDIF,
+					target:
+						"/container/service/slapd/assets/config/bootstrap/ldif/custom/50-ryot.ldif",
+				},
+			])
+			.withCommand(["--copy-service"])
+			.withNetwork(network)
+			.withNetworkAliases("ldap")
+			.withExposedPorts(389)
+			.withWaitStrategy(Wait.forLogMessage("slapd starting"))
+			.start(),
 	]);
-	console.log("[Orchestrator] PostgreSQL and MinIO containers started.");
+	console.log(
+		"[Orchestrator] PostgreSQL, MinIO and OpenLDAP containers started.",
+	);
 
 	const dbHost = pgContainer.getHost();
 	const dbPort = pgContainer.getPort();
 	const minioHost = minioContainer.getHost();
 	const minioPort = minioContainer.getMappedPort(9000);
 	const minioExternalEndpoint = `http://${minioHost}:${minioPort}`;
+	const ldapUrl = `ldap://${ldapContainer.getHost()}:${ldapContainer.getMappedPort(389)}`;
 
 	await createMinioBucket(minioExternalEndpoint);
 
@@ -259,7 +305,12 @@ export async function startAllServices() {
 		"[Orchestrator] Starting backend and frontend processes in parallel...",
 	);
 	const [backendProcess, frontendProcess] = await Promise.all([
-		startBackendProcess(backendDbUrl, freeBackendPort, minioExternalEndpoint),
+		startBackendProcess(
+			backendDbUrl,
+			freeBackendPort,
+			minioExternalEndpoint,
+			ldapUrl,
+		),
 		startFrontendProcess(freeFrontendPort),
 	]);
 
@@ -277,6 +328,7 @@ export async function startAllServices() {
 		pgContainer,
 		caddyProcess,
 		caddyBaseUrl,
+		ldapContainer,
 		minioContainer,
 		backendProcess,
 		frontendProcess,
@@ -359,6 +411,15 @@ export async function stopAllServices(services: StartedServices | undefined) {
 		}
 	};
 
+	const stopLdap = async () => {
+		try {
+			await services.ldapContainer.stop();
+			console.log("[Orchestrator] OpenLDAP container stopped.");
+		} catch (e) {
+			console.error("Error stopping OpenLDAP container:", e);
+		}
+	};
+
 	const stopPostgres = async () => {
 		try {
 			await services.pgContainer.stop();
@@ -381,6 +442,7 @@ export async function stopAllServices(services: StartedServices | undefined) {
 		stopCaddy(),
 		stopFrontend(),
 		stopBackend(),
+		stopLdap(),
 		stopMinio(),
 		stopPostgres(),
 	]);
@@ -0,0 +1,121 @@
+import {
+	CreateOrUpdateCollectionDocument,
+	DeployAddEntitiesToCollectionJobDocument,
+	DeployRemoveEntitiesFromCollectionJobDocument,
+	EntityLot,
+} from "@ryot/generated/graphql/backend/graphql";
+import {
+	addEntitiesToCollection,
+	getCollectionContents,
+	getGraphqlClient,
+	registerTestUser,
+	searchAudibleAudiobook,
+} from "src/utils";
+import { beforeAll, describe, expect, it } from "vitest";
+
+const COLLECTION_NAME = "Shared Reading List";
+
+describe("Collection collaborator role tests", () => {
+	const url = process.env.API_BASE_URL as string;
+	let ownerId: string;
+	let ownerApiKey: string;
+	let viewerApiKey: string;
+	let collectionId: string;
+	let duneId: string;
+	let foundationId: string;
+
+	beforeAll(async () => {
+		[ownerApiKey, ownerId] = await registerTestUser(url);
+		const [apiKey, viewerId] = await registerTestUser(url);
+		viewerApiKey = apiKey;
+
+		const client = getGraphqlClient(url);
+		const { createOrUpdateCollection } = await client.request(
+			CreateOrUpdateCollectionDocument,
+			{ input: { name: COLLECTION_NAME, viewers: [viewerId] } },
+			{ Authorization: `Bearer ${ownerApiKey}` },
+		);
+		collectionId = createOrUpdateCollection.id;
+
+		[duneId] = await searchAudibleAudiobook(url, ownerApiKey, "Dune");
+		[foundationId] = await searchAudibleAudiobook(
+			url,
+			ownerApiKey,
+			"Foundation Isaac Asimov",
+		);
+		await addEntitiesToCollection(url, ownerApiKey, ownerId, COLLECTION_NAME, [
+			{ entityId: duneId, entityLot: EntityLot.Metadata },
+		]);
+	});
+
+	const getViewerHeaders = () => ({
+		Authorization: `Bearer ${viewerApiKey}`,
+	});
+
+	it("should not let viewers add entities", async () => {
+		const client = getGraphqlClient(url);
+		await expect(
+			client.request(
+				DeployAddEntitiesToCollectionJobDocument,
+				{
+					input: {
+						creatorUserId: ownerId,
+						collectionName: COLLECTION_NAME,
+						entities: [
+							{ entityId: foundationId, entityLot: EntityLot.Metadata },
+						],
+					},
+				},
+				getViewerHeaders(),
+			),
+		).rejects.toThrow("You are not allowed to edit this collection");
+
+		const contents = await getCollectionContents(
+			url,
+			ownerApiKey,
+			collectionId,
+		);
+		expect(contents.map((c) => c.entityId)).toEqual([duneId]);
+	});
+
+	it("should not let viewers remove entities", async () => {
+		const client = getGraphqlClient(url);
+		await expect(
+			client.request(
+				DeployRemoveEntitiesFromCollectionJobDocument,
+				{
+					input: {
+						creatorUserId: ownerId,
+						collectionName: COLLECTION_NAME,
+						entities: [{ entityId: duneId, entityLot: EntityLot.Metadata }],
+					},
+				},
+				getViewerHeaders(),
+			),
+		).rejects.toThrow("You are not allowed to edit this collection");
+
+		const contents = await getCollectionContents(
+			url,
+			ownerApiKey,
+			collectionId,
+		);
+		expect(contents.map((c) => c.entityId)).toEqual([duneId]);
+	});
+
+	it("should not let viewers update the collection", async () => {
+		const client = getGraphqlClient(url);
+		await expect(
+			client.request(
+				CreateOrUpdateCollectionDocument,
+				{
+					input: {
+						updateId: collectionId,
+						name: COLLECTION_NAME,
+						description: "Taken over by a viewer",
+					},
+				},
+				getViewerHeaders(),
+			),
+		).rejects.toThrow("Only the owner of the collection can update it");
+	});
+});
@@ -0,0 +1,127 @@
+import {
+	CollectionShareLinksDocument,
+	CreateCollectionShareLinkDocument,
+	type CreateCollectionShareLinkInput,
+	CreateOrUpdateCollectionDocument,
+	RevokeCollectionShareLinkDocument,
+	SharedCollectionContentsDocument,
+} from "@ryot/generated/graphql/backend/graphql";
+import { getGraphqlClient, registerTestUser } from "src/utils";
+import { beforeAll, describe, expect, it } from "vitest";
+
+describe("Collection share link tests", () => {
+	const url = process.env.API_BASE_URL as string;
+	let userApiKey: string;
+	let collectionId: string;
+
+	const getAuthHeaders = () => ({ Authorization: `Bearer ${userApiKey}` });
+
+	beforeAll(async () => {
+		[userApiKey] = await registerTestUser(url);
+		const client = getGraphqlClient(url);
+		const { createOrUpdateCollection } = await client.request(
+			CreateOrUpdateCollectionDocument,
+			{ input: { name: "Shared Watchlist" } },
+			getAuthHeaders(),
+		);
+		collectionId = createOrUpdateCollection.id;
+	});
+
+	const createShareLink = async (
+		input: Omit<CreateCollectionShareLinkInput, "collectionId"> = {},
+	) => {
+		const client = getGraphqlClient(url);
+		const { createCollectionShareLink } = await client.request(
+			CreateCollectionShareLinkDocument,
+			{ input: { collectionId, ...input } },
+			getAuthHeaders(),
+		);
+		return createCollectionShareLink.id;
+	};
+
+	const openShareLink = (
+		shareLinkId: string,
+		page?: number,
+		visitToken?: string,
+	) => {
+		const client = getGraphqlClient(url);
+		return client.request(SharedCollectionContentsDocument, {
+			input: { shareLinkId, visitToken, search: { page } },
+		});
+	};
+
+	const getTimesUsed = async (shareLinkId: string) => {
+		const client = getGraphqlClient(url);
+		const { collectionShareLinks } = await client.request(
+			CollectionShareLinksDocument,
+			{ collectionId },
+			getAuthHeaders(),
+		);
+		return collectionShareLinks.find((l) => l.id === shareLinkId)?.timesUsed;
+	};
+
+	it("should only count a new visit as a use", async () => {
+		const shareLinkId = await createShareLink();
+		const { sharedCollectionContents } = await openShareLink(shareLinkId);
+		expect(sharedCollectionContents.details.name).toBe("Shared Watchlist");
+		const { visitToken } = sharedCollectionContents;
+		const { sharedCollectionContents: nextPage } = await openShareLink(
+			shareLinkId,
+			2,
+			visitToken,
+		);
+		expect(nextPage.visitToken).toBe(visitToken);
+		await openShareLink(shareLinkId, 3, visitToken);
+		expect(await getTimesUsed(shareLinkId)).toBe(1);
+		await openShareLink(shareLinkId, 2);
+		expect(await getTimesUsed(shareLinkId)).toBe(2);
+	});
+
+	it("should not continue a visit of another share link", async () => {
+		const firstShareLinkId = await createShareLink();
+		const secondShareLinkId = await createShareLink();
+		const { sharedCollectionContents } = await openShareLink(firstShareLinkId);
+		await openShareLink(
+			secondShareLinkId,
+			2,
+			sharedCollectionContents.visitToken,
+		);
+		expect(await getTimesUsed(secondShareLinkId)).toBe(1);
+	});
+
+	it("should reject links that reached their maximum uses", async () => {
+		const shareLinkId = await createShareLink({ maximumUses: 1 });
+		const { sharedCollectionContents } = await openShareLink(shareLinkId);
+		const { visitToken } = sharedCollectionContents;
+		await expect(
+			openShareLink(shareLinkId, 2, visitToken),
+		).resolves.toBeDefined();
+		await expect(openShareLink(shareLinkId)).rejects.toThrow(/maximum uses/);
+		await expect(openShareLink(shareLinkId, 2)).rejects.toThrow(
+			/maximum uses/,
+		);
+		expect(await getTimesUsed(shareLinkId)).toBe(1);
+	});
+
+	it("should reject expired links", async () => {
+		const expiresOn = new Date(Date.now() - 60 * 60 * 1000).toISOString();
+		const shareLinkId = await createShareLink({ expiresOn });
+		await expect(openShareLink(shareLinkId)).rejects.toThrow(/expired/);
+		expect(await getTimesUsed(shareLinkId)).toBe(0);
+	});
+
+	it("should reject revoked links", async () => {
+		const shareLinkId = await createShareLink();
+		const { sharedCollectionContents } = await openShareLink(shareLinkId);
+		const client = getGraphqlClient(url);
+		await client.request(
+			RevokeCollectionShareLinkDocument,
+			{ shareLinkId },
+			getAuthHeaders(),
+		);
+		await expect(openShareLink(shareLinkId)).rejects.toThrow(/revoked/);
+		await expect(
+			openShareLink(shareLinkId, 2, sharedCollectionContents.visitToken),
+		).rejects.toThrow(/revoked/);
+	});
+});
@@ -0,0 +1,232 @@
+import { createHash } from "node:crypto";
+import { faker } from "@faker-js/faker";
+import {
+	CreateOrUpdateUserIntegrationDocument,
+	IntegrationProvider,
+	UserIntegrationsDocument,
+} from "@ryot/generated/graphql/backend/graphql";
+import { getGraphqlClient, registerTestUser } from "src/utils";
+import { beforeAll, describe, expect, it } from "vitest";
+
+describe("Integration related tests", () => {
+	const url = process.env.API_BASE_URL as string;
+	let userApiKey: string;
+
+	const getAuthHeaders = () => ({ Authorization: `Bearer ${userApiKey}` });
+
+	const createIntegration = async (
+		provider: IntegrationProvider,
+		providerSpecifics?: Record<string, unknown>,
+	) => {
+		const client = getGraphqlClient(url);
+		await client.request(
+			CreateOrUpdateUserIntegrationDocument,
+			{
+				input: {
+					provider,
+					providerSpecifics,
+					extraSettings: { disableOnContinuousErrors: false },
+				},
+			},
+			getAuthHeaders(),
+		);
+		const { userIntegrations } = await client.request(
+			UserIntegrationsDocument,
+			{},
+			getAuthHeaders(),
+		);
+		const integration = userIntegrations.find((i) => i.provider === provider);
+		if (!integration) throw new Error(`No ${provider} integration created`);
+		return integration;
+	};
+
+	beforeAll(async () => {
+		[userApiKey] = await registerTestUser(url);
+	});
+
+	describe("KOReader sync", () => {
+		const username = faker.internet.username();
+		const password = faker.internet.password();
+		const document = createHash("md5")
+			.update(faker.string.uuid())
+			.digest("hex");
+		let syncUrl: string;
+
+		const getKoreaderHeaders = (key = password) => ({
+			"x-auth-user": username,
+			"Content-Type": "application/json",
+			Accept: "application/vnd.koreader.v1+json",
+			"x-auth-key": createHash("md5").update(key).digest("hex"),
+		});
+
+		beforeAll(async () => {
+			const integration = await createIntegration(
+				IntegrationProvider.KoreaderSync,
+				{ koreaderSyncUsername: username, koreaderSyncPassword: password },
+			);
+			syncUrl = `${url}/backend/koreader-sync/${integration.id}`;
+			expect(integration.providerSpecifics?.koreaderSyncUsername).toBe(
+				username,
+			);
+		});
+
+		it("should not create an integration without a password", async () => {
+			const client = getGraphqlClient(url);
+			await expect(
+				client.request(
+					CreateOrUpdateUserIntegrationDocument,
+					{
+						input: {
+							provider: IntegrationProvider.KoreaderSync,
+							extraSettings: { disableOnContinuousErrors: false },
+							providerSpecifics: { koreaderSyncUsername: username },
+						},
+					},
+					getAuthHeaders(),
+				),
+			).rejects.toThrow();
+		});
+
+		it("should authorize valid credentials", async () => {
+			const response = await fetch(`${syncUrl}/users/auth`, {
+				headers: getKoreaderHeaders(),
+			});
+			expect(response.status).toBe(200);
+			expect(await response.json()).toEqual({ authorized: "OK" });
+		});
+
+		it("should reject an invalid password", async () => {
+			const response = await fetch(`${syncUrl}/users/auth`, {
+				headers: getKoreaderHeaders("wrong-password"),
+			});
+			expect(response.status).toBe(401);
+		});
+
+		it("should reject an unknown integration", async () => {
+			const response = await fetch(
+				`${url}/backend/koreader-sync/unknown-integration/users/auth`,
+				{ headers: getKoreaderHeaders() },
+			);
+			expect(response.status).toBe(401);
+		});
+
+		it("should return empty progress for an unknown document", async () => {
+			const response = await fetch(`${syncUrl}/syncs/progress/${document}`, {
+				headers: getKoreaderHeaders(),
+			});
+			expect(response.status).toBe(200);
+			expect(await response.json()).toEqual({});
+		});
+
+		it("should store and return document progress", async () => {
+			const progress = {
+				document,
+				device: "Kobo",
+				percentage: 0.42,
+				device_id: "test-device",
+				progress: "/body/DocFragment[12]/body/p[3]/text().0",
+			};
+			const updateResponse = await fetch(`${syncUrl}/syncs/progress`, {
+				method: "PUT",
+				headers: getKoreaderHeaders(),
+				body: JSON.stringify(progress),
+			});
+			expect(updateResponse.status).toBe(200);
+			const updated = await updateResponse.json();
+			expect(updated.document).toBe(document);
+			expect(typeof updated.timestamp).toBe("number");
+
+			const getResponse = await fetch(
+				`${syncUrl}/syncs/progress/${document}`,
+				{ headers: getKoreaderHeaders() },
+			);
+			expect(getResponse.status).toBe(200);
+			expect(await getResponse.json()).toMatchObject(progress);
+		});
+
+		it("should not update progress with invalid credentials", async () => {
+			const response = await fetch(`${syncUrl}/syncs/progress`, {
+				method: "PUT",
+				headers: getKoreaderHeaders("wrong-password"),
+				body: JSON.stringify({
+					document,
+					progress: "",
+					percentage: 1,
+					device: "Kobo",
+					device_id: "test-device",
+				}),
+			});
+			expect(response.status).toBe(401);
+		});
+	});
+
+	describe("ListenBrainz", () => {
+		const listenBrainzUrl = `${url}/backend/listenbrainz/1`;
+		let token: string;
+
+		const getListenBrainzHeaders = (value = token) => ({
+			Authorization: `Token ${value}`,
+			"Content-Type": "application/json",
+		});
+
+		beforeAll(async () => {
+			const integration = await createIntegration(
+				IntegrationProvider.ListenBrainz,
+			);
+			token = integration.id;
+		});
+
+		it("should validate a known token", async () => {
+			const response = await fetch(`${listenBrainzUrl}/validate-token`, {
+				headers: getListenBrainzHeaders(),
+			});
+			expect(response.status).toBe(200);
+			const data = await response.json();
+			expect(data.valid).toBe(true);
+			expect(data.user_name).toBeTruthy();
+		});
+
+		it("should reject an unknown token", async () => {
+			const response = await fetch(`${listenBrainzUrl}/validate-token`, {
+				headers: getListenBrainzHeaders("unknown-token"),
+			});
+			expect(response.status).toBe(200);
+			const data = await response.json();
+			expect(data.valid).toBe(false);
+			expect(data.user_name).toBeUndefined();
+		});
+
+		it("should accept submitted listens", async () => {
+			const response = await fetch(`${listenBrainzUrl}/submit-listens`, {
+				method: "POST",
+				headers: getListenBrainzHeaders(),
+				body: JSON.stringify({
+					listen_type: "single",
+					payload: [
+						{
+							listened_at: Math.floor(Date.now() / 1000),
+							track_metadata: {
+								artist_name: "Daft Punk",
+								track_name: "Harder, Better, Faster, Stronger",
+								additional_info: {
+									recording_mbid: "c2c5c4c1-9a3c-4b6f-9b5f-3f5c1f1f4b2d",
+								},
+							},
+						},
+					],
+				}),
+			});
+			expect(response.status).toBe(200);
+			expect(await response.json()).toEqual({ status: "ok" });
+		});
+
+		it("should not accept listens with an unknown token", async () => {
+			const response = await fetch(`${listenBrainzUrl}/submit-listens`, {
+				method: "POST",
+				headers: getListenBrainzHeaders("unknown-token"),
+				body: JSON.stringify({ listen_type: "single", payload: [] }),
+			});
+			expect(response.status).toBe(401);
+		});
+	});
+});
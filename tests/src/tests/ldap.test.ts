@@ -0,0 +1,87 @@
+import {
+	LoginUserDocument,
+	RegisterUserDocument,
+	UserDetailsDocument,
+	UserLot,
+} from "@ryot/generated/graphql/backend/graphql";
+import { getGraphqlClient, TEST_LDAP_USERS } from "src/utils";
+import { describe, expect, it } from "vitest";
+
+describe("LDAP authentication tests", () => {
+	const url = process.env.API_BASE_URL as string;
+
+	const login = async (username: string, password: string) => {
+		const client = getGraphqlClient(url);
+		const { loginUser } = await client.request(LoginUserDocument, {
+			input: { password: { username, password } },
+		});
+		return loginUser;
+	};
+
+	const getUserDetails = async (apiKey: string) => {
+		const client = getGraphqlClient(url);
+		const { userDetails } = await client.request(
+			UserDetailsDocument,
+			{},
+			{ Authorization: `Bearer ${apiKey}` },
+		);
+		if (userDetails.__typename !== "UserDetails") {
+			throw new Error(`Expected UserDetails but got ${userDetails.__typename}`);
+		}
+		return userDetails;
+	};
+
+	it("should provision users on their first login", async () => {
+		const { username, password } = TEST_LDAP_USERS.member;
+		const loginUser = await login(username, password);
+		expect(loginUser.__typename).toBe("ApiKeyResponse");
+		if (loginUser.__typename !== "ApiKeyResponse") return;
+
+		const userDetails = await getUserDetails(loginUser.apiKey);
+		expect(userDetails.name).toBe(username);
+		expect(userDetails.lot).toBe(UserLot.Normal);
+
+		const secondLogin = await login(username, password);
+		expect(secondLogin.__typename).toBe("ApiKeyResponse");
+		if (secondLogin.__typename !== "ApiKeyResponse") return;
+		const secondDetails = await getUserDetails(secondLogin.apiKey);
+		expect(secondDetails.id).toBe(userDetails.id);
+	});
+
+	it("should make members of the admin group admins", async () => {
+		const { username, password } = TEST_LDAP_USERS.admin;
+		const loginUser = await login(username, password);
+		expect(loginUser.__typename).toBe("ApiKeyResponse");
+		if (loginUser.__typename !== "ApiKeyResponse") return;
+
+		const userDetails = await getUserDetails(loginUser.apiKey);
+		expect(userDetails.lot).toBe(UserLot.Admin);
+	});
+
+	it("should not log in to a local user with the same name", async () => {
+		const { username, password } = TEST_LDAP_USERS.localNameClash;
+		const localPassword = "local-user-password";
+		const client = getGraphqlClient(url);
+		const { registerUser } = await client.request(RegisterUserDocument, {
+			input: { data: { password: { username, password: localPassword } } },
+		});
+		if (registerUser.__typename !== "StringIdObject") {
+			throw new Error(`Failed to register local user: ${registerUser.error}`);
+		}
+
+		const ldapLogin = await login(username, password);
+		expect(ldapLogin.__typename).toBe("LoginError");
+
+		const localLogin = await login(username, localPassword);
+		expect(localLogin.__typename).toBe("ApiKeyResponse");
+		if (localLogin.__typename !== "ApiKeyResponse") return;
+		const userDetails = await getUserDetails(localLogin.apiKey);
+		expect(userDetails.id).toBe(registerUser.id);
+	});
+
+	it("should reject wrong passwords", async () => {
+		const { username } = TEST_LDAP_USERS.member;
+		const loginUser = await login(username, "not-the-password");
+		expect(loginUser.__typename).toBe("LoginError");
+	});
+});
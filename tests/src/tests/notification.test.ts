@@ -0,0 +1,153 @@
+import { createServer, type Server } from "node:http";
+import type { AddressInfo } from "node:net";
+import {
+	CreateUserNotificationPlatformDocument,
+	NotificationPlatformLot,
+	TestUserNotificationPlatformsDocument,
+	UpdateUserNotificationPlatformDocument,
+	UserNotificationPlatformsDocument,
+} from "@ryot/generated/graphql/backend/graphql";
+import { getGraphqlClient, registerTestUser } from "src/utils";
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+
+interface ReceivedRequest {
+	path: string;
+	method?: string;
+	authorization?: string;
+	body: Record<string, unknown>;
+}
+
+describe("Notification platform related tests", () => {
+	const url = process.env.API_BASE_URL as string;
+	const matrixRoomId = "!ryot-test-room:example.org";
+	const matrixAccessToken = "matrix-test-access-token";
+	const receivedRequests: ReceivedRequest[] = [];
+	let userApiKey: string;
+	let homeserver: Server;
+	let homeserverUrl: string;
+
+	beforeAll(async () => {
+		[userApiKey] = await registerTestUser(url);
+
+		homeserver = createServer((req, res) => {
+			let body = "";
+			req.on("data", (chunk) => {
+				body += chunk;
+			});
+			req.on("end", () => {
+				receivedRequests.push({
+					method: req.method,
+					body: JSON.parse(body || "{}"),
+					authorization: req.headers.authorization,
+					path: decodeURIComponent(req.url ?? ""),
+				});
+				res.writeHead(200, { "Content-Type": "application/json" });
+				res.end(JSON.stringify({ event_id: "$ryot-test-event" }));
+			});
+		});
+		await new Promise<void>((resolve) =>
+			homeserver.listen(0, "127.0.0.1", resolve),
+		);
+		const { port } = homeserver.address() as AddressInfo;
+		homeserverUrl = `http://127.0.0.1:${port}`;
+	});
+
+	afterAll(async () => {
+		await new Promise((resolve) => homeserver.close(resolve));
+	});
+
+	const getAuthHeaders = () => ({
+		Authorization: `Bearer ${userApiKey}`,
+	});
+
+	it("should send Matrix notifications as room messages", async () => {
+		const client = getGraphqlClient(url);
+
+		await client.request(
+			CreateUserNotificationPlatformDocument,
+			{
+				input: {
+					chatId: matrixRoomId,
+					baseUrl: homeserverUrl,
+					apiToken: matrixAccessToken,
+					lot: NotificationPlatformLot.Matrix,
+				},
+			},
+			getAuthHeaders(),
+		);
+
+		const { testUserNotificationPlatforms } = await client.request(
+			TestUserNotificationPlatformsDocument,
+			{},
+			getAuthHeaders(),
+		);
+		expect(testUserNotificationPlatforms).toBe(true);
+
+		expect(receivedRequests).toHaveLength(1);
+		const [request] = receivedRequests;
+		expect(request.method).toBe("PUT");
+		expect(request.authorization).toBe(`Bearer ${matrixAccessToken}`);
+		expect(request.path).toMatch(
+			new RegExp(
+				`^/_matrix/client/v3/rooms/${matrixRoomId}/send/m\\.room\\.message/[0-9a-f-]{36}$`,
+			),
+		);
+		expect(request.body.msgtype).toBe("m.text");
+		expect(request.body.format).toBe("org.matrix.custom.html");
+		expect(request.body.body).toContain("This is a test notification");
+		expect(request.body.formatted_body).toBe(`<p>${request.body.body}</p>`);
+	});
+
+	it("should clear the quiet hours of a platform", async () => {
+		const client = getGraphqlClient(url);
+
+		const { createUserNotificationPlatform: notificationId } =
+			await client.request(
+				CreateUserNotificationPlatformDocument,
+				{
+					input: {
+						lot: NotificationPlatformLot.Discord,
+						baseUrl: "https://discord.example.com/api/webhooks/ryot",
+					},
+				},
+				getAuthHeaders(),
+			);
+
+		const getQuietHours = async () => {
+			const { userNotificationPlatforms } = await client.request(
+				UserNotificationPlatformsDocument,
+				{},
+				getAuthHeaders(),
+			);
+			return userNotificationPlatforms.find((p) => p.id === notificationId)
+				?.quietHours;
+		};
+
+		await client.request(
+			UpdateUserNotificationPlatformDocument,
+			{
+				input: {
+					notificationId,
+					quietHours: {
+						isEnabled: true,
+						endTime: "07:00:00",
+						startTime: "22:00:00",
+					},
+				},
+			},
+			getAuthHeaders(),
+		);
+		expect(await getQuietHours()).toEqual({
+			isEnabled: true,
+			endTime: "07:00:00",
+			startTime: "22:00:00",
+		});
+
+		await client.request(
+			UpdateUserNotificationPlatformDocument,
+			{ input: { notificationId, clearQuietHours: true } },
+			getAuthHeaders(),
+		);
+		expect(await getQuietHours()).toBeNull();
+	});
+});
@@ -0,0 +1,37 @@
+import { faker } from "@faker-js/faker";
+import {
+	CoreDetailsDocument,
+	LoginUserViaProxyDocument,
+} from "@ryot/generated/graphql/backend/graphql";
+import { getGraphqlClient, TEST_TRUSTED_PROXY_HEADER } from "src/utils";
+import { describe, expect, it } from "vitest";
+
+describe("Reverse proxy header authentication tests", () => {
+	const url = process.env.API_BASE_URL as string;
+
+	it("should tell the frontend which header the proxy sends", async () => {
+		const client = getGraphqlClient(url);
+		const { coreDetails } = await client.request(CoreDetailsDocument);
+		expect(coreDetails.proxyAuthHeader).toBe(TEST_TRUSTED_PROXY_HEADER);
+	});
+
+	it("should ignore the header when it is relayed by the bundled proxy", async () => {
+		const client = getGraphqlClient(url);
+		await expect(
+			client.request(
+				LoginUserViaProxyDocument,
+				{},
+				{ [TEST_TRUSTED_PROXY_HEADER]: faker.internet.username() },
+			),
+		).rejects.toThrow();
+	});
+
+	it("should not create a session on the login page for untrusted clients", async () => {
+		const response = await fetch(`${url}/auth`, {
+			redirect: "manual",
+			headers: { [TEST_TRUSTED_PROXY_HEADER]: faker.internet.username() },
+		});
+		expect(response.status).toBe(200);
+		expect(response.headers.get("set-cookie")).toBeNull();
+	});
+});
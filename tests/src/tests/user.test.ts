@@ -1,12 +1,22 @@
 import { faker } from "@faker-js/faker";
 import {
+	ClearLoginLockoutDocument,
 	CreateOrUpdateUserIntegrationDocument,
+	CreateUserApiKeyDocument,
 	IntegrationProvider,
+	LoginErrorVariant,
+	LoginUserDocument,
+	RegisterUserDocument,
 	ResetUserDocument,
+	RevokeAllOtherSessionsDocument,
 	UserDetailsDocument,
 	UserImportReportsDocument,
 	UserIntegrationsDocument,
+	UserApiKeyScope,
+	UserAuditEventLot,
+	UserAuditLogsDocument,
 	UserNotificationPlatformsDocument,
+	UserSessionsDocument,
 	UserWorkoutTemplatesListDocument,
 } from "@ryot/generated/graphql/backend/graphql";
 import {
@@ -19,6 +29,7 @@ import {
 	getUserWorkoutsList,
 	registerAdminUser,
 	registerTestUser,
+	waitFor,
 } from "src/utils";
 import { beforeAll, describe, expect, it } from "vitest";
 
@@ -50,6 +61,71 @@ describe("User related tests", () => {
 		}
 	});
 
+	it("should return the user details for an admin API key", async () => {
+		const client = getGraphqlClient(url);
+		const { createUserApiKey } = await client.request(
+			CreateUserApiKeyDocument,
+			{ input: { name: "Admin key", scopes: [UserApiKeyScope.Admin] } },
+			getAuthHeaders(),
+		);
+
+		const { userDetails } = await client.request(
+			UserDetailsDocument,
+			{},
+			{ Authorization: `Bearer ${createUserApiKey.apiKey}` },
+		);
+		const { userDetails: sessionUserDetails } = await client.request(
+			UserDetailsDocument,
+			{},
+			getAuthHeaders(),
+		);
+
+		expect(userDetails.__typename).toBe("UserDetails");
+		expect(sessionUserDetails.__typename).toBe("UserDetails");
+		if (
+			userDetails.__typename === "UserDetails" &&
+			sessionUserDetails.__typename === "UserDetails"
+		) {
+			expect(userDetails.id).toBe(sessionUserDetails.id);
+		}
+	});
+
+	it("should reject user details for a read-only API key", async () => {
+		const client = getGraphqlClient(url);
+		const { createUserApiKey } = await client.request(
+			CreateUserApiKeyDocument,
+			{ input: { name: "Read key", scopes: [UserApiKeyScope.ReadMedia] } },
+			getAuthHeaders(),
+		);
+
+		await expect(
+			client.request(
+				UserDetailsDocument,
+				{},
+				{ Authorization: `Bearer ${createUserApiKey.apiKey}` },
+			),
+		).rejects.toThrow();
+	});
+
+	it("should reject integrations and notification platforms for a read-only API key", async () => {
+		const client = getGraphqlClient(url);
+		const { createUserApiKey } = await client.request(
+			CreateUserApiKeyDocument,
+			{ input: { name: "Read key", scopes: [UserApiKeyScope.ReadMedia] } },
+			getAuthHeaders(),
+		);
+		const readOnlyHeaders = {
+			Authorization: `Bearer ${createUserApiKey.apiKey}`,
+		};
+
+		await expect(
+			client.request(UserIntegrationsDocument, {}, readOnlyHeaders),
+		).rejects.toThrow();
+		await expect(
+			client.request(UserNotificationPlatformsDocument, {}, readOnlyHeaders),
+		).rejects.toThrow();
+	});
+
 	it("should throw without authentication", async () => {
 		const client = getGraphqlClient(url);
 
@@ -326,3 +402,202 @@ describe("Reset User functionality", () => {
 		expect(collectionsAfterReset).toHaveLength(DEFAULT_USER_COLLECTIONS_COUNT);
 	});
 });
+
+describe("User session management", () => {
+	const url = process.env.API_BASE_URL as string;
+	const username = faker.internet.username();
+	const password = faker.internet.password();
+
+	const loginUser = async () => {
+		const client = getGraphqlClient(url);
+		const { loginUser } = await client.request(LoginUserDocument, {
+			input: { password: { username, password } },
+		});
+		if (loginUser.__typename !== "ApiKeyResponse")
+			throw new Error(`Expected ApiKeyResponse but got ${loginUser.__typename}`);
+		return loginUser.apiKey;
+	};
+
+	beforeAll(async () => {
+		const client = getGraphqlClient(url);
+		await client.request(RegisterUserDocument, {
+			input: { data: { password: { username, password } } },
+		});
+	});
+
+	it("should revoke all other sessions", async () => {
+		const client = getGraphqlClient(url);
+		const firstToken = await loginUser();
+		const secondToken = await loginUser();
+
+		const { userSessions } = await client.request(
+			UserSessionsDocument,
+			{},
+			{ Authorization: `Bearer ${firstToken}` },
+		);
+		expect(userSessions.length).toBeGreaterThanOrEqual(2);
+		expect(userSessions.filter((s) => s.isCurrent)).toHaveLength(1);
+
+		const { revokeAllOtherSessions } = await client.request(
+			RevokeAllOtherSessionsDocument,
+			{},
+			{ Authorization: `Bearer ${firstToken}` },
+		);
+		expect(revokeAllOtherSessions).toBe(true);
+
+		const { userDetails: revokedDetails } = await client.request(
+			UserDetailsDocument,
+			{},
+			{ Authorization: `Bearer ${secondToken}` },
+		);
+		expect(revokedDetails.__typename).toBe("UserDetailsError");
+
+		const { userDetails: currentDetails } = await client.request(
+			UserDetailsDocument,
+			{},
+			{ Authorization: `Bearer ${firstToken}` },
+		);
+		expect(currentDetails.__typename).toBe("UserDetails");
+
+		const { userSessions: remainingSessions } = await client.request(
+			UserSessionsDocument,
+			{},
+			{ Authorization: `Bearer ${firstToken}` },
+		);
+		expect(remainingSessions).toHaveLength(1);
+		expect(remainingSessions[0].isCurrent).toBe(true);
+	});
+});
+
+describe("User audit logs", () => {
+	const url = process.env.API_BASE_URL as string;
+	const username = faker.internet.username();
+	const password = faker.internet.password();
+	let userApiKey: string;
+
+	beforeAll(async () => {
+		const client = getGraphqlClient(url);
+		await client.request(RegisterUserDocument, {
+			input: { data: { password: { username, password } } },
+		});
+		const { loginUser } = await client.request(LoginUserDocument, {
+			input: { password: { username, password } },
+		});
+		if (loginUser.__typename !== "ApiKeyResponse")
+			throw new Error(`Expected ApiKeyResponse but got ${loginUser.__typename}`);
+		userApiKey = loginUser.apiKey;
+	});
+
+	it("should not allow a normal user to read the events of all users", async () => {
+		const client = getGraphqlClient(url);
+		await expect(
+			client.request(
+				UserAuditLogsDocument,
+				{ input: { allUsers: true } },
+				{ Authorization: `Bearer ${userApiKey}` },
+			),
+		).rejects.toThrow();
+	});
+
+	it("should record a failed login attempt", async () => {
+		const client = getGraphqlClient(url);
+		const { loginUser } = await client.request(LoginUserDocument, {
+			input: { password: { username, password: "wrong-password" } },
+		});
+		expect(loginUser.__typename).toBe("LoginError");
+
+		const { userAuditLogs } = await client.request(
+			UserAuditLogsDocument,
+			{ input: { lot: UserAuditEventLot.LoginFailed } },
+			{ Authorization: `Bearer ${userApiKey}` },
+		);
+		expect(userAuditLogs.items.length).toBeGreaterThanOrEqual(1);
+		expect(userAuditLogs.items[0]).toMatchObject({
+			lot: UserAuditEventLot.LoginFailed,
+			information: { username, reason: "CredentialsMismatch" },
+		});
+	});
+});
+
+describe("Login brute-force protection", () => {
+	const url = process.env.API_BASE_URL as string;
+	const password = faker.internet.password();
+
+	const loginUser = async (username: string, loginPassword = password) => {
+		const client = getGraphqlClient(url);
+		const { loginUser } = await client.request(LoginUserDocument, {
+			input: { password: { username, password: loginPassword } },
+		});
+		return loginUser;
+	};
+
+	const registerUser = async () => {
+		const client = getGraphqlClient(url);
+		const username = faker.internet.username();
+		await client.request(RegisterUserDocument, {
+			input: { data: { password: { username, password } } },
+		});
+		return username;
+	};
+
+	// Every failed attempt doubles the backoff, so wait it out before the next one
+	// until the default maximum of 5 attempts locks the account.
+	const lockOutUser = async (username: string) => {
+		for (let attempt = 0; attempt < 5; attempt++) {
+			if (attempt > 0) await waitFor(2 ** (attempt - 1) * 1000 + 200);
+			const result = await loginUser(username, "wrong-password");
+			expect(result).toMatchObject({
+				error: LoginErrorVariant.CredentialsMismatch,
+			});
+		}
+	};
+
+	it("should back off after a failed attempt", async () => {
+		const username = await registerUser();
+
+		const failed = await loginUser(username, "wrong-password");
+		expect(failed).toMatchObject({
+			error: LoginErrorVariant.CredentialsMismatch,
+		});
+		const blocked = await loginUser(username);
+		expect(blocked).toMatchObject({ error: LoginErrorVariant.AccountLocked });
+
+		await waitFor(1200);
+		const allowed = await loginUser(username);
+		expect(allowed.__typename).toBe("ApiKeyResponse");
+	});
+
+	it("should lock out the account after too many failed attempts", async () => {
+		const username = await registerUser();
+		await lockOutUser(username);
+
+		const locked = await loginUser(username);
+		expect(locked).toMatchObject({ error: LoginErrorVariant.AccountLocked });
+	});
+
+	it("should allow an admin to clear the lockout", async () => {
+		const client = getGraphqlClient(url);
+		const [adminApiKey] = await registerAdminUser(url);
+		const [userApiKey] = await registerTestUser(url);
+		const username = await registerUser();
+		await lockOutUser(username);
+
+		await expect(
+			client.request(
+				ClearLoginLockoutDocument,
+				{ input: { username } },
+				{ Authorization: `Bearer ${userApiKey}` },
+			),
+		).rejects.toThrow();
+
+		const { clearLoginLockout } = await client.request(
+			ClearLoginLockoutDocument,
+			{ input: { username } },
+			{ Authorization: `Bearer ${adminApiKey}` },
+		);
+		expect(clearLoginLockout).toBe(true);
+
+		const allowed = await loginUser(username);
+		expect(allowed.__typename).toBe("ApiKeyResponse");
+	});
+});
@@ -24,6 +24,16 @@ import {
 import { GraphQLClient } from "graphql-request";
 
 export const TEST_ADMIN_ACCESS_TOKEN = "test-admin-access-token-for-e2e-tests";
+export const TEST_TRUSTED_PROXY_HEADER = "Remote-User";
+export const TEST_LDAP_BASE_DN = "dc=example,dc=org";
+export const TEST_LDAP_USERS = {
+	member: { username: "ldap-member", password: "ldap-member-password" },
+	admin: { username: "ldap-admin", password: "ldap-admin-password" },
+	localNameClash: {
+		username: "ldap-local-clash",
+		password: "ldap-clash-password",
+	},
+};
 export const DEFAULT_USER_COLLECTIONS_COUNT = 7;
 
 export const getGraphqlClient = (baseUrl: string) => {